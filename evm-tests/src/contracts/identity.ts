@@ -0,0 +1,243 @@
+export const IIDENTITY_ADDRESS = "0x0000000000000000000000000000000000000811";
+
+export const IIdentityABI = [
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "coldkey",
+        "type": "bytes32"
+      }
+    ],
+    "name": "IdentitySet",
+    "type": "event"
+  },
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "SubnetIdentitySet",
+    "type": "event"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "bytes32",
+        "name": "coldkey",
+        "type": "bytes32"
+      }
+    ],
+    "name": "getIdentity",
+    "outputs": [
+      {
+        "components": [
+          {
+            "internalType": "string",
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "url",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "githubRepo",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "image",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "discord",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "additional",
+            "type": "string"
+          }
+        ],
+        "internalType": "struct ChainIdentity",
+        "name": "",
+        "type": "tuple"
+      }
+    ],
+    "stateMutability": "view",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "getSubnetIdentity",
+    "outputs": [
+      {
+        "components": [
+          {
+            "internalType": "string",
+            "name": "subnetName",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "githubRepo",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "subnetContact",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "subnetUrl",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "discord",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "logoUrl",
+            "type": "string"
+          },
+          {
+            "internalType": "string",
+            "name": "additional",
+            "type": "string"
+          }
+        ],
+        "internalType": "struct SubnetIdentity",
+        "name": "",
+        "type": "tuple"
+      }
+    ],
+    "stateMutability": "view",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "string",
+        "name": "name",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "url",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "githubRepo",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "image",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "discord",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "description",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "additional",
+        "type": "string"
+      }
+    ],
+    "name": "setIdentity",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "string",
+        "name": "subnetName",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "githubRepo",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "subnetContact",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "subnetUrl",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "discord",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "description",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "logoUrl",
+        "type": "string"
+      },
+      {
+        "internalType": "string",
+        "name": "additional",
+        "type": "string"
+      }
+    ],
+    "name": "setSubnetIdentity",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  }
+]
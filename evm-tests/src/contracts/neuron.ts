@@ -1,235 +1,467 @@
 export const INEURON_ADDRESS = "0x0000000000000000000000000000000000000804";
 
 export const INeuronABI = [
-    {
-        inputs: [
-            {
-                internalType: "uint16",
-                name: "netuid",
-                type: "uint16",
-            },
-            {
-                internalType: "bytes32",
-                name: "commitHash",
-                type: "bytes32",
-            },
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "hotkey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": false,
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "AxonServed",
+    "type": "event"
+  },
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "coldkey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "hotkey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": false,
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "NeuronRegistered",
+    "type": "event"
+  },
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "hotkey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": false,
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "PrometheusServed",
+    "type": "event"
+  },
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "hotkey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": false,
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "indexed": false,
+        "internalType": "bytes32",
+        "name": "commitHash",
+        "type": "bytes32"
+      }
+    ],
+    "name": "WeightsCommitted",
+    "type": "event"
+  },
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "hotkey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": false,
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "WeightsKeyRemoved",
+    "type": "event"
+  },
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "hotkey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "weightsKey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": false,
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "WeightsKeySet",
+    "type": "event"
+  },
+  {
+    "anonymous": false,
+    "inputs": [
+      {
+        "indexed": true,
+        "internalType": "bytes32",
+        "name": "hotkey",
+        "type": "bytes32"
+      },
+      {
+        "indexed": false,
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "WeightsSet",
+    "type": "event"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "bytes32",
+        "name": "hotkey",
+        "type": "bytes32"
+      }
+    ],
+    "name": "burnedRegister",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "bytes32",
+        "name": "commitHash",
+        "type": "bytes32"
+      }
+    ],
+    "name": "commitWeights",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      }
+    ],
+    "name": "removeWeightsKey",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint16[]",
+        "name": "uids",
+        "type": "uint16[]"
+      },
+      {
+        "internalType": "uint16[]",
+        "name": "values",
+        "type": "uint16[]"
+      },
+      {
+        "internalType": "uint16[]",
+        "name": "salt",
+        "type": "uint16[]"
+      },
+      {
+        "internalType": "uint64",
+        "name": "versionKey",
+        "type": "uint64"
+      }
+    ],
+    "name": "revealWeights",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint32",
+        "name": "version",
+        "type": "uint32"
+      },
+      {
+        "internalType": "uint128",
+        "name": "ip",
+        "type": "uint128"
+      },
+      {
+        "internalType": "uint16",
+        "name": "port",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint8",
+        "name": "ipType",
+        "type": "uint8"
+      },
+      {
+        "internalType": "uint8",
+        "name": "protocol",
+        "type": "uint8"
+      },
+      {
+        "internalType": "uint8",
+        "name": "placeholder1",
+        "type": "uint8"
+      },
+      {
+        "internalType": "uint8",
+        "name": "placeholder2",
+        "type": "uint8"
+      }
+    ],
+    "name": "serveAxon",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint32",
+        "name": "version",
+        "type": "uint32"
+      },
+      {
+        "internalType": "uint128",
+        "name": "ip",
+        "type": "uint128"
+      },
+      {
+        "internalType": "uint16",
+        "name": "port",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint8",
+        "name": "ipType",
+        "type": "uint8"
+      },
+      {
+        "internalType": "uint8",
+        "name": "protocol",
+        "type": "uint8"
+      },
+      {
+        "internalType": "uint8",
+        "name": "placeholder1",
+        "type": "uint8"
+      },
+      {
+        "internalType": "uint8",
+        "name": "placeholder2",
+        "type": "uint8"
+      },
+      {
+        "internalType": "bytes",
+        "name": "certificate",
+        "type": "bytes"
+      }
+    ],
+    "name": "serveAxonTls",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint32",
+        "name": "version",
+        "type": "uint32"
+      },
+      {
+        "components": [
+          {
+            "internalType": "uint8",
+            "name": "protocol",
+            "type": "uint8"
+          },
+          {
+            "internalType": "uint128",
+            "name": "ip",
+            "type": "uint128"
+          },
+          {
+            "internalType": "uint8",
+            "name": "ipType",
+            "type": "uint8"
+          },
+          {
+            "internalType": "uint16",
+            "name": "port",
+            "type": "uint16"
+          },
+          {
+            "internalType": "uint8",
+            "name": "priority",
+            "type": "uint8"
+          }
         ],
-        name: "commitWeights",
-        outputs: [],
-        stateMutability: "payable",
-        type: "function",
-    },
-    {
-        inputs: [
-            {
-                internalType: "uint16",
-                name: "netuid",
-                type: "uint16",
-            },
-            {
-                internalType: "uint16[]",
-                name: "uids",
-                type: "uint16[]",
-            },
-            {
-                internalType: "uint16[]",
-                name: "values",
-                type: "uint16[]",
-            },
-            {
-                internalType: "uint16[]",
-                name: "salt",
-                type: "uint16[]",
-            },
-            {
-                internalType: "uint64",
-                name: "versionKey",
-                type: "uint64",
-            },
-        ],
-        name: "revealWeights",
-        outputs: [],
-        stateMutability: "payable",
-        type: "function",
-    },
-    {
-        inputs: [
-            {
-                internalType: "uint16",
-                name: "netuid",
-                type: "uint16",
-            },
-            {
-                internalType: "uint16[]",
-                name: "dests",
-                type: "uint16[]",
-            },
-            {
-                internalType: "uint16[]",
-                name: "weights",
-                type: "uint16[]",
-            },
-            {
-                internalType: "uint64",
-                name: "versionKey",
-                type: "uint64",
-            },
-        ],
-        name: "setWeights",
-        outputs: [],
-        stateMutability: "payable",
-        type: "function",
-    },
-    {
-        inputs: [
-            {
-                internalType: "uint16",
-                name: "netuid",
-                type: "uint16",
-            },
-            {
-                internalType: "uint32",
-                name: "version",
-                type: "uint32",
-            },
-            {
-                internalType: "uint128",
-                name: "ip",
-                type: "uint128",
-            },
-            {
-                internalType: "uint16",
-                name: "port",
-                type: "uint16",
-            },
-            {
-                internalType: "uint8",
-                name: "ipType",
-                type: "uint8",
-            },
-            {
-                internalType: "uint8",
-                name: "protocol",
-                type: "uint8",
-            },
-            {
-                internalType: "uint8",
-                name: "placeholder1",
-                type: "uint8",
-            },
-            {
-                internalType: "uint8",
-                name: "placeholder2",
-                type: "uint8",
-            },
-        ],
-        name: "serveAxon",
-        outputs: [],
-        stateMutability: "payable",
-        type: "function",
-    },
-    {
-        inputs: [
-            {
-                internalType: "uint16",
-                name: "netuid",
-                type: "uint16",
-            },
-            {
-                internalType: "uint32",
-                name: "version",
-                type: "uint32",
-            },
-            {
-                internalType: "uint128",
-                name: "ip",
-                type: "uint128",
-            },
-            {
-                internalType: "uint16",
-                name: "port",
-                type: "uint16",
-            },
-            {
-                internalType: "uint8",
-                name: "ipType",
-                type: "uint8",
-            },
-            {
-                internalType: "uint8",
-                name: "protocol",
-                type: "uint8",
-            },
-            {
-                internalType: "uint8",
-                name: "placeholder1",
-                type: "uint8",
-            },
-            {
-                internalType: "uint8",
-                name: "placeholder2",
-                type: "uint8",
-            },
-            {
-                internalType: "bytes",
-                name: "certificate",
-                type: "bytes",
-            },
-        ],
-        name: "serveAxonTls",
-        outputs: [],
-        stateMutability: "payable",
-        type: "function",
-    },
-    {
-        inputs: [
-            {
-                internalType: "uint16",
-                name: "netuid",
-                type: "uint16",
-            },
-            {
-                internalType: "uint32",
-                name: "version",
-                type: "uint32",
-            },
-            {
-                internalType: "uint128",
-                name: "ip",
-                type: "uint128",
-            },
-            {
-                internalType: "uint16",
-                name: "port",
-                type: "uint16",
-            },
-            {
-                internalType: "uint8",
-                name: "ipType",
-                type: "uint8",
-            },
-        ],
-        name: "servePrometheus",
-        outputs: [],
-        stateMutability: "payable",
-        type: "function",
-    },
-    {
-        inputs: [
-            {
-                internalType: "uint16",
-                name: "netuid",
-                type: "uint16",
-            },
-            {
-                internalType: "bytes32",
-                name: "hotkey",
-                type: "bytes32",
-            },
-        ],
-        name: "burnedRegister",
-        outputs: [],
-        stateMutability: "payable",
-        type: "function",
-    },
-];
\ No newline at end of file
+        "internalType": "struct INeuron.AxonEndpoint[]",
+        "name": "endpoints",
+        "type": "tuple[]"
+      }
+    ],
+    "name": "serveAxons",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint32",
+        "name": "version",
+        "type": "uint32"
+      },
+      {
+        "internalType": "uint128",
+        "name": "ip",
+        "type": "uint128"
+      },
+      {
+        "internalType": "uint16",
+        "name": "port",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint8",
+        "name": "ipType",
+        "type": "uint8"
+      }
+    ],
+    "name": "servePrometheus",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "uint16[]",
+        "name": "dests",
+        "type": "uint16[]"
+      },
+      {
+        "internalType": "uint16[]",
+        "name": "weights",
+        "type": "uint16[]"
+      },
+      {
+        "internalType": "uint64",
+        "name": "versionKey",
+        "type": "uint64"
+      }
+    ],
+    "name": "setWeights",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  },
+  {
+    "inputs": [
+      {
+        "internalType": "uint16",
+        "name": "netuid",
+        "type": "uint16"
+      },
+      {
+        "internalType": "bytes32",
+        "name": "weightsKey",
+        "type": "bytes32"
+      }
+    ],
+    "name": "setWeightsKey",
+    "outputs": [],
+    "stateMutability": "payable",
+    "type": "function"
+  }
+]
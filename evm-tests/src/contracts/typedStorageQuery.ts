@@ -0,0 +1,33 @@
+export const ITYPED_STORAGE_QUERY_ADDRESS = "0x0000000000000000000000000000000000000810";
+
+export const ITypedStorageQueryABI = [
+  {
+    "inputs": [
+      {
+        "internalType": "string",
+        "name": "name",
+        "type": "string"
+      },
+      {
+        "internalType": "uint32",
+        "name": "version",
+        "type": "uint32"
+      },
+      {
+        "internalType": "bytes",
+        "name": "keys",
+        "type": "bytes"
+      }
+    ],
+    "name": "query",
+    "outputs": [
+      {
+        "internalType": "bytes",
+        "name": "",
+        "type": "bytes"
+      }
+    ],
+    "stateMutability": "view",
+    "type": "function"
+  }
+]
@@ -386,4 +386,13 @@ export async function setTargetRegistrationsPerInterval(
         call: internal_tx.decodedCall,
     });
     await waitForTransactionWithRetry(api, tx, alice);
-}
\ No newline at end of file
+}
+// Let users add liquidity to the subnet pool, signed by the subnet owner
+export async function enableUserLiquidity(api: TypedApi<typeof devnet>, netuid: number, keypair: KeyPair) {
+    const signer = getSignerFromKeypair(keypair)
+    const tx = api.tx.Swap.toggle_user_liquidity({
+        netuid: netuid,
+        enable: true,
+    })
+    await waitForTransactionWithRetry(api, tx, signer)
+}
@@ -0,0 +1,113 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58 } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, startCall,
+} from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+import { IAlphaTokenABI, alphaTokenAddress } from "../src/contracts/alphaToken"
+
+describe("Test the alpha token precompile", () => {
+    // init eth part
+    const owner = generateRandomEthersWallet();
+    const spender = generateRandomEthersWallet();
+    const recipient = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, owner.address)
+        await forceSetBalanceToEthAddress(api, spender.address)
+        await forceSetBalanceToEthAddress(api, recipient.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+        await startCall(api, netuid, coldkey)
+
+        console.log("test the case on subnet ", netuid)
+
+        const staking = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, owner);
+        const tx = await staking.addStake(hotkey.publicKey, tao(100).toString(), netuid)
+        await tx.wait()
+    })
+
+    it("Balance follows the chosen hotkey", async () => {
+        const token = new ethers.Contract(alphaTokenAddress(netuid), IAlphaTokenABI, owner);
+        assert.equal(BigInt(await token.balanceOf(owner.address)), BigInt(0))
+
+        const tx = await token.setHotkey(hotkey.publicKey)
+        await tx.wait()
+
+        assert.equal(await token.hotkeyOf(owner.address), ethers.hexlify(hotkey.publicKey))
+        const balance = BigInt(await token.balanceOf(owner.address))
+        assert.ok(balance > BigInt(0))
+        assert.ok(BigInt(await token.totalSupply()) >= balance)
+    })
+
+    it("Infinite allowance is not spent by transferFrom", async () => {
+        const ownerToken = new ethers.Contract(alphaTokenAddress(netuid), IAlphaTokenABI, owner);
+        const spenderToken = new ethers.Contract(alphaTokenAddress(netuid), IAlphaTokenABI, spender);
+
+        let tx = await ownerToken.approve(spender.address, ethers.MaxUint256)
+        await tx.wait()
+        assert.equal(BigInt(await ownerToken.allowance(owner.address, spender.address)), ethers.MaxUint256)
+
+        tx = await spenderToken.transferFrom(owner.address, recipient.address, tao(1))
+        await tx.wait()
+
+        assert.ok(BigInt(await ownerToken.balanceOf(recipient.address)) > BigInt(0))
+        // The recipient is credited on the owner's hotkey.
+        assert.equal(await ownerToken.hotkeyOf(recipient.address), ethers.hexlify(hotkey.publicKey))
+        assert.equal(BigInt(await ownerToken.allowance(owner.address, spender.address)), ethers.MaxUint256)
+    })
+
+    it("transferFrom spends a finite allowance", async () => {
+        const ownerToken = new ethers.Contract(alphaTokenAddress(netuid), IAlphaTokenABI, owner);
+        const spenderToken = new ethers.Contract(alphaTokenAddress(netuid), IAlphaTokenABI, spender);
+
+        let tx = await ownerToken.approve(spender.address, tao(1))
+        await tx.wait()
+        assert.equal(BigInt(await ownerToken.allowance(owner.address, spender.address)), tao(1))
+
+        try {
+            tx = await spenderToken.transferFrom(owner.address, recipient.address, tao(2))
+            await tx.wait()
+            assert.fail("Transaction should have failed");
+        } catch (error) {
+            // Transaction failed as expected
+        }
+
+        const recipientBalanceBefore = BigInt(await ownerToken.balanceOf(recipient.address))
+        tx = await spenderToken.transferFrom(owner.address, recipient.address, tao(1))
+        await tx.wait()
+
+        assert.equal(BigInt(await ownerToken.allowance(owner.address, spender.address)), BigInt(0))
+        assert.ok(BigInt(await ownerToken.balanceOf(recipient.address)) > recipientBalanceBefore)
+    })
+
+    it("Zero value transfers succeed and emit Transfer", async () => {
+        const token = new ethers.Contract(alphaTokenAddress(netuid), IAlphaTokenABI, spender);
+
+        const tx = await token.transfer(recipient.address, 0)
+        const receipt = await tx.wait()
+
+        const transfer = receipt.logs
+            .map((log: ethers.Log) => token.interface.parseLog(log))
+            .find((log: ethers.LogDescription | null) => log?.name === "Transfer")
+        assert.ok(transfer !== undefined)
+        assert.equal(transfer.args.from, spender.address)
+        assert.equal(transfer.args.to, recipient.address)
+        assert.equal(BigInt(transfer.args.value), BigInt(0))
+    })
+});
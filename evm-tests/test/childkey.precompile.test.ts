@@ -0,0 +1,77 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58 } from "../src/address-utils"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, startCall,
+} from "../src/subtensor"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+import { ICHILDKEY_ADDRESS, IChildkeyABI } from "../src/contracts/childkey"
+
+describe("Test the childkey precompile", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+    // owned by the wallet once it registers it
+    const ownedHotkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+        await startCall(api, netuid, coldkey)
+
+        console.log("test the case on subnet ", netuid)
+
+        const neuron = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        const tx = await neuron.burnedRegister(netuid, ownedHotkey.publicKey)
+        await tx.wait()
+    })
+
+    it("Childkey take set through the precompile is read back", async () => {
+        const contract = new ethers.Contract(ICHILDKEY_ADDRESS, IChildkeyABI, wallet);
+        const [, maxTake] = await contract.getChildkeyTakeRange()
+
+        const tx = await contract.setChildkeyTake(ownedHotkey.publicKey, netuid, maxTake)
+        await tx.wait()
+
+        assert.equal(Number(await contract.getChildkeyTake(ownedHotkey.publicKey, netuid)), Number(maxTake))
+        const take = await api.query.SubtensorModule.ChildkeyTake.getValue(convertPublicKeyToSs58(ownedHotkey.publicKey), netuid)
+        assert.equal(take, Number(maxTake))
+    })
+
+    it("Decreased delegate take is read back", async () => {
+        const contract = new ethers.Contract(ICHILDKEY_ADDRESS, IChildkeyABI, wallet);
+        const [minTake] = await contract.getDelegateTakeRange()
+        assert.ok(Number(await contract.getDelegateTake(ownedHotkey.publicKey)) > Number(minTake))
+
+        const tx = await contract.decreaseTake(ownedHotkey.publicKey, minTake)
+        await tx.wait()
+
+        assert.equal(Number(await contract.getDelegateTake(ownedHotkey.publicKey)), Number(minTake))
+    })
+
+    it("Setting the take of a hotkey the caller doesn't own fails", async () => {
+        const contract = new ethers.Contract(ICHILDKEY_ADDRESS, IChildkeyABI, wallet);
+        const takeBefore = Number(await contract.getChildkeyTake(hotkey.publicKey, netuid))
+        try {
+            const tx = await contract.setChildkeyTake(hotkey.publicKey, netuid, takeBefore + 1)
+            await tx.wait()
+            assert.fail("Transaction should have failed");
+        } catch (error) {
+            assert.ok(!(error instanceof assert.AssertionError))
+        }
+        assert.equal(Number(await contract.getChildkeyTake(hotkey.publicKey, netuid)), takeBefore)
+    })
+});
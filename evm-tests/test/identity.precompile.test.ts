@@ -0,0 +1,71 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToPublicKey } from "../src/address-utils"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, startCall,
+} from "../src/subtensor"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+import { IIDENTITY_ADDRESS, IIdentityABI } from "../src/contracts/identity"
+
+describe("Test the identity precompile", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    const unknownWallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+    // owned by the wallet once it registers it
+    const ownedHotkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+        await startCall(api, netuid, coldkey)
+
+        console.log("test the case on subnet ", netuid)
+
+        const neuron = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+        const tx = await neuron.burnedRegister(netuid, ownedHotkey.publicKey)
+        await tx.wait()
+    })
+
+    it("Identity set through the precompile is read back", async () => {
+        const contract = new ethers.Contract(IIDENTITY_ADDRESS, IIdentityABI, wallet);
+
+        const tx = await contract.setIdentity(
+            "name", "https://example.com", "https://github.com/example/repo", "https://example.com/image.png",
+            "discord", "description", "additional",
+        )
+        await tx.wait()
+
+        const identity = await contract.getIdentity(convertH160ToPublicKey(wallet.address))
+        assert.equal(identity.name, "name")
+        assert.equal(identity.url, "https://example.com")
+        assert.equal(identity.githubRepo, "https://github.com/example/repo")
+        assert.equal(identity.image, "https://example.com/image.png")
+        assert.equal(identity.discord, "discord")
+        assert.equal(identity.description, "description")
+        assert.equal(identity.additional, "additional")
+    })
+
+    it("Reading a missing identity reverts", async () => {
+        const contract = new ethers.Contract(IIDENTITY_ADDRESS, IIdentityABI, wallet);
+        try {
+            await contract.getIdentity(convertH160ToPublicKey(unknownWallet.address))
+            assert.fail("Missing identity should revert")
+        } catch (error) {
+            assert.ok(!(error instanceof assert.AssertionError))
+        }
+    })
+});
@@ -0,0 +1,85 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToPublicKey } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, startCall,
+    enableUserLiquidity,
+} from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+import { ILIQUIDITY_ADDRESS, ILiquidityABI } from "../src/contracts/liquidity"
+
+describe("Test the liquidity precompile", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+        await startCall(api, netuid, coldkey)
+        await enableUserLiquidity(api, netuid, coldkey)
+
+        console.log("test the case on subnet ", netuid)
+
+        const staking = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const tx = await staking.addStake(hotkey.publicKey, tao(100).toString(), netuid)
+        await tx.wait()
+    })
+
+    it("Adds, reads, modifies and removes a position", async () => {
+        const contract = new ethers.Contract(ILIQUIDITY_ADDRESS, ILiquidityABI, wallet);
+        const coldkey = convertH160ToPublicKey(wallet.address)
+
+        assert.equal(await contract.isUserLiquidityEnabled(netuid), true)
+        assert.deepEqual(await contract.getPositionIds(netuid, coldkey), [])
+
+        const currentTick = Number(await contract.getCurrentTick(netuid))
+        const liquidity = tao(1)
+        let tx = await contract.addLiquidity(
+            hotkey.publicKey, netuid, currentTick - 1000, currentTick + 1000, liquidity,
+        )
+        await tx.wait()
+
+        const positionIds = await contract.getPositionIds(netuid, coldkey)
+        assert.equal(positionIds.length, 1)
+        const positionId = positionIds[0]
+
+        let position = await contract.getPosition(netuid, coldkey, positionId)
+        assert.equal(BigInt(position.id), BigInt(positionId))
+        assert.equal(Number(position.tick_low), currentTick - 1000)
+        assert.equal(Number(position.tick_high), currentTick + 1000)
+        assert.equal(BigInt(position.liquidity), liquidity)
+        assert.ok(BigInt(position.tao) > BigInt(0))
+        assert.ok(BigInt(position.alpha) > BigInt(0))
+
+        const tick = await contract.getTick(netuid, currentTick - 1000)
+        assert.equal(BigInt(tick.liquidity_gross), liquidity)
+        assert.equal(BigInt(tick.liquidity_net), liquidity)
+
+        tx = await contract.modifyPosition(hotkey.publicKey, netuid, positionId, -(liquidity / BigInt(2)))
+        await tx.wait()
+        position = await contract.getPosition(netuid, coldkey, positionId)
+        assert.equal(BigInt(position.liquidity), liquidity / BigInt(2))
+
+        tx = await contract.collectFees(hotkey.publicKey, netuid, positionId)
+        await tx.wait()
+
+        tx = await contract.removeLiquidity(hotkey.publicKey, netuid, positionId)
+        await tx.wait()
+        assert.deepEqual(await contract.getPositionIds(netuid, coldkey), [])
+    })
+});
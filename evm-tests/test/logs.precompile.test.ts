@@ -0,0 +1,115 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58, convertH160ToPublicKey } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, burnedRegister,
+    setCommitRevealWeightsEnabled, setWeightsSetRateLimit, startCall,
+} from "../src/subtensor"
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking"
+import { INEURON_ADDRESS, INeuronABI } from "../src/contracts/neuron"
+
+describe("Test the event logs of the precompiles", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    const validator = generateRandomEthersWallet();
+    const weightsKey = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+    const registeredHotkey = getRandomSubstrateKeypair();
+
+    const staking = new ethers.Interface(IStakingV2ABI)
+    const neuron = new ethers.Interface(INeuronABI)
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+
+    // Fetches the logs of an event with eth_getLogs, filtered by its first indexed parameter.
+    async function getLogs(address: string, contract: ethers.Interface, event: string, topic: Uint8Array, blockNumber: number) {
+        const logs = await wallet.provider!.getLogs({
+            address,
+            topics: [contract.getEvent(event)!.topicHash, ethers.hexlify(topic)],
+            fromBlock: blockNumber,
+            toBlock: blockNumber,
+        })
+        return logs.map((log) => contract.parseLog(log)!)
+    }
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        await forceSetBalanceToEthAddress(api, validator.address)
+        await forceSetBalanceToEthAddress(api, weightsKey.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+        await startCall(api, netuid, coldkey)
+
+        await burnedRegister(api, netuid, convertH160ToSS58(validator.address), coldkey)
+        await setCommitRevealWeightsEnabled(api, netuid, false)
+        await setWeightsSetRateLimit(api, netuid, BigInt(0))
+
+        console.log("test the case on subnet ", netuid)
+    })
+
+    it("Staking logs the added and removed amounts", async () => {
+        const contract = new ethers.Contract(ISTAKING_V2_ADDRESS, IStakingV2ABI, wallet);
+        const coldkeyPublicKey = convertH160ToPublicKey(wallet.address)
+
+        let tx = await contract.addStake(hotkey.publicKey, tao(10), netuid)
+        let receipt = await tx.wait()
+
+        let logs = await getLogs(ISTAKING_V2_ADDRESS, staking, "StakeAdded", coldkeyPublicKey, receipt.blockNumber)
+        assert.equal(logs.length, 1)
+        assert.equal(logs[0].args.hotkey, ethers.hexlify(hotkey.publicKey))
+        assert.equal(Number(logs[0].args.netuid), netuid)
+        assert.equal(BigInt(logs[0].args.amount), tao(10))
+
+        // A partial limit order logs the amount it executed.
+        const stakeBefore = BigInt(await contract.getStake(hotkey.publicKey, coldkeyPublicKey, netuid))
+        tx = await contract.removeStakeLimit(hotkey.publicKey, stakeBefore * BigInt(2), tao(1) / BigInt(2), true, netuid)
+        receipt = await tx.wait()
+        const stakeAfter = BigInt(await contract.getStake(hotkey.publicKey, coldkeyPublicKey, netuid))
+
+        logs = await getLogs(ISTAKING_V2_ADDRESS, staking, "StakeRemoved", coldkeyPublicKey, receipt.blockNumber)
+        assert.equal(logs.length, 1)
+        assert.equal(BigInt(logs[0].args.amount), stakeBefore - stakeAfter)
+    })
+
+    it("Registering logs the new neuron", async () => {
+        const contract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, wallet);
+
+        const tx = await contract.burnedRegister(netuid, registeredHotkey.publicKey)
+        const receipt = await tx.wait()
+
+        const logs = await getLogs(INEURON_ADDRESS, neuron, "NeuronRegistered", convertH160ToPublicKey(wallet.address), receipt.blockNumber)
+        assert.equal(logs.length, 1)
+        assert.equal(logs[0].args.hotkey, ethers.hexlify(registeredHotkey.publicKey))
+        assert.equal(Number(logs[0].args.netuid), netuid)
+    })
+
+    it("Weights set by a weights key are logged for its hotkey", async () => {
+        const validatorContract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, validator);
+        const weightsKeyContract = new ethers.Contract(INEURON_ADDRESS, INeuronABI, weightsKey);
+        const validatorPublicKey = convertH160ToPublicKey(validator.address)
+
+        let tx = await validatorContract.setWeightsKey(netuid, convertH160ToPublicKey(weightsKey.address))
+        await tx.wait()
+
+        tx = await weightsKeyContract.setWeights(netuid, [0], [1], 0)
+        const receipt = await tx.wait()
+
+        const logs = await getLogs(INEURON_ADDRESS, neuron, "WeightsSet", validatorPublicKey, receipt.blockNumber)
+        assert.equal(logs.length, 1)
+        assert.equal(Number(logs[0].args.netuid), netuid)
+
+        const signerLogs = await getLogs(INEURON_ADDRESS, neuron, "WeightsSet", convertH160ToPublicKey(weightsKey.address), receipt.blockNumber)
+        assert.equal(signerLogs.length, 0)
+    })
+});
@@ -0,0 +1,178 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58, convertH160ToSS58, convertH160ToPublicKey } from "../src/address-utils"
+import { tao } from "../src/balance-math"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork, startCall,
+} from "../src/subtensor"
+import { IPROXY_ADDRESS, IProxyABI } from "../src/contracts/proxy"
+
+// Indexes of the runtime's `ProxyType` variants.
+const PROXY_TYPE_STAKING = 8;
+const PROXY_TYPE_REGISTRATION = 9;
+
+describe("Test the proxy and batch precompile", () => {
+    // init eth part
+    const real = generateRandomEthersWallet();
+    const delegate = generateRandomEthersWallet();
+    const delayedReal = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+
+    // The precompile takes `pallet_subtensor::Call`s, without the pallet index of the runtime call.
+    async function subtensorCall(tx: { getEncodedData: () => Promise<{ asBytes: () => Uint8Array }> }) {
+        const encoded = (await tx.getEncodedData()).asBytes()
+        return ethers.hexlify(encoded.slice(1))
+    }
+
+    async function addStakeCall(amount: bigint) {
+        return subtensorCall(api.tx.SubtensorModule.add_stake({
+            hotkey: convertPublicKeyToSs58(hotkey.publicKey),
+            netuid: netuid,
+            amount_staked: amount,
+        }))
+    }
+
+    async function removeStakeCall(amount: bigint) {
+        return subtensorCall(api.tx.SubtensorModule.remove_stake({
+            hotkey: convertPublicKeyToSs58(hotkey.publicKey),
+            netuid: netuid,
+            amount_unstaked: amount,
+        }))
+    }
+
+    async function getStake(wallet: { address: string }) {
+        return api.query.SubtensorModule.Alpha.getValue(
+            convertPublicKeyToSs58(hotkey.publicKey),
+            convertH160ToSS58(wallet.address),
+            netuid,
+        )
+    }
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, real.address)
+        await forceSetBalanceToEthAddress(api, delegate.address)
+        await forceSetBalanceToEthAddress(api, delayedReal.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+        await startCall(api, netuid, coldkey)
+
+        console.log("test the case on subnet ", netuid)
+    })
+
+    it("batch reports every call and keeps the successful ones", async () => {
+        const contract = new ethers.Contract(IPROXY_ADDRESS, IProxyABI, real);
+        const calls = [
+            await addStakeCall(tao(1)),
+            await removeStakeCall(tao(1_000_000)),
+            await addStakeCall(tao(1)),
+        ]
+
+        const results = await contract.batch.staticCall(calls)
+        assert.equal(results.length, 3)
+        assert.equal(results[0].success, true)
+        assert.equal(results[1].success, false)
+        assert.ok(ethers.getBytes(results[1].error).length > 0)
+        assert.equal(results[2].success, true)
+
+        const stakeBefore = await getStake(real)
+        const tx = await contract.batch(calls)
+        await tx.wait()
+
+        assert.ok(await getStake(real) > stakeBefore)
+    })
+
+    it("batchAll rolls back every call when one fails", async () => {
+        const contract = new ethers.Contract(IPROXY_ADDRESS, IProxyABI, real);
+        const stakeBefore = await getStake(real)
+
+        try {
+            const tx = await contract.batchAll([
+                await addStakeCall(tao(1)),
+                await removeStakeCall(tao(1_000_000)),
+            ])
+            await tx.wait()
+            assert.fail("Transaction should have failed");
+        } catch (error) {
+            // Transaction failed as expected
+        }
+
+        assert.equal(await getStake(real), stakeBefore)
+    })
+
+    it("Calls outside the whitelist are rejected", async () => {
+        const contract = new ethers.Contract(IPROXY_ADDRESS, IProxyABI, real);
+        const rootRegister = await subtensorCall(api.tx.SubtensorModule.root_register({
+            hotkey: convertPublicKeyToSs58(hotkey.publicKey),
+        }))
+
+        try {
+            const tx = await contract.batch([rootRegister])
+            await tx.wait()
+            assert.fail("Transaction should have failed");
+        } catch (error) {
+            // Transaction failed as expected
+        }
+    })
+
+    it("proxyBatch only dispatches calls the proxy type allows", async () => {
+        const realContract = new ethers.Contract(IPROXY_ADDRESS, IProxyABI, real);
+        const delegateContract = new ethers.Contract(IPROXY_ADDRESS, IProxyABI, delegate);
+        const realPublicKey = convertH160ToPublicKey(real.address)
+        const delegatePublicKey = convertH160ToPublicKey(delegate.address)
+
+        let tx = await realContract.addProxy(delegatePublicKey, PROXY_TYPE_REGISTRATION, 0)
+        await tx.wait()
+
+        // A registration proxy can't stake.
+        try {
+            tx = await delegateContract.proxyBatchAll(realPublicKey, [await addStakeCall(tao(1))])
+            await tx.wait()
+            assert.fail("Transaction should have failed");
+        } catch (error) {
+            // Transaction failed as expected
+        }
+
+        // A staking proxy can.
+        tx = await realContract.removeProxy(delegatePublicKey, PROXY_TYPE_REGISTRATION, 0)
+        await tx.wait()
+        tx = await realContract.addProxy(delegatePublicKey, PROXY_TYPE_STAKING, 0)
+        await tx.wait()
+
+        const stakeBefore = await getStake(real)
+        tx = await delegateContract.proxyBatchAll(realPublicKey, [await addStakeCall(tao(1))])
+        await tx.wait()
+        assert.ok(await getStake(real) > stakeBefore)
+        assert.equal(await getStake(delegate), BigInt(0))
+    })
+
+    it("Delayed proxies can't dispatch through the precompile", async () => {
+        const realContract = new ethers.Contract(IPROXY_ADDRESS, IProxyABI, delayedReal);
+        const delegateContract = new ethers.Contract(IPROXY_ADDRESS, IProxyABI, delegate);
+        const realPublicKey = convertH160ToPublicKey(delayedReal.address)
+
+        let tx = await realContract.addProxy(convertH160ToPublicKey(delegate.address), PROXY_TYPE_STAKING, 10)
+        await tx.wait()
+
+        const stakeBefore = await getStake(delayedReal)
+        try {
+            tx = await delegateContract.proxyBatchAll(realPublicKey, [await addStakeCall(tao(1))])
+            await tx.wait()
+            assert.fail("Transaction should have failed");
+        } catch (error) {
+            // Transaction failed as expected
+        }
+        assert.equal(await getStake(delayedReal), stakeBefore)
+    })
+});
@@ -0,0 +1,86 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate"
+import { devnet } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { convertPublicKeyToSs58 } from "../src/address-utils"
+import { ethers } from "ethers"
+import { generateRandomEthersWallet } from "../src/utils"
+import {
+    forceSetBalanceToEthAddress, forceSetBalanceToSs58Address, addNewSubnetwork,
+} from "../src/subtensor"
+import { ITYPED_STORAGE_QUERY_ADDRESS, ITypedStorageQueryABI } from "../src/contracts/typedStorageQuery"
+
+describe("Test the typed storage query precompile", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+    // init substrate part
+    const hotkey = getRandomSubstrateKeypair();
+    const coldkey = getRandomSubstrateKeypair();
+    const unknownHotkey = getRandomSubstrateKeypair();
+
+    const coder = ethers.AbiCoder.defaultAbiCoder()
+
+    let api: TypedApi<typeof devnet>
+    let netuid: number;
+
+    // Runs a version 1 getter with its keys ABI-encoded and decodes the returned value.
+    async function query(name: string, keyTypes: string[], keys: unknown[], valueTypes: string[]) {
+        const contract = new ethers.Contract(ITYPED_STORAGE_QUERY_ADDRESS, ITypedStorageQueryABI, wallet);
+        const value = await contract.query(name, 1, coder.encode(keyTypes, keys))
+        return coder.decode(valueTypes, value)
+    }
+
+    before(async () => {
+        api = await getDevnetApi()
+
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(hotkey.publicKey))
+        await forceSetBalanceToSs58Address(api, convertPublicKeyToSs58(coldkey.publicKey))
+        await forceSetBalanceToEthAddress(api, wallet.address)
+        netuid = await addNewSubnetwork(api, hotkey, coldkey)
+
+        console.log("test the case on subnet ", netuid)
+    })
+
+    it("Typed getters match the chain storage", async () => {
+        const [ownerExists, owner] = await query("SubnetOwner", ["uint16"], [netuid], ["bool", "bytes32"])
+        assert.ok(ownerExists)
+        assert.equal(owner, ethers.hexlify(coldkey.publicKey))
+
+        const [registered, uid] = await query("Uids", ["uint16", "bytes32"], [netuid, hotkey.publicKey], ["bool", "uint16"])
+        assert.ok(registered)
+
+        const [keyExists, key] = await query("Keys", ["uint16", "uint16"], [netuid, uid], ["bool", "bytes32"])
+        assert.ok(keyExists)
+        assert.equal(key, ethers.hexlify(hotkey.publicKey))
+
+        const [coldkeyExists, hotkeyOwner] = await query("Owner", ["bytes32"], [hotkey.publicKey], ["bool", "bytes32"])
+        assert.ok(coldkeyExists)
+        assert.equal(hotkeyOwner, ethers.hexlify(coldkey.publicKey))
+
+        const tempo = await api.query.SubtensorModule.Tempo.getValue(netuid)
+        const [queriedTempo] = await query("Tempo", ["uint16"], [netuid], ["uint16"])
+        assert.equal(Number(queriedTempo), tempo)
+    })
+
+    it("Missing entries are reported as absent", async () => {
+        const [registered] = await query("Uids", ["uint16", "bytes32"], [netuid, unknownHotkey.publicKey], ["bool", "uint16"])
+        assert.ok(!registered)
+
+        const [keyExists, key] = await query("Keys", ["uint16", "uint16"], [netuid, 65535], ["bool", "bytes32"])
+        assert.ok(!keyExists)
+        assert.equal(key, ethers.ZeroHash)
+
+        const [coldkeyExists] = await query("Owner", ["bytes32"], [unknownHotkey.publicKey], ["bool", "bytes32"])
+        assert.ok(!coldkeyExists)
+    })
+
+    it("Unknown getters revert", async () => {
+        const contract = new ethers.Contract(ITYPED_STORAGE_QUERY_ADDRESS, ITypedStorageQueryABI, wallet);
+        try {
+            await contract.query("TotalIssuance", 2, "0x")
+            assert.fail("Unknown getter version should revert")
+        } catch (error) {
+            assert.ok(!(error instanceof assert.AssertionError))
+        }
+    })
+});
@@ -54,9 +54,6 @@ pub fn devnet_config() -> Result<ChainSpec, String> {
         // Pre-funded accounts
         vec![],
         true,
-        vec![],
-        vec![],
-        0,
     ))
     .with_properties(properties)
     .build())
@@ -69,9 +66,6 @@ fn devnet_genesis(
     root_key: AccountId,
     _endowed_accounts: Vec<AccountId>,
     _enable_println: bool,
-    _stakes: Vec<(AccountId, Vec<(AccountId, (u64, u16))>)>,
-    _balances: Vec<(AccountId, u64)>,
-    _balances_issuance: u64,
 ) -> serde_json::Value {
     serde_json::json!({
         "balances": {
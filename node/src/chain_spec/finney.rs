@@ -8,61 +8,8 @@ pub fn finney_mainnet_config() -> Result<ChainSpec, String> {
     let path: PathBuf = std::path::PathBuf::from("./snapshot.json");
     let wasm_binary = WASM_BINARY.ok_or("Development wasm not available".to_string())?;
 
-    // We mmap the file into memory first, as this is *a lot* faster than using
-    // `serde_json::from_reader`. See https://github.com/serde-rs/json/issues/160
-    let file = File::open(&path)
-        .map_err(|e| format!("Error opening genesis file `{}`: {}", path.display(), e))?;
-
-    // SAFETY: `mmap` is fundamentally unsafe since technically the file can change
-    //         underneath us while it is mapped; in practice it's unlikely to be a problem
-    let bytes = unsafe {
-        memmap2::Mmap::map(&file)
-            .map_err(|e| format!("Error mmaping genesis file `{}`: {}", path.display(), e))?
-    };
-
-    let old_state: ColdkeyHotkeys =
-        json::from_slice(&bytes).map_err(|e| format!("Error parsing genesis file: {}", e))?;
-
-    let mut processed_stakes: Vec<(
-        sp_runtime::AccountId32,
-        Vec<(sp_runtime::AccountId32, (u64, u16))>,
-    )> = Vec::new();
-    for (coldkey_str, hotkeys) in old_state.stakes.iter() {
-        let coldkey = <sr25519::Public as Ss58Codec>::from_ss58check(coldkey_str)
-            .map_err(|e| e.to_string())?;
-        let coldkey_account = sp_runtime::AccountId32::from(coldkey);
-
-        let mut processed_hotkeys: Vec<(sp_runtime::AccountId32, (u64, u16))> = Vec::new();
-
-        for (hotkey_str, amount_uid) in hotkeys.iter() {
-            let (amount, uid) = amount_uid;
-            let hotkey = <sr25519::Public as Ss58Codec>::from_ss58check(hotkey_str)
-                .map_err(|e| e.to_string())?;
-            let hotkey_account = sp_runtime::AccountId32::from(hotkey);
-
-            processed_hotkeys.push((hotkey_account, (*amount, *uid)));
-        }
-        processed_hotkeys.sort();
-
-        processed_stakes.push((coldkey_account, processed_hotkeys));
-    }
-
-    processed_stakes.sort();
-
-    let mut balances_issuance: u64 = 0;
-    let mut processed_balances: Vec<(sp_runtime::AccountId32, u64)> = Vec::new();
-    for (key_str, amount) in old_state.balances.iter() {
-        let key =
-            <sr25519::Public as Ss58Codec>::from_ss58check(key_str).map_err(|e| e.to_string())?;
-        let key_account = sp_runtime::AccountId32::from(key);
-
-        processed_balances.push((key_account, *amount));
-        balances_issuance = balances_issuance
-            .checked_add(*amount)
-            .ok_or("Balances issuance overflowed".to_string())?;
-    }
-
-    processed_balances.sort();
+    let snapshot = snapshot::load_snapshot(&path)?;
+    let balances_issuance = snapshot.balances_issuance()?;
 
     // Give front-ends necessary data to present to users
     let mut properties = sc_service::Properties::new();
@@ -177,8 +124,7 @@ pub fn finney_mainnet_config() -> Result<ChainSpec, String> {
         // Pre-funded accounts
         vec![],
         true,
-        processed_stakes.clone(),
-        processed_balances.clone(),
+        snapshot,
         balances_issuance,
     ))
     .with_properties(properties)
@@ -210,12 +156,11 @@ fn finney_genesis(
     _root_key: AccountId,
     _endowed_accounts: Vec<AccountId>,
     _enable_println: bool,
-    stakes: Vec<(AccountId, Vec<(AccountId, (u64, u16))>)>,
-    balances: Vec<(AccountId, u64)>,
+    snapshot: snapshot::Snapshot,
     balances_issuance: u64,
 ) -> serde_json::Value {
     serde_json::json!({
-        "balances": { "balances": balances.to_vec() },
+        "balances": { "balances": snapshot.balances },
         "aura": { "authorities": initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>() },
         "grandpa": { "authorities": initial_authorities
                 .iter()
@@ -224,7 +169,7 @@ fn finney_genesis(
         },
         "sudo": { "key": Some(<AccountId32 as Ss58Codec>::from_ss58check("5FCM3DBXWiGcwYYQtT8z4ZD93TqYpYxjaAfgv6aMStV1FTCT").unwrap()) },
         "subtensorModule": {
-            "stakes": stakes,
+            "snapshot": snapshot.subtensor,
             "balancesIssuance": balances_issuance,
         }
     })
@@ -3,7 +3,12 @@
 
 use super::*;
 
-pub fn localnet_config(single_authority: bool) -> Result<ChainSpec, String> {
+/// Builds the local development chain spec. With `accelerated_timing`, the genesis shortens
+/// subnet start, childkey and tempo timings so the chain is usable for tests within minutes.
+pub fn localnet_config(
+    single_authority: bool,
+    accelerated_timing: bool,
+) -> Result<ChainSpec, String> {
     let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
 
     // Give front-ends necessary data to present to users
@@ -43,6 +48,7 @@ pub fn localnet_config(single_authority: bool) -> Result<ChainSpec, String> {
         },
         // Pre-funded accounts
         true,
+        accelerated_timing,
     ))
     .with_properties(properties)
     .build())
@@ -51,6 +57,7 @@ pub fn localnet_config(single_authority: bool) -> Result<ChainSpec, String> {
 fn localnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
     _enable_println: bool,
+    accelerated_timing: bool,
 ) -> serde_json::Value {
     let mut balances = vec![
         (
@@ -111,6 +118,19 @@ fn localnet_genesis(
         get_account_id_from_seed::<sr25519::Public>("Ferdie"),
     ];
 
+    // Timings that production keeps long enough for humans, shortened for test networks.
+    let subtensor_module = if accelerated_timing {
+        serde_json::json!({
+            "startCallDelay": 5,
+            "pendingChildkeyCooldown": 15,
+            "setChildrenRateLimit": 15,
+            "childkeyTakeRateLimit": 5,
+            "initialSubnetTempo": 10,
+        })
+    } else {
+        serde_json::json!({})
+    };
+
     serde_json::json!({
         "balances": { "balances": balances },
         "aura": {
@@ -134,5 +154,6 @@ fn localnet_genesis(
         "evmChainId": {
             "chainId": 42,
         },
+        "subtensorModule": subtensor_module,
     })
 }
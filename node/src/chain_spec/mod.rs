@@ -4,6 +4,7 @@
 pub mod devnet;
 pub mod finney;
 pub mod localnet;
+pub mod snapshot;
 pub mod testnet;
 
 use node_subtensor_runtime::{Block, WASM_BINARY};
@@ -77,10 +78,3 @@ pub fn get_grandpa_from_ss58_addr(s: &str) -> GrandpaId {
 use serde::{Deserialize, Serialize};
 use serde_json as json;
 use std::{fs::File, path::PathBuf};
-
-// Configure storage from nakamoto data
-#[derive(Deserialize, Debug)]
-struct ColdkeyHotkeys {
-    stakes: std::collections::HashMap<String, std::collections::HashMap<String, (u64, u16)>>,
-    balances: std::collections::HashMap<String, u64>,
-}
@@ -0,0 +1,123 @@
+//! Chain state snapshots written by `export-snapshot` and read by the live chain specs.
+
+use super::*;
+use node_subtensor_runtime::pallet_subtensor::utils::snapshot::{StakeSnapshot, SubtensorSnapshot};
+use std::collections::HashMap;
+use std::path::Path;
+use subtensor_runtime_common::NetUid;
+
+/// Version of the snapshot format written by this node.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// The state needed to rebuild a chain in a new genesis.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    /// Format version, see [`SNAPSHOT_VERSION`].
+    pub version: u32,
+    /// Block the snapshot was taken at.
+    pub block: u64,
+    /// Account balances.
+    pub balances: Vec<(AccountId, u64)>,
+    /// Subnets, stakes and identities.
+    pub subtensor: SubtensorSnapshot<AccountId>,
+}
+
+impl Snapshot {
+    /// Total balance held by the accounts.
+    pub fn balances_issuance(&self) -> Result<u64, String> {
+        self.balances
+            .iter()
+            .try_fold(0u64, |issuance, (_, amount)| {
+                issuance
+                    .checked_add(*amount)
+                    .ok_or_else(|| "Balances issuance overflowed".to_string())
+            })
+    }
+}
+
+/// Root-only stakes and balances, the format of snapshots taken before dynamic TAO.
+#[derive(Deserialize, Debug)]
+struct LegacySnapshot {
+    stakes: HashMap<String, HashMap<String, (u64, u16)>>,
+    balances: HashMap<String, u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SnapshotFile {
+    Versioned(Snapshot),
+    Legacy(LegacySnapshot),
+}
+
+/// Reads a snapshot, converting legacy snapshots into root stakes.
+pub fn load_snapshot(path: &Path) -> Result<Snapshot, String> {
+    // We mmap the file into memory first, as this is *a lot* faster than using
+    // `serde_json::from_reader`. See https://github.com/serde-rs/json/issues/160
+    let file = File::open(path)
+        .map_err(|e| format!("Error opening genesis file `{}`: {}", path.display(), e))?;
+
+    // SAFETY: `mmap` is fundamentally unsafe since technically the file can change
+    //         underneath us while it is mapped; in practice it's unlikely to be a problem
+    let bytes = unsafe {
+        memmap2::Mmap::map(&file)
+            .map_err(|e| format!("Error mmaping genesis file `{}`: {}", path.display(), e))?
+    };
+
+    match json::from_slice(&bytes).map_err(|e| format!("Error parsing genesis file: {e}"))? {
+        SnapshotFile::Versioned(snapshot) if snapshot.version == SNAPSHOT_VERSION => Ok(snapshot),
+        SnapshotFile::Versioned(snapshot) => Err(format!(
+            "Unsupported snapshot version {}, expected {}",
+            snapshot.version, SNAPSHOT_VERSION
+        )),
+        SnapshotFile::Legacy(legacy) => legacy.try_into(),
+    }
+}
+
+impl TryFrom<LegacySnapshot> for Snapshot {
+    type Error = String;
+
+    fn try_from(legacy: LegacySnapshot) -> Result<Self, Self::Error> {
+        let mut subtensor = SubtensorSnapshot::default();
+        for (coldkey_str, hotkeys) in legacy.stakes.iter() {
+            let coldkey = parse_account(coldkey_str)?;
+            for (hotkey_str, (amount, _uid)) in hotkeys.iter() {
+                let hotkey = parse_account(hotkey_str)?;
+                subtensor.hotkeys.push((hotkey.clone(), coldkey.clone()));
+                if *amount > 0 {
+                    subtensor.stakes.push(StakeSnapshot {
+                        hotkey,
+                        coldkey: coldkey.clone(),
+                        netuid: NetUid::ROOT,
+                        alpha: (*amount).into(),
+                    });
+                }
+            }
+        }
+        // Hotkeys staked to by several coldkeys are owned by the first one.
+        subtensor.hotkeys.sort();
+        subtensor.hotkeys.dedup_by(|a, b| a.0 == b.0);
+        subtensor
+            .stakes
+            .sort_by(|a, b| (&a.coldkey, &a.hotkey).cmp(&(&b.coldkey, &b.hotkey)));
+
+        let mut balances = legacy
+            .balances
+            .iter()
+            .map(|(key_str, amount)| Ok((parse_account(key_str)?, *amount)))
+            .collect::<Result<Vec<_>, String>>()?;
+        balances.sort();
+
+        Ok(Self {
+            version: SNAPSHOT_VERSION,
+            block: 0,
+            balances,
+            subtensor,
+        })
+    }
+}
+
+fn parse_account(ss58: &str) -> Result<AccountId, String> {
+    let key = <sr25519::Public as Ss58Codec>::from_ss58check(ss58).map_err(|e| e.to_string())?;
+    Ok(AccountId32::from(key))
+}
@@ -3,39 +3,18 @@
 
 use super::*;
 
-pub fn finney_testnet_config() -> Result<ChainSpec, String> {
+/// Builds the testnet chain spec. Only the sudo account is funded unless `from_snapshot` is set,
+/// in which case the state in `./snapshot.json` is rebuilt in the genesis.
+pub fn finney_testnet_config(from_snapshot: bool) -> Result<ChainSpec, String> {
     let path: PathBuf = std::path::PathBuf::from("./snapshot.json");
     let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
 
-    // We mmap the file into memory first, as this is *a lot* faster than using
-    // `serde_json::from_reader`. See https://github.com/serde-rs/json/issues/160
-    let file = File::open(&path)
-        .map_err(|e| format!("Error opening genesis file `{}`: {}", path.display(), e))?;
-
-    // SAFETY: `mmap` is fundamentally unsafe since technically the file can change
-    //         underneath us while it is mapped; in practice it's unlikely to be a problem
-    let bytes = unsafe {
-        memmap2::Mmap::map(&file)
-            .map_err(|e| format!("Error mmaping genesis file `{}`: {}", path.display(), e))?
+    let snapshot = if from_snapshot {
+        Some(snapshot::load_snapshot(&path)?)
+    } else {
+        None
     };
 
-    let old_state: ColdkeyHotkeys =
-        json::from_slice(&bytes).map_err(|e| format!("Error parsing genesis file: {e}"))?;
-
-    let mut balances_issuance: u64 = 0;
-    let mut processed_balances: Vec<(sp_runtime::AccountId32, u64)> = Vec::new();
-    for (key_str, amount) in old_state.balances.iter() {
-        let key =
-            <sr25519::Public as Ss58Codec>::from_ss58check(key_str).map_err(|e| e.to_string())?;
-        let key_account = sp_runtime::AccountId32::from(key);
-
-        processed_balances.push((key_account, *amount));
-        balances_issuance = balances_issuance
-            .checked_add(*amount)
-            .ok_or("Balances issuance overflowed".to_string())?;
-    }
-    processed_balances.sort();
-
     // Give front-ends necessary data to present to users
     let mut properties = sc_service::Properties::new();
     properties.insert("tokenSymbol".into(), "testTAO".into());
@@ -94,9 +73,7 @@ pub fn finney_testnet_config() -> Result<ChainSpec, String> {
         // Pre-funded accounts
         vec![],
         true,
-        vec![],
-        processed_balances,
-        balances_issuance,
+        snapshot,
     ))
     .with_properties(properties)
     .build())
@@ -109,11 +86,9 @@ fn testnet_genesis(
     root_key: AccountId,
     _endowed_accounts: Vec<AccountId>,
     _enable_println: bool,
-    _stakes: Vec<(AccountId, Vec<(AccountId, (u64, u16))>)>,
-    _balances: Vec<(AccountId, u64)>,
-    _balances_issuance: u64,
+    snapshot: Option<snapshot::Snapshot>,
 ) -> serde_json::Value {
-    serde_json::json!({
+    let mut genesis = serde_json::json!({
         "balances": {
             // Configure sudo balance
             "balances": vec![(root_key.clone(), 1_000_000_000_000u128)],
@@ -128,7 +103,25 @@ fn testnet_genesis(
                 .collect::<Vec<_>>(),
         },
         "sudo": {
-            "key": Some(root_key),
+            "key": Some(root_key.clone()),
         },
-    })
+    });
+
+    if let Some(snapshot) = snapshot {
+        // Keep the sudo balance on top of the snapshot balances
+        let mut balances = snapshot.balances;
+        balances.retain(|(account, _)| *account != root_key);
+        balances.push((root_key, 1_000_000_000_000u64));
+        let balances_issuance = balances.iter().fold(0u64, |issuance, (_, amount)| {
+            issuance.saturating_add(*amount)
+        });
+
+        genesis["balances"] = serde_json::json!({ "balances": balances });
+        genesis["subtensorModule"] = serde_json::json!({
+            "snapshot": snapshot.subtensor,
+            "balancesIssuance": balances_issuance,
+        });
+    }
+
+    genesis
 }
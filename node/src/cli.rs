@@ -36,6 +36,12 @@ pub enum Subcommand {
     // Export the state of a given block into a chain spec.
     ExportState(sc_cli::ExportStateCmd),
 
+    // Export the state of a given block as a snapshot the live chain specs start from.
+    ExportSnapshot(crate::export_snapshot::ExportSnapshotCmd),
+
+    // Turn an exported state into a chain spec runnable with the development keys.
+    ForkState(crate::fork_state::ForkStateCmd),
+
     // Import blocks.
     ImportBlocks(sc_cli::ImportBlocksCmd),
 
@@ -42,11 +42,20 @@ impl SubstrateCli for Cli {
 
     fn load_spec(&self, id: &str) -> Result<Box<dyn sc_service::ChainSpec>, String> {
         Ok(match id {
-            "dev" => Box::new(chain_spec::localnet::localnet_config(true)?),
-            "local" => Box::new(chain_spec::localnet::localnet_config(false)?),
+            "dev" => Box::new(chain_spec::localnet::localnet_config(
+                true,
+                cfg!(feature = "fast-blocks"),
+            )?),
+            "local" => Box::new(chain_spec::localnet::localnet_config(
+                false,
+                cfg!(feature = "fast-blocks"),
+            )?),
+            "dev-accelerated" => Box::new(chain_spec::localnet::localnet_config(true, true)?),
+            "local-accelerated" => Box::new(chain_spec::localnet::localnet_config(false, true)?),
             "finney" => Box::new(chain_spec::finney::finney_mainnet_config()?),
             "devnet" => Box::new(chain_spec::devnet::devnet_config()?),
-            "" | "test_finney" => Box::new(chain_spec::testnet::finney_testnet_config()?),
+            "" | "test_finney" => Box::new(chain_spec::testnet::finney_testnet_config(false)?),
+            "test_finney_snapshot" => Box::new(chain_spec::testnet::finney_testnet_config(true)?),
             path => Box::new(chain_spec::ChainSpec::from_json_file(
                 std::path::PathBuf::from(path),
             )?),
@@ -90,6 +99,15 @@ pub fn run() -> sc_cli::Result<()> {
                 Ok((cmd.run(client, config.chain_spec), task_manager))
             })
         }
+        Some(Subcommand::ExportSnapshot(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.async_run(|mut config| {
+                let (client, _, _, task_manager, _) =
+                    service::new_chain_ops(&mut config, &cli.eth)?;
+                Ok((cmd.run(client), task_manager))
+            })
+        }
+        Some(Subcommand::ForkState(cmd)) => cmd.run(),
         Some(Subcommand::ImportBlocks(cmd)) => {
             let runner = cli.create_runner(cmd)?;
             runner.async_run(|mut config| {
@@ -0,0 +1,109 @@
+//! The `export-snapshot` subcommand: writes the state of a block as a versioned snapshot that
+//! the live chain specs can rebuild into a genesis.
+
+use std::{fs, io::Write, path::PathBuf, sync::Arc};
+
+use node_subtensor_runtime::{Runtime, SubtensorModule, opaque::Block};
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::{StorageProvider, UsageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+    hashing::twox_128,
+    storage::{Storage, StorageKey},
+};
+
+use crate::chain_spec::snapshot::{SNAPSHOT_VERSION, Snapshot};
+use crate::client::FullClient;
+
+/// Pallets whose storage is read to build the snapshot.
+const SNAPSHOT_PALLETS: &[&str] = &["System", "SubtensorModule"];
+
+/// The `export-snapshot` command.
+#[derive(Debug, clap::Parser)]
+pub struct ExportSnapshotCmd {
+    /// Block hash or number to snapshot, the best block if omitted.
+    #[arg(value_name = "HASH or NUMBER")]
+    pub input: Option<BlockNumberOrHash>,
+
+    /// Where to write the snapshot, standard output if omitted.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+impl ExportSnapshotCmd {
+    /// Reads the state of the requested block and writes it as a snapshot.
+    pub async fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+        let hash = match &self.input {
+            Some(input) => client.expect_block_hash_from_id(&input.parse::<Block>()?)?,
+            None => client.usage_info().chain.best_hash,
+        };
+
+        let mut top = std::collections::BTreeMap::new();
+        for pallet in SNAPSHOT_PALLETS {
+            let prefix = StorageKey(twox_128(pallet.as_bytes()).to_vec());
+            top.extend(
+                client
+                    .storage_pairs(hash, Some(&prefix), None)?
+                    .map(|(key, value)| (key.0, value.0)),
+            );
+        }
+
+        // Decode the state with the runtime types rather than by hand.
+        let mut ext = sp_io::TestExternalities::new(Storage {
+            top,
+            children_default: Default::default(),
+        });
+        let snapshot = ext.execute_with(|| {
+            let mut balances: Vec<_> = frame_system::Account::<Runtime>::iter()
+                .map(|(account, info)| {
+                    let balance = info.data.free.saturating_add(info.data.reserved);
+                    (account, balance)
+                })
+                .filter(|(_, balance)| *balance > 0)
+                .collect();
+            balances.sort();
+
+            Snapshot {
+                version: SNAPSHOT_VERSION,
+                block: frame_system::Pallet::<Runtime>::block_number().into(),
+                balances,
+                subtensor: SubtensorModule::take_snapshot(),
+            }
+        });
+
+        let json = serde_json::to_string_pretty(&snapshot)
+            .map_err(|e| format!("Unable to encode the snapshot: {e}"))?;
+        match &self.output {
+            Some(path) => fs::write(path, json)?,
+            None => std::io::stdout().write_all(json.as_bytes())?,
+        }
+
+        Ok(())
+    }
+}
+
+impl CliConfiguration for ExportSnapshotCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
@@ -0,0 +1,247 @@
+//! The `fork-state` subcommand: turns an exported raw state into a runnable local chain spec.
+//!
+//! The forked state is kept as is, except for the consensus authorities, the sudo key and the
+//! drand beacon, which are taken from the local development genesis so the fork can be run by
+//! the development keys. Development accounts missing from the fork are funded so they can
+//! submit transactions.
+//!
+//! The fork restarts at block 0, so the subtensor items recording blocks of the live chain are
+//! reset, as if it all happened at genesis. Otherwise weights, rate limited calls and pending
+//! children would wait for the live block number to come around again. Last transaction blocks
+//! are removed, which rate limits treat as never, and the other block numbers are zeroed. Pending
+//! weight commits are dropped, as their reveal epochs are never reached.
+
+use std::{collections::BTreeMap, fs, io::Write, path::PathBuf};
+
+use scale_codec::{Compact, Decode};
+use serde_json::Value;
+use sp_core::hashing::twox_128;
+
+use crate::chain_spec;
+
+/// Pallets whose whole storage is replaced by the local development genesis.
+const REPLACED_PALLETS: &[&str] = &["Aura", "Grandpa", "Sudo", "Drand"];
+
+/// Well-known key of the GRANDPA authority set.
+const GRANDPA_AUTHORITIES_KEY: &[u8] = b":grandpa_authorities";
+
+/// Well-known key of the runtime code.
+const CODE_KEY: &[u8] = b":code";
+
+/// Storage values holding the total issuance, raised by the funds given to development
+/// accounts.
+const ISSUANCE_KEYS: &[(&str, &str)] = &[
+    ("Balances", "TotalIssuance"),
+    ("SubtensorModule", "TotalIssuance"),
+];
+
+/// How the block numbers of the live chain held by a storage item are reset.
+#[derive(Clone, Copy)]
+enum BlockNumbers {
+    /// The entries are removed.
+    Remove,
+    /// The value is a block number, set to zero.
+    Value,
+    /// The value is a vector of block numbers, all set to zero.
+    Vec,
+    /// The value ends with a block number, set to zero.
+    Trailing,
+}
+
+/// Subtensor storage items holding block numbers of the live chain.
+const BLOCK_NUMBER_ITEMS: &[(&str, BlockNumbers)] = &[
+    ("LastTxBlock", BlockNumbers::Remove),
+    ("LastTxBlockChildKeyTake", BlockNumbers::Remove),
+    ("LastTxBlockDelegateTake", BlockNumbers::Remove),
+    ("TransactionKeyLastBlock", BlockNumbers::Remove),
+    ("LastRateLimitedBlock", BlockNumbers::Remove),
+    ("LastHotkeySwapOnNetuid", BlockNumbers::Remove),
+    ("LastColdkeyHotkeyStakeBlock", BlockNumbers::Remove),
+    ("NetworkLastRegistered", BlockNumbers::Remove),
+    ("WeightCommits", BlockNumbers::Remove),
+    ("NetworkRegisteredAt", BlockNumbers::Value),
+    ("BlockAtRegistration", BlockNumbers::Value),
+    ("LastAdjustmentBlock", BlockNumbers::Value),
+    ("LastMechansimStepBlock", BlockNumbers::Value),
+    ("LastUpdate", BlockNumbers::Vec),
+    ("PendingChildKeys", BlockNumbers::Trailing),
+];
+
+/// Raw storage, hex-encoded keys to hex-encoded values.
+type RawStorage = BTreeMap<String, String>;
+
+/// The `fork-state` command.
+#[derive(Debug, clap::Parser)]
+pub struct ForkStateCmd {
+    /// Raw state to fork, either a chain spec written by `export-state` or a JSON object of
+    /// hex-encoded storage keys and values.
+    #[arg(value_name = "STATE")]
+    pub input: PathBuf,
+
+    /// Where to write the chain spec, standard output if omitted.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    /// Run the fork with Alice and Bob as authorities instead of Alice alone.
+    #[arg(long)]
+    pub two_authorities: bool,
+
+    /// Replace the forked runtime with the one built into this node, so that its migrations
+    /// run on the first block of the fork.
+    #[arg(long)]
+    pub local_runtime: bool,
+}
+
+impl ForkStateCmd {
+    /// Writes a chain spec whose genesis is the forked state.
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let state: Value = serde_json::from_slice(&fs::read(&self.input)?)
+            .map_err(|e| format!("Invalid state file: {e}"))?;
+        let mut top = top_storage(&state)?;
+
+        let dev_spec = chain_spec::localnet::localnet_config(!self.two_authorities, true)?;
+        let mut spec: Value = serde_json::from_str(&dev_spec.as_json(true)?)
+            .map_err(|e| format!("Invalid development chain spec: {e}"))?;
+        let dev_top = top_storage(&spec)?;
+
+        let mut replaced: Vec<String> = REPLACED_PALLETS
+            .iter()
+            .map(|pallet| encode(twox_128(pallet.as_bytes())))
+            .collect();
+        replaced.push(encode(GRANDPA_AUTHORITIES_KEY));
+        if self.local_runtime {
+            replaced.push(encode(CODE_KEY));
+        }
+        let is_replaced = |key: &String| replaced.iter().any(|prefix| key.starts_with(prefix));
+
+        top.retain(|key, _| !is_replaced(key));
+        top.extend(
+            dev_top
+                .iter()
+                .filter(|(key, _)| is_replaced(key))
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+        fund_dev_accounts(&mut top, &dev_top)?;
+        reset_block_numbers(&mut top)?;
+
+        spec["name"] = "Bittensor Fork".into();
+        spec["id"] = "bittensor_fork".into();
+        spec["bootNodes"] = Value::Array(Vec::new());
+        spec["genesis"]["raw"]["top"] = serde_json::to_value(top)
+            .map_err(|e| format!("Unable to encode the forked state: {e}"))?;
+        if let Some(children) = state.pointer("/genesis/raw/childrenDefault") {
+            spec["genesis"]["raw"]["childrenDefault"] = children.clone();
+        }
+
+        let json = serde_json::to_string_pretty(&spec)
+            .map_err(|e| format!("Unable to encode the chain spec: {e}"))?;
+        match &self.output {
+            Some(path) => fs::write(path, json)?,
+            None => std::io::stdout().write_all(json.as_bytes())?,
+        }
+
+        Ok(())
+    }
+}
+
+/// The top-level raw storage of a raw chain spec, or of a plain key-value state file.
+fn top_storage(state: &Value) -> Result<RawStorage, String> {
+    let top = state
+        .pointer("/genesis/raw/top")
+        .unwrap_or(state)
+        .as_object()
+        .ok_or("Expected a raw chain spec or a JSON object of storage keys and values")?;
+
+    top.iter()
+        .map(|(key, value)| {
+            let value = value
+                .as_str()
+                .ok_or_else(|| format!("Storage value of {key} is not a hex string"))?;
+            Ok((key.to_lowercase(), value.to_lowercase()))
+        })
+        .collect()
+}
+
+/// Copies the development accounts missing from `top` and adds their free balance to the
+/// total issuance.
+fn fund_dev_accounts(top: &mut RawStorage, dev_top: &RawStorage) -> Result<(), String> {
+    let accounts = encode(storage_prefix("System", "Account"));
+    let mut minted: u64 = 0;
+
+    for (key, value) in dev_top.iter().filter(|(key, _)| key.starts_with(&accounts)) {
+        if top.contains_key(key) {
+            continue;
+        }
+        // `AccountInfo` starts with four `u32` counters, followed by the free balance.
+        let free = decode(value)?
+            .get(16..24)
+            .and_then(|free| free.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or("Malformed development account")?;
+        minted = minted.saturating_add(free);
+        top.insert(key.clone(), value.clone());
+    }
+
+    for (pallet, storage) in ISSUANCE_KEYS {
+        let key = encode(storage_prefix(pallet, storage));
+        let Some(value) = top.get(&key) else {
+            continue;
+        };
+        let issuance = decode(value)?
+            .try_into()
+            .map(u64::from_le_bytes)
+            .map_err(|_| format!("Malformed {pallet}::{storage}"))?;
+        top.insert(key, encode(issuance.saturating_add(minted).to_le_bytes()));
+    }
+
+    Ok(())
+}
+
+/// Resets the block numbers of the live chain kept by the subtensor storage, as the fork restarts
+/// at block 0.
+fn reset_block_numbers(top: &mut RawStorage) -> Result<(), String> {
+    for (storage, block_numbers) in BLOCK_NUMBER_ITEMS {
+        let prefix = encode(storage_prefix("SubtensorModule", storage));
+
+        if let BlockNumbers::Remove = block_numbers {
+            top.retain(|key, _| !key.starts_with(&prefix));
+            continue;
+        }
+
+        let entries = top
+            .range_mut(prefix.clone()..)
+            .take_while(|(key, _)| key.starts_with(&prefix));
+        for (_, value) in entries {
+            let mut bytes = decode(value)?;
+            let start = match block_numbers {
+                BlockNumbers::Vec => {
+                    let mut input = bytes.as_slice();
+                    Compact::<u32>::decode(&mut input)
+                        .map_err(|_| format!("Malformed SubtensorModule::{storage}"))?;
+                    bytes.len().saturating_sub(input.len())
+                }
+                BlockNumbers::Trailing => bytes
+                    .len()
+                    .checked_sub(8)
+                    .ok_or_else(|| format!("Malformed SubtensorModule::{storage}"))?,
+                BlockNumbers::Remove | BlockNumbers::Value => 0,
+            };
+            bytes.iter_mut().skip(start).for_each(|byte| *byte = 0);
+            *value = encode(bytes);
+        }
+    }
+
+    Ok(())
+}
+
+fn storage_prefix(pallet: &str, storage: &str) -> Vec<u8> {
+    [twox_128(pallet.as_bytes()), twox_128(storage.as_bytes())].concat()
+}
+
+fn encode(bytes: impl AsRef<[u8]>) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, String> {
+    hex::decode(value.trim_start_matches("0x")).map_err(|e| format!("Invalid hex {value}: {e}"))
+}
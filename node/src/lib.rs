@@ -2,5 +2,7 @@ pub mod chain_spec;
 pub mod cli;
 pub mod client;
 pub mod ethereum;
+pub mod export_snapshot;
+pub mod fork_state;
 pub mod rpc;
 pub mod service;
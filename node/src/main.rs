@@ -10,6 +10,8 @@ mod cli;
 mod client;
 mod command;
 mod ethereum;
+mod export_snapshot;
+mod fork_state;
 mod rpc;
 
 fn main() -> sc_cli::Result<()> {
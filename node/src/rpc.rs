@@ -5,6 +5,9 @@
 
 #![warn(missing_docs)]
 
+pub mod dev;
+pub mod metagraph_diff;
+
 use std::{collections::BTreeMap, sync::Arc};
 
 use futures::channel::mpsc;
@@ -88,8 +91,12 @@ pub struct FullDeps<P, CT, CIDP> {
     pub client: Arc<FullClient>,
     /// Transaction pool instance.
     pub pool: Arc<P>,
+    /// Backend instance to use.
+    pub backend: Arc<FullBackend>,
     /// Manual seal command sink
     pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
+    /// Clock of the manual-seal blocks.
+    pub dev_clock: dev::DevClock,
     /// Ethereum-compatibility specific dependencies.
     pub eth: EthDeps<P, CT, CIDP>,
 }
@@ -115,6 +122,8 @@ where
     CIDP: CreateInherentDataProviders<Block, ()> + Send + Clone + 'static,
     CT: fp_rpc::ConvertTransaction<<Block as BlockT>::Extrinsic> + Send + Sync + Clone + 'static,
 {
+    use dev::{Dev, DevApiServer};
+    use metagraph_diff::{MetagraphDiffApiServer, MetagraphSubscriptions};
     use pallet_subtensor_swap_rpc::{Swap, SwapRpcApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
@@ -124,19 +133,26 @@ where
     let mut module = RpcModule::new(());
     let FullDeps {
         client,
+        backend,
         pool,
         command_sink,
+        dev_clock,
         eth,
     } = deps;
 
     // Custom RPC methods for Paratensor
     module.merge(SubtensorCustom::new(client.clone()).into_rpc())?;
 
+    // Metagraph diffs pushed on finalized blocks
+    module.merge(
+        MetagraphSubscriptions::new(client.clone(), subscription_task_executor.clone()).into_rpc(),
+    )?;
+
     // Swap RPC
     module.merge(Swap::new(client.clone()).into_rpc())?;
 
     module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
@@ -147,8 +163,9 @@ where
         module.merge(
             // We provide the rpc handler with the sending end of the channel to allow the rpc
             // send EngineCommands to the background block authorship task.
-            ManualSeal::new(command_sink).into_rpc(),
+            ManualSeal::new(command_sink.clone()).into_rpc(),
         )?;
+        module.merge(Dev::new(client, backend, command_sink, dev_clock).into_rpc())?;
     }
 
     // Ethereum compatibility RPCs
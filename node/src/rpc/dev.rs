@@ -0,0 +1,198 @@
+//! The `dev` RPC namespace, only served with `--sealing`, to drive a manual-seal chain from
+//! integration tests: produce many blocks at once, move the clock, and roll the chain back to
+//! an earlier snapshot.
+
+use std::sync::{
+    Arc, Mutex, PoisonError,
+    atomic::{AtomicU64, Ordering},
+};
+
+use futures::{SinkExt, channel::mpsc, channel::oneshot};
+use jsonrpsee::{
+    core::{RpcResult, async_trait},
+    proc_macros::rpc,
+    types::{ErrorObject, ErrorObjectOwned},
+};
+use sc_client_api::Backend;
+use sc_consensus_manual_seal::EngineCommand;
+use sp_blockchain::HeaderBackend;
+use subtensor_runtime_common::{Hash, time::SLOT_DURATION};
+
+use crate::client::{FullBackend, FullClient};
+
+/// Timestamp of the last manual-seal block, in milliseconds. Each sealed block moves it forward
+/// by one slot.
+#[derive(Clone, Default)]
+pub struct DevClock(Arc<AtomicU64>);
+
+impl DevClock {
+    /// Moves the clock to the next block and returns its timestamp.
+    pub fn advance(&self) -> u64 {
+        self.0
+            .fetch_add(SLOT_DURATION, Ordering::SeqCst)
+            .saturating_add(SLOT_DURATION)
+    }
+
+    /// Timestamp of the last block.
+    pub fn now(&self) -> u64 {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    fn set(&self, timestamp: u64) {
+        self.0.store(timestamp, Ordering::SeqCst);
+    }
+}
+
+/// Dev RPC methods.
+#[rpc(server)]
+pub trait DevApi {
+    /// Seals `count` blocks, empty unless transactions are pending, and returns the hash of the
+    /// last one.
+    #[method(name = "dev_advanceBlocks")]
+    async fn advance_blocks(&self, count: u32) -> RpcResult<Hash>;
+
+    /// Sets the timestamp, in milliseconds, of the next sealed block. Later blocks keep moving
+    /// one slot at a time from there.
+    #[method(name = "dev_setTimestamp")]
+    fn set_timestamp(&self, timestamp: u64) -> RpcResult<()>;
+
+    /// Records the current best block and clock, returning an id to pass to `dev_revert`.
+    #[method(name = "dev_snapshot")]
+    fn snapshot(&self) -> RpcResult<u32>;
+
+    /// Reverts the chain and the clock to a snapshot. The snapshot and every later one are
+    /// discarded.
+    #[method(name = "dev_revert")]
+    fn revert(&self, id: u32) -> RpcResult<()>;
+}
+
+/// A snapshot taken by `dev_snapshot`.
+struct DevSnapshot {
+    hash: Hash,
+    number: u32,
+    timestamp: u64,
+}
+
+/// Implements the dev RPC methods over the manual-seal authorship task.
+pub struct Dev {
+    client: Arc<FullClient>,
+    backend: Arc<FullBackend>,
+    command_sink: mpsc::Sender<EngineCommand<Hash>>,
+    clock: DevClock,
+    snapshots: Mutex<Vec<DevSnapshot>>,
+}
+
+impl Dev {
+    /// Creates a new instance of the dev RPC handler.
+    pub fn new(
+        client: Arc<FullClient>,
+        backend: Arc<FullBackend>,
+        command_sink: mpsc::Sender<EngineCommand<Hash>>,
+        clock: DevClock,
+    ) -> Self {
+        Self {
+            client,
+            backend,
+            command_sink,
+            clock,
+            snapshots: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl DevApiServer for Dev {
+    async fn advance_blocks(&self, count: u32) -> RpcResult<Hash> {
+        let mut sink = self.command_sink.clone();
+        let mut last = self.client.info().best_hash;
+
+        for _ in 0..count {
+            let (sender, receiver) = oneshot::channel();
+            sink.send(EngineCommand::SealNewBlock {
+                create_empty: true,
+                finalize: true,
+                parent_hash: None,
+                sender: Some(sender),
+            })
+            .await
+            .map_err(|e| error(format!("Unable to reach the authorship task: {e}")))?;
+
+            last = receiver
+                .await
+                .map_err(|e| error(format!("Authorship task dropped the request: {e}")))?
+                .map_err(|e| error(format!("Unable to seal a block: {e}")))?
+                .hash;
+        }
+
+        Ok(last)
+    }
+
+    fn set_timestamp(&self, timestamp: u64) -> RpcResult<()> {
+        // The runtime rejects blocks less than half a slot apart.
+        let earliest = self.clock.now().saturating_add(SLOT_DURATION / 2);
+        if timestamp < earliest {
+            return Err(error(format!(
+                "Timestamp {timestamp} is before the earliest allowed one, {earliest}"
+            )));
+        }
+
+        self.clock.set(timestamp.saturating_sub(SLOT_DURATION));
+        Ok(())
+    }
+
+    fn snapshot(&self) -> RpcResult<u32> {
+        let info = self.client.info();
+        let mut snapshots = self
+            .snapshots
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        snapshots.push(DevSnapshot {
+            hash: info.best_hash,
+            number: info.best_number,
+            timestamp: self.clock.now(),
+        });
+
+        u32::try_from(snapshots.len().saturating_sub(1))
+            .map_err(|_| error("Too many snapshots".into()))
+    }
+
+    fn revert(&self, id: u32) -> RpcResult<()> {
+        let mut snapshots = self
+            .snapshots
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let index = usize::try_from(id).map_err(|_| error(format!("Unknown snapshot {id}")))?;
+        let snapshot = snapshots
+            .get(index)
+            .ok_or_else(|| error(format!("Unknown snapshot {id}")))?;
+
+        let canonical = self
+            .client
+            .hash(snapshot.number)
+            .map_err(|e| error(e.to_string()))?;
+        if canonical != Some(snapshot.hash) {
+            return Err(error(format!("Snapshot {id} is not on the best chain")));
+        }
+
+        let blocks = self
+            .client
+            .info()
+            .best_number
+            .saturating_sub(snapshot.number);
+        let (reverted, _) = self
+            .backend
+            .revert(blocks, true)
+            .map_err(|e| error(format!("Unable to revert: {e}")))?;
+        if reverted != blocks {
+            return Err(error(format!("Reverted {reverted} blocks out of {blocks}")));
+        }
+
+        self.clock.set(snapshot.timestamp);
+        snapshots.truncate(index);
+        Ok(())
+    }
+}
+
+fn error(message: String) -> ErrorObjectOwned {
+    ErrorObject::owned(1, message, None::<()>)
+}
@@ -0,0 +1,375 @@
+//! `subnetInfo_subscribeMetagraphDiff`, a pubsub variant of `subnetInfo_getSelectiveMetagraph`
+//! that only pushes the UIDs and fields that changed in each finalized block.
+//!
+//! The first notification carries the whole selection. After that, subnet-level fields are
+//! re-read on every finalized block, while per-UID fields are re-read only when a subtensor
+//! event in the newly finalized blocks may have touched them, or when an epoch has run on the
+//! subnet (its `last_step` moved). Values are rendered with the v1 JSON views of
+//! [`subtensor_custom_rpc::json`], so a diff can be applied directly on top of the output of
+//! `subnetInfo_getSelectiveMetagraphJson_v1`, truncating the per-UID fields to `num_uids` when
+//! the diff carries it.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use futures::{StreamExt, future};
+use jsonrpsee::{PendingSubscriptionSink, proc_macros::rpc, types::ErrorObject};
+use node_subtensor_runtime::{
+    Runtime, RuntimeEvent, StorageValue,
+    opaque::Block,
+    pallet_subtensor::{
+        Event as SubtensorEvent, rpc_info::metagraph::SelectiveMetagraphIndex as Index,
+    },
+};
+use sc_client_api::{BlockchainEvents, FinalityNotification, StorageProvider};
+use sc_rpc::{
+    SubscriptionTaskExecutor,
+    utils::{pipe_from_stream, spawn_subscription_task},
+};
+use scale_codec::Decode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sp_api::ProvideRuntimeApi;
+use sp_core::storage::StorageKey;
+use sp_runtime::traits::Header as _;
+use subtensor_custom_rpc::json::v1;
+use subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi;
+use subtensor_runtime_common::{Hash, NetUid};
+
+use crate::client::FullClient;
+
+/// Number of `SelectiveMetagraphIndex` variants.
+const INDEX_COUNT: u16 = Index::ValidatorWeightMetrics as u16 + 1;
+
+/// Indices whose value is a vector with one entry per UID.
+const PER_UID: &[u16] = &[
+    Index::Hotkeys as u16,
+    Index::Coldkeys as u16,
+    Index::Identities as u16,
+    Index::Axons as u16,
+    Index::Active as u16,
+    Index::ValidatorPermit as u16,
+    Index::PruningScore as u16,
+    Index::LastUpdate as u16,
+    Index::Emission as u16,
+    Index::Dividends as u16,
+    Index::Incentives as u16,
+    Index::Consensus as u16,
+    Index::Trust as u16,
+    Index::Rank as u16,
+    Index::BlockAtRegistration as u16,
+    Index::AlphaStake as u16,
+    Index::TaoStake as u16,
+    Index::TotalStake as u16,
+    Index::AxonEndpoints as u16,
+    Index::IdentityAttestations as u16,
+    Index::ValidatorWeightMetrics as u16,
+];
+
+/// JSON keys of the [`PER_UID`] fields in [`v1::Metagraph`].
+const PER_UID_KEYS: &[&str] = &[
+    "hotkeys",
+    "coldkeys",
+    "identities",
+    "axons",
+    "active",
+    "validator_permit",
+    "pruning_score",
+    "last_update",
+    "emission",
+    "dividends",
+    "incentives",
+    "consensus",
+    "trust",
+    "rank",
+    "block_at_registration",
+    "alpha_stake",
+    "tao_stake",
+    "total_stake",
+    "axon_endpoints",
+    "identity_attestations",
+    "validator_weight_metrics",
+];
+
+const AXON_FIELDS: &[u16] = &[Index::Axons as u16, Index::AxonEndpoints as u16];
+const WEIGHT_FIELDS: &[u16] = &[Index::LastUpdate as u16];
+const STAKE_FIELDS: &[u16] = &[
+    Index::AlphaStake as u16,
+    Index::TaoStake as u16,
+    Index::TotalStake as u16,
+];
+const IDENTITY_FIELDS: &[u16] = &[Index::Identities as u16];
+const ATTESTATION_FIELDS: &[u16] = &[Index::IdentityAttestations as u16];
+
+/// Changes to a subnet's metagraph in one finalized block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetagraphDiff {
+    /// The subnet.
+    pub netuid: u16,
+    /// Number of the finalized block.
+    pub block_number: u32,
+    /// Hash of the finalized block.
+    pub block_hash: Hash,
+    /// Subnet-level fields that changed, keyed like [`v1::Metagraph`].
+    pub subnet: Map<String, Value>,
+    /// Per-UID fields that changed, keyed by UID and then like [`v1::Metagraph`]. When the
+    /// per-UID fields shrink, `num_uids` is set in `subnet` to the new length, even if it was not
+    /// selected, and the UIDs past it are to be dropped.
+    pub uids: BTreeMap<u16, Map<String, Value>>,
+}
+
+/// Metagraph pubsub RPC methods.
+#[rpc(server)]
+pub trait MetagraphDiffApi {
+    /// Subscribe to changes of the selected metagraph `fields` (`SelectiveMetagraphIndex`
+    /// values, all of them if omitted) of `netuid`, one notification per finalized block
+    /// in which something changed.
+    #[subscription(
+        name = "subnetInfo_subscribeMetagraphDiff" => "subnetInfo_metagraphDiff",
+        unsubscribe = "subnetInfo_unsubscribeMetagraphDiff",
+        item = MetagraphDiff,
+    )]
+    fn subscribe_metagraph_diff(&self, netuid: NetUid, fields: Option<Vec<u16>>);
+}
+
+/// Metagraph diff subscriptions.
+pub struct MetagraphSubscriptions {
+    client: Arc<FullClient>,
+    executor: SubscriptionTaskExecutor,
+}
+
+impl MetagraphSubscriptions {
+    /// Create new metagraph diff subscriptions.
+    pub fn new(client: Arc<FullClient>, executor: SubscriptionTaskExecutor) -> Self {
+        Self { client, executor }
+    }
+}
+
+impl MetagraphDiffApiServer for MetagraphSubscriptions {
+    fn subscribe_metagraph_diff(
+        &self,
+        pending: PendingSubscriptionSink,
+        netuid: NetUid,
+        fields: Option<Vec<u16>>,
+    ) {
+        let fields = fields.unwrap_or_else(|| (0..INDEX_COUNT).collect());
+        if let Some(index) = fields.iter().find(|index| **index >= INDEX_COUNT) {
+            let error =
+                ErrorObject::owned(1, format!("Unknown metagraph index: {}", index), None::<()>);
+            spawn_subscription_task(&self.executor, pending.reject(error));
+            return;
+        }
+
+        let client = self.client.clone();
+        let mut differ = MetagraphDiffer::new(netuid, fields);
+        let stream = self
+            .client
+            .finality_notification_stream()
+            .filter_map(move |notification| future::ready(differ.next(&client, &notification)));
+
+        spawn_subscription_task(&self.executor, pipe_from_stream(pending, stream));
+    }
+}
+
+/// Per-subscription state, the last value sent for every selected field.
+struct MetagraphDiffer {
+    netuid: NetUid,
+    /// Selected subnet-level indices, always followed by `LastStep`.
+    subnet_fields: Vec<u16>,
+    /// Selected per-UID indices.
+    uid_fields: BTreeSet<u16>,
+    last_step_selected: bool,
+    /// `None` until the first notification.
+    last_step: Option<Value>,
+    state: Map<String, Value>,
+}
+
+impl MetagraphDiffer {
+    fn new(netuid: NetUid, fields: Vec<u16>) -> Self {
+        let last_step = Index::LastStep as u16;
+        let last_step_selected = fields.contains(&last_step);
+        let (uid_fields, mut subnet_fields): (Vec<u16>, Vec<u16>) = fields
+            .into_iter()
+            .partition(|index| PER_UID.contains(index));
+        subnet_fields.retain(|index| *index != last_step);
+        subnet_fields.push(last_step);
+
+        Self {
+            netuid,
+            subnet_fields,
+            uid_fields: uid_fields.into_iter().collect(),
+            last_step_selected,
+            last_step: None,
+            state: Map::new(),
+        }
+    }
+
+    fn next(
+        &mut self,
+        client: &FullClient,
+        notification: &FinalityNotification<Block>,
+    ) -> Option<MetagraphDiff> {
+        let hash = notification.hash;
+        let mut fields = self.read(client, hash, self.subnet_fields.clone())?;
+
+        let last_step = fields.get("last_step").cloned();
+        if !self.last_step_selected {
+            fields.remove("last_step");
+        }
+
+        // Re-read every per-UID field on the first notification, after an epoch, or when the
+        // events of the newly finalized blocks can't be decoded.
+        let uid_fields: Vec<u16> = match self.last_step.replace(last_step.clone()?) {
+            Some(previous) if Some(&previous) == last_step.as_ref() => notification
+                .tree_route
+                .iter()
+                .chain(std::iter::once(&hash))
+                .map(|hash| events(client, *hash))
+                .collect::<Option<Vec<_>>>()
+                .map(|events| {
+                    events
+                        .iter()
+                        .flatten()
+                        .flat_map(|event| touched_fields(self.netuid, event))
+                        .filter(|index| self.uid_fields.contains(*index))
+                        .copied()
+                        .collect::<BTreeSet<_>>()
+                        .into_iter()
+                        .collect()
+                })
+                .unwrap_or_else(|| self.uid_fields.iter().copied().collect()),
+            _ => self.uid_fields.iter().copied().collect(),
+        };
+        if !uid_fields.is_empty() {
+            fields.extend(self.read(client, hash, uid_fields)?);
+        }
+
+        let (subnet, uids) = self.diff(fields);
+        if subnet.is_empty() && uids.is_empty() {
+            return None;
+        }
+
+        Some(MetagraphDiff {
+            netuid: self.netuid.into(),
+            block_number: *notification.header.number(),
+            block_hash: hash,
+            subnet,
+            uids,
+        })
+    }
+
+    /// Reads the given indices at `hash` and renders them as v1 JSON, without `netuid`.
+    fn read(
+        &self,
+        client: &FullClient,
+        hash: Hash,
+        indexes: Vec<u16>,
+    ) -> Option<Map<String, Value>> {
+        let metagraph = client
+            .runtime_api()
+            .get_selective_metagraph(hash, self.netuid, indexes)
+            .ok()
+            .flatten()?;
+        let Ok(Value::Object(mut fields)) = serde_json::to_value(v1::Metagraph::from(metagraph))
+        else {
+            return None;
+        };
+        fields.remove("netuid");
+        Some(fields)
+    }
+
+    /// Records `fields` as the latest state and returns the subnet-level and per-UID values
+    /// that differ from what was sent before.
+    fn diff(
+        &mut self,
+        fields: Map<String, Value>,
+    ) -> (Map<String, Value>, BTreeMap<u16, Map<String, Value>>) {
+        let mut subnet = Map::new();
+        let mut uids = BTreeMap::<u16, Map<String, Value>>::new();
+
+        for (key, value) in fields {
+            let previous = self.state.insert(key.clone(), value.clone());
+            if previous.as_ref() == Some(&value) {
+                continue;
+            }
+
+            match value {
+                Value::Array(values) if PER_UID_KEYS.contains(&key.as_str()) => {
+                    let previous = match previous {
+                        Some(Value::Array(previous)) => previous,
+                        _ => Vec::new(),
+                    };
+                    // Trailing UIDs that are gone can't be sent per UID, so the new length is.
+                    if previous.len() > values.len() {
+                        subnet.insert("num_uids".into(), values.len().into());
+                    }
+                    for (uid, value) in values.into_iter().enumerate() {
+                        if previous.get(uid) == Some(&value) {
+                            continue;
+                        }
+                        let Ok(uid) = u16::try_from(uid) else {
+                            break;
+                        };
+                        uids.entry(uid).or_default().insert(key.clone(), value);
+                    }
+                }
+                value => {
+                    subnet.insert(key, value);
+                }
+            }
+        }
+
+        (subnet, uids)
+    }
+}
+
+/// The runtime events deposited in block `hash`, `None` if they can't be read.
+fn events(client: &FullClient, hash: Hash) -> Option<Vec<RuntimeEvent>> {
+    let key = StorageKey(frame_system::Events::<Runtime>::hashed_key().to_vec());
+    let Some(data) = client.storage(hash, &key).ok()? else {
+        return Some(Vec::new());
+    };
+    let records =
+        Vec::<frame_system::EventRecord<RuntimeEvent, Hash>>::decode(&mut &data.0[..]).ok()?;
+    Some(records.into_iter().map(|record| record.event).collect())
+}
+
+/// The per-UID fields of `netuid` that `event` may have changed.
+fn touched_fields(netuid: NetUid, event: &RuntimeEvent) -> &'static [u16] {
+    let RuntimeEvent::SubtensorModule(event) = event else {
+        return &[];
+    };
+
+    match event {
+        SubtensorEvent::NeuronRegistered(n, ..)
+        | SubtensorEvent::HotkeySwappedOnSubnet { netuid: n, .. }
+            if *n == netuid =>
+        {
+            PER_UID
+        }
+        SubtensorEvent::BulkNeuronsRegistered(n, _) if NetUid::from(*n) == netuid => PER_UID,
+        SubtensorEvent::HotkeySwapped { .. } | SubtensorEvent::ColdkeySwapped { .. } => PER_UID,
+        SubtensorEvent::AxonServed(n, _)
+        | SubtensorEvent::AxonEndpointsServed { netuid: n, .. }
+            if *n == netuid =>
+        {
+            AXON_FIELDS
+        }
+        SubtensorEvent::WeightsSet(n, _) if *n == netuid => WEIGHT_FIELDS,
+        // Stake on root counts towards the TAO stake of every subnet.
+        SubtensorEvent::StakeAdded(..)
+        | SubtensorEvent::StakeRemoved(..)
+        | SubtensorEvent::StakeMoved(..)
+        | SubtensorEvent::StakeTransferred(..)
+        | SubtensorEvent::StakeSwapped(..)
+        | SubtensorEvent::AlphaRecycled(..)
+        | SubtensorEvent::AlphaBurned(..) => STAKE_FIELDS,
+        SubtensorEvent::ChainIdentitySet(_) => IDENTITY_FIELDS,
+        SubtensorEvent::IdentityAttested { .. }
+        | SubtensorEvent::IdentityAttestationRevoked { .. }
+        | SubtensorEvent::IdentityAttesterRemoved(_) => ATTESTATION_FIELDS,
+        _ => &[],
+    }
+}
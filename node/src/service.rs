@@ -20,8 +20,8 @@ use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
 use sp_core::H256;
 use sp_runtime::traits::{Block as BlockT, Header, NumberFor};
 use std::collections::HashSet;
+use std::path::Path;
 use std::str::FromStr;
-use std::{cell::RefCell, path::Path};
 use std::{marker::PhantomData, sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
 
@@ -32,6 +32,7 @@ use crate::ethereum::{
     StorageOverride, StorageOverrideHandler, db_config_dir, new_frontier_partial,
     spawn_frontier_tasks,
 };
+use crate::rpc::dev::DevClock;
 
 /// The minimum period of blocks on which justifications will be
 /// imported and generated.
@@ -494,6 +495,8 @@ where
 
     // Channel for the rpc handler to communicate with the authorship task.
     let (command_sink, commands_stream) = mpsc::channel(1000);
+    // Clock of the manual-seal blocks, programmable through the dev RPC.
+    let dev_clock = crate::rpc::dev::DevClock::default();
 
     // Sinks for pubsub notifications.
     // Everytime a new subscription is created, a new mpsc channel is added to the sink pool.
@@ -509,6 +512,8 @@ where
 
     let rpc_builder = {
         let client = client.clone();
+        let backend = backend.clone();
+        let dev_clock = dev_clock.clone();
         let pool = transaction_pool.clone();
         let network = network.clone();
         let sync_service = sync_service.clone();
@@ -571,12 +576,14 @@ where
             };
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
+                backend: backend.clone(),
                 pool: pool.clone(),
                 command_sink: if sealing.is_some() {
                     Some(command_sink.clone())
                 } else {
                     None
                 },
+                dev_clock: dev_clock.clone(),
                 eth: eth_deps,
             };
             crate::rpc::create_full(
@@ -630,6 +637,7 @@ where
                 prometheus_registry.as_ref(),
                 telemetry.as_ref(),
                 commands_stream,
+                dev_clock,
             )?;
 
             network_starter.start_network();
@@ -790,6 +798,7 @@ fn run_manual_seal_authorship(
     commands_stream: mpsc::Receiver<
         sc_consensus_manual_seal::rpc::EngineCommand<<Block as BlockT>::Hash>,
     >,
+    dev_clock: DevClock,
 ) -> Result<(), ServiceError> {
     let proposer_factory = sc_basic_authorship::ProposerFactory::new(
         task_manager.spawn_handle(),
@@ -799,11 +808,9 @@ fn run_manual_seal_authorship(
         telemetry.as_ref().map(|x| x.handle()),
     );
 
-    thread_local!(static TIMESTAMP: RefCell<u64> = const { RefCell::new(0) });
-
-    /// Provide a mock duration starting at 0 in millisecond for timestamp inherent.
+    /// Provide the timestamp inherent from the dev clock, starting at 0 in milliseconds.
     /// Each call will increment timestamp by slot_duration making Aura think time has passed.
-    struct MockTimestampInherentDataProvider;
+    struct MockTimestampInherentDataProvider(DevClock);
 
     #[async_trait::async_trait]
     impl sp_inherents::InherentDataProvider for MockTimestampInherentDataProvider {
@@ -811,11 +818,7 @@ fn run_manual_seal_authorship(
             &self,
             inherent_data: &mut sp_inherents::InherentData,
         ) -> Result<(), sp_inherents::Error> {
-            TIMESTAMP.with(|x| {
-                let mut x_ref = x.borrow_mut();
-                *x_ref = x_ref.saturating_add(subtensor_runtime_common::time::SLOT_DURATION);
-                inherent_data.put_data(sp_timestamp::INHERENT_IDENTIFIER, &*x.borrow())
-            })
+            inherent_data.put_data(sp_timestamp::INHERENT_IDENTIFIER, &self.0.advance())
         }
 
         async fn try_handle_error(
@@ -828,8 +831,10 @@ fn run_manual_seal_authorship(
         }
     }
 
-    let create_inherent_data_providers =
-        move |_, ()| async move { Ok(MockTimestampInherentDataProvider) };
+    let create_inherent_data_providers = move |_, ()| {
+        let dev_clock = dev_clock.clone();
+        async move { Ok(MockTimestampInherentDataProvider(dev_clock)) }
+    };
 
     let manual_seal = match sealing {
         Sealing::Manual => future::Either::Left(sc_consensus_manual_seal::run_manual_seal(
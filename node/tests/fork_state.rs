@@ -0,0 +1,100 @@
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use node_subtensor::{chain_spec::localnet::localnet_config, fork_state::ForkStateCmd};
+use serde_json::Value;
+use sp_core::hashing::twox_128;
+
+type RawStorage = BTreeMap<String, String>;
+
+fn storage_key(pallet: &str, storage: &str, key: &[u8]) -> String {
+    let prefix = [twox_128(pallet.as_bytes()), twox_128(storage.as_bytes())].concat();
+    format!("0x{}{}", hex::encode(prefix), hex::encode(key))
+}
+
+fn top(spec: &Value) -> RawStorage {
+    serde_json::from_value(spec["genesis"]["raw"]["top"].clone()).unwrap()
+}
+
+fn free_balance(account: &str) -> u64 {
+    let account = hex::decode(account.trim_start_matches("0x")).unwrap();
+    u64::from_le_bytes(account[16..24].try_into().unwrap())
+}
+
+fn issuance(top: &RawStorage) -> u64 {
+    let value = &top[&storage_key("Balances", "TotalIssuance", &[])];
+    u64::from_le_bytes(
+        hex::decode(value.trim_start_matches("0x")).unwrap()[..8]
+            .try_into()
+            .unwrap(),
+    )
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("fork-state-{}-{name}", std::process::id()))
+}
+
+#[test]
+fn test_fork_state_replaces_authorities_sudo_and_issuance() {
+    let dev_spec: Value =
+        serde_json::from_str(&localnet_config(true, true).unwrap().as_json(true).unwrap()).unwrap();
+    let dev_top = top(&dev_spec);
+
+    // The live state: other authorities and sudo key, a development account missing, and blocks
+    // of the live chain.
+    let mut live_top = dev_top.clone();
+    let aura_authorities = storage_key("Aura", "Authorities", &[]);
+    let sudo_key = storage_key("Sudo", "Key", &[]);
+    live_top.insert(aura_authorities.clone(), format!("0x04{}", "11".repeat(32)));
+    live_top.insert(sudo_key.clone(), format!("0x{}", "22".repeat(32)));
+    let account_prefix = storage_key("System", "Account", &[]);
+    let (missing_account, missing_info) = dev_top
+        .iter()
+        .find(|(key, _)| key.starts_with(&account_prefix))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .unwrap();
+    live_top.remove(&missing_account);
+    let last_tx_block = storage_key("SubtensorModule", "LastTxBlock", &[0x33; 32]);
+    live_top.insert(last_tx_block.clone(), "0x404b4c0000000000".into());
+    let last_update = storage_key("SubtensorModule", "LastUpdate", &1u16.to_le_bytes());
+    live_top.insert(
+        last_update.clone(),
+        "0x08404b4c00000000000700000000000000".into(),
+    );
+
+    let mut live_spec = dev_spec.clone();
+    live_spec["genesis"]["raw"]["top"] = serde_json::to_value(&live_top).unwrap();
+    let input = temp_path("live.json");
+    let output = temp_path("fork.json");
+    fs::write(&input, serde_json::to_vec(&live_spec).unwrap()).unwrap();
+
+    ForkStateCmd {
+        input: input.clone(),
+        output: Some(output.clone()),
+        two_authorities: false,
+        local_runtime: false,
+    }
+    .run()
+    .unwrap();
+
+    let fork: Value = serde_json::from_slice(&fs::read(&output).unwrap()).unwrap();
+    let fork_top = top(&fork);
+    let _ = fs::remove_file(input);
+    let _ = fs::remove_file(output);
+
+    // Authorities and sudo come from the development genesis.
+    assert_eq!(fork_top[&aura_authorities], dev_top[&aura_authorities]);
+    assert_eq!(fork_top[&sudo_key], dev_top[&sudo_key]);
+
+    // The missing development account is funded and its balance issued.
+    assert_eq!(fork_top[&missing_account], missing_info);
+    assert_eq!(
+        issuance(&fork_top),
+        issuance(&live_top).saturating_add(free_balance(&missing_info))
+    );
+
+    // Blocks of the live chain are reset.
+    assert!(!fork_top.contains_key(&last_tx_block));
+    assert_eq!(fork_top[&last_update], format!("0x08{}", "00".repeat(16)));
+}
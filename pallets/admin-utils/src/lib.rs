@@ -28,6 +28,7 @@ pub mod pallet {
     };
     use frame_system::pallet_prelude::*;
     use pallet_evm_chain_id::{self, ChainId};
+    use pallet_subtensor::subnets::roles::Hyperparameter;
     use pallet_subtensor::utils::rate_limiting::TransactionType;
     use sp_runtime::BoundedVec;
     use substrate_fixed::types::I96F32;
@@ -133,6 +134,22 @@ pub mod pallet {
         PureProxy,
         /// Leasing precompile
         Leasing,
+        /// Concentrated liquidity precompile
+        Liquidity,
+        /// Alpha ERC-20 token precompiles
+        AlphaToken,
+        /// Commitments precompile
+        Commitments,
+        /// Drand randomness precompile
+        Drand,
+        /// Proxy and batch precompile
+        Proxy,
+        /// Childkey and take precompile
+        Childkey,
+        /// Typed storage query precompile
+        TypedStorageQuery,
+        /// Identity precompile
+        Identity,
     }
 
     #[pallet::type_value]
@@ -214,7 +231,12 @@ pub mod pallet {
             netuid: NetUid,
             serving_rate_limit: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::ServingRateLimit,
+                serving_rate_limit,
+            )?;
 
             pallet_subtensor::Pallet::<T>::set_serving_rate_limit(netuid, serving_rate_limit);
             log::debug!(
@@ -263,7 +285,12 @@ pub mod pallet {
             netuid: NetUid,
             max_difficulty: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::MaxDifficulty,
+                max_difficulty,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -290,15 +317,22 @@ pub mod pallet {
             netuid: NetUid,
             weights_version_key: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin.clone(),
+                netuid,
+                Hyperparameter::WeightsVersionKey,
+                weights_version_key,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
 
-            if let Ok(RawOrigin::Signed(who)) = origin.into() {
-                // SN Owner
+            if let Ok(RawOrigin::Signed(_)) = origin.into() {
+                // SN Owner or delegate
+                // The rate limit is shared with the delegates of the subnet owner.
+                let who = pallet_subtensor::SubnetOwner::<T>::get(netuid);
                 // Ensure the origin passes the rate limit.
                 ensure!(
                     pallet_subtensor::Pallet::<T>::passes_rate_limit_on_subnet(
@@ -401,7 +435,12 @@ pub mod pallet {
             netuid: NetUid,
             adjustment_alpha: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::AdjustmentAlpha,
+                adjustment_alpha,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -427,7 +466,12 @@ pub mod pallet {
             netuid: NetUid,
             max_weight_limit: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::MaxWeightLimit,
+                max_weight_limit.into(),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -454,7 +498,12 @@ pub mod pallet {
             netuid: NetUid,
             immunity_period: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::ImmunityPeriod,
+                immunity_period.into(),
+            )?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -481,7 +530,12 @@ pub mod pallet {
             netuid: NetUid,
             min_allowed_weights: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::MinAllowedWeights,
+                min_allowed_weights.into(),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -534,7 +588,12 @@ pub mod pallet {
         .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
         .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
         pub fn sudo_set_kappa(origin: OriginFor<T>, netuid: NetUid, kappa: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::Kappa,
+                kappa.into(),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -553,7 +612,12 @@ pub mod pallet {
         .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
         .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
         pub fn sudo_set_rho(origin: OriginFor<T>, netuid: NetUid, rho: u16) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::Rho,
+                rho.into(),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -576,7 +640,12 @@ pub mod pallet {
             netuid: NetUid,
             activity_cutoff: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::ActivityCutoff,
+                activity_cutoff.into(),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -640,7 +709,12 @@ pub mod pallet {
             netuid: NetUid,
             registration_allowed: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::NetworkPowRegistrationAllowed,
+                registration_allowed.into(),
+            )?;
 
             pallet_subtensor::Pallet::<T>::set_network_pow_registration_allowed(
                 netuid,
@@ -810,11 +884,16 @@ pub mod pallet {
             netuid: NetUid,
             bonds_moving_average: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin.clone(),
+                netuid,
+                Hyperparameter::BondsMovingAverage,
+                bonds_moving_average,
+            )?;
 
-            if pallet_subtensor::Pallet::<T>::ensure_subnet_owner(origin, netuid).is_ok() {
+            if ensure_root(origin).is_err() {
                 ensure!(
-                    bonds_moving_average <= 975000,
+                    bonds_moving_average <= pallet_subtensor::MAX_OWNER_BONDS_MOVING_AVERAGE,
                     Error::<T>::BondsMovingAverageMaxReached
                 )
             }
@@ -844,7 +923,12 @@ pub mod pallet {
             netuid: NetUid,
             bonds_penalty: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::BondsPenalty,
+                bonds_penalty.into(),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -1164,7 +1248,12 @@ pub mod pallet {
             netuid: NetUid,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::CommitRevealWeightsEnabled,
+                enabled.into(),
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
@@ -1192,7 +1281,12 @@ pub mod pallet {
             netuid: NetUid,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::LiquidAlphaEnabled,
+                enabled.into(),
+            )?;
             pallet_subtensor::Pallet::<T>::set_liquid_alpha_enabled(netuid, enabled);
             log::debug!(
                 "LiquidAlphaEnableToggled( netuid: {:?}, Enabled: {:?} ) ",
@@ -1211,7 +1305,6 @@ pub mod pallet {
             alpha_low: u16,
             alpha_high: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
             pallet_subtensor::Pallet::<T>::do_set_alpha_values(
                 origin, netuid, alpha_low, alpha_high,
             )
@@ -1365,16 +1458,20 @@ pub mod pallet {
             netuid: NetUid,
             interval: u64,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::CommitRevealWeightsInterval,
+                interval,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
 
-            const MAX_COMMIT_REVEAL_PEROIDS: u64 = 100;
             ensure!(
-                interval <= MAX_COMMIT_REVEAL_PEROIDS,
+                interval <= pallet_subtensor::MAX_COMMIT_REVEAL_PERIODS,
                 Error::<T>::RevealPeriodOutOfBounds
             );
 
@@ -1456,7 +1553,12 @@ pub mod pallet {
             netuid: NetUid,
             toggle: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::TransferEnabled,
+                toggle.into(),
+            )?;
             pallet_subtensor::Pallet::<T>::toggle_transfer(netuid, toggle)
         }
 
@@ -1581,7 +1683,7 @@ pub mod pallet {
         /// # Errors
         /// * `BadOrigin` - If the caller is not the root account.
         /// * `SubnetDoesNotExist` - If the specified subnet does not exist.
-        /// * `NegativeSigmoidSteepness` - If the steepness is negative and the caller is
+        /// * `NegativeSigmoidSteepness` - If the steepness is negative and the caller is not
         /// root.
         /// # Weight
         /// Weight is handled by the `#[pallet::weight]` attribute.
@@ -1592,19 +1694,28 @@ pub mod pallet {
             netuid: NetUid,
             steepness: i16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin.clone(), netuid)?;
+            // Negative values are reserved to root, so they are never checked against the bounds
+            // of a delegated role.
+            let value = match u64::try_from(steepness) {
+                Ok(value) => value,
+                Err(_) => {
+                    ensure_root(origin.clone())
+                        .map_err(|_| Error::<T>::NegativeSigmoidSteepness)?;
+                    0
+                }
+            };
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::AlphaSigmoidSteepness,
+                value,
+            )?;
 
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
 
-            let is_root = ensure_root(origin).is_ok();
-            ensure!(
-                is_root || steepness >= 0,
-                Error::<T>::NegativeSigmoidSteepness
-            );
-
             pallet_subtensor::Pallet::<T>::set_alpha_sigmoid_steepness(netuid, steepness);
 
             log::debug!(
@@ -1631,7 +1742,12 @@ pub mod pallet {
             netuid: NetUid,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::Yuma3Enabled,
+                enabled.into(),
+            )?;
             pallet_subtensor::Pallet::<T>::set_yuma3_enabled(netuid, enabled);
 
             Self::deposit_event(Event::Yuma3EnableToggled { netuid, enabled });
@@ -1659,7 +1775,12 @@ pub mod pallet {
             netuid: NetUid,
             enabled: bool,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root(origin, netuid)?;
+            pallet_subtensor::Pallet::<T>::ensure_subnet_owner_or_root_or_role(
+                origin,
+                netuid,
+                Hyperparameter::BondsResetEnabled,
+                enabled.into(),
+            )?;
             pallet_subtensor::Pallet::<T>::set_bonds_reset(netuid, enabled);
 
             Self::deposit_event(Event::BondsResetToggled { netuid, enabled });
@@ -1740,6 +1861,89 @@ pub mod pallet {
             );
             Ok(())
         }
+
+        /// Sets the minimum notice required to change a subnet hyperparameter.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `param` - The hyperparameter the notice applies to.
+        /// * `blocks` - The minimum notice, in blocks. Zero removes the requirement.
+        ///
+        /// While a notice is required, subnet owners and their delegates can only change the
+        /// hyperparameter through `schedule_hyperparameter_change`.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(71)]
+        #[pallet::weight((
+            Weight::from_parts(5_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_hyperparameter_min_notice(
+            origin: OriginFor<T>,
+            param: Hyperparameter,
+            blocks: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_hyperparameter_min_notice(param, blocks);
+
+            log::debug!(
+                "HyperparameterMinNoticeSet( param: {:?}, blocks: {:?} )",
+                param,
+                blocks
+            );
+            Ok(())
+        }
+
+        /// Sets the range of values subnet owners may use for a hyperparameter.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the root account.
+        /// * `param` - The hyperparameter the range applies to.
+        /// * `bounds` - The inclusive `(min, max)` range, or `None` to remove it.
+        ///
+        /// Setters called by subnet owners or their delegates fail with `ValueOutOfBounds`
+        /// when the value is outside the range. Root is not bound by the range.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the caller is not the root account.
+        /// * `InvalidHyperparameterBounds` - If the minimum is greater than the maximum.
+        ///
+        /// # Weight
+        /// Weight is handled by the `#[pallet::weight]` attribute.
+        #[pallet::call_index(72)]
+        #[pallet::weight((
+            Weight::from_parts(5_000_000, 0)
+                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn sudo_set_hyperparameter_bounds(
+            origin: OriginFor<T>,
+            param: Hyperparameter,
+            bounds: Option<(u64, u64)>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            if let Some((min, max)) = bounds {
+                ensure!(
+                    min <= max,
+                    pallet_subtensor::Error::<T>::InvalidHyperparameterBounds
+                );
+            }
+            pallet_subtensor::Pallet::<T>::set_hyperparameter_bounds(param, bounds);
+
+            log::debug!(
+                "HyperparameterBoundsSet( param: {:?}, bounds: {:?} )",
+                param,
+                bounds
+            );
+            Ok(())
+        }
     }
 }
 
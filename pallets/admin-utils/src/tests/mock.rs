@@ -396,6 +396,15 @@ impl pallet_drand::Config for Test {
     type Verifier = pallet_drand::verifier::QuicknetVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type UnixTime = MockUnixTime;
+}
+
+pub struct MockUnixTime;
+
+impl frame_support::traits::UnixTime for MockUnixTime {
+    fn now() -> core::time::Duration {
+        core::time::Duration::ZERO
+    }
 }
 
 impl frame_system::offchain::SigningTypes for Test {
@@ -8,6 +8,7 @@ use frame_system::Config;
 use pallet_subtensor::{Error as SubtensorError, SubnetOwner, Tempo, WeightsVersionKeyRateLimit};
 // use pallet_subtensor::{migrations, Event};
 use pallet_subtensor::Event;
+use pallet_subtensor::subnets::roles::{Hyperparameter, HyperparameterBound, HyperparameterGroup};
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{Get, Pair, U256, ed25519};
 use substrate_fixed::types::I96F32;
@@ -1934,3 +1935,328 @@ fn test_sudo_set_yuma3_enabled() {
         assert_eq!(SubtensorModule::get_yuma3_enabled(netuid), !to_be_set);
     });
 }
+
+#[test]
+fn test_subnet_role_delegates_hyperparameter_rights() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        let delegate = U256::from(2);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_ok!(SubtensorModule::grant_subnet_role(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            delegate,
+            vec![HyperparameterGroup::Consensus],
+            vec![HyperparameterBound {
+                param: Hyperparameter::Kappa,
+                min: 10,
+                max: 100,
+            }],
+            None,
+        ));
+
+        // Within the role and its bounds.
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::signed(delegate),
+            netuid,
+            50
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), 50);
+
+        // Outside the bounds of the role.
+        assert_noop!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(delegate),
+                netuid,
+                101
+            ),
+            SubtensorError::<Test>::SubnetRoleBoundsExceeded
+        );
+
+        // The owner is not bound by the role.
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            101
+        ));
+
+        // Hyperparameters outside the granted groups are rejected.
+        assert_noop!(
+            AdminUtils::sudo_set_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::signed(delegate),
+                netuid,
+                10
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // The role does not carry over to other subnets.
+        let other_netuid = NetUid::from(2);
+        add_network(other_netuid, 10);
+        assert_noop!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(delegate),
+                other_netuid,
+                50
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // Delegates are held to the owner limits.
+        assert_noop!(
+            AdminUtils::sudo_set_bonds_moving_average(
+                <<Test as Config>::RuntimeOrigin>::signed(delegate),
+                netuid,
+                975_001
+            ),
+            Error::<Test>::BondsMovingAverageMaxReached
+        );
+
+        assert_ok!(SubtensorModule::revoke_subnet_role(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            delegate,
+        ));
+        assert_noop!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(delegate),
+                netuid,
+                50
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_subnet_role_expires() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        let delegate = U256::from(2);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_ok!(SubtensorModule::grant_subnet_role(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            delegate,
+            vec![HyperparameterGroup::Registration],
+            vec![],
+            Some(10),
+        ));
+
+        assert_ok!(AdminUtils::sudo_set_immunity_period(
+            <<Test as Config>::RuntimeOrigin>::signed(delegate),
+            netuid,
+            10
+        ));
+
+        System::set_block_number(10);
+        assert_noop!(
+            AdminUtils::sudo_set_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::signed(delegate),
+                netuid,
+                20
+            ),
+            SubtensorError::<Test>::SubnetRoleExpired
+        );
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 10);
+    });
+}
+
+#[test]
+fn test_subnet_role_cannot_set_negative_sigmoid_steepness() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        let delegate = U256::from(2);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_ok!(SubtensorModule::grant_subnet_role(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            delegate,
+            vec![HyperparameterGroup::Consensus],
+            vec![HyperparameterBound {
+                param: Hyperparameter::AlphaSigmoidSteepness,
+                min: 0,
+                max: 100,
+            }],
+            None,
+        ));
+
+        let steepness = SubtensorModule::get_alpha_sigmoid_steepness(netuid);
+        assert_noop!(
+            AdminUtils::sudo_set_alpha_sigmoid_steepness(
+                <<Test as Config>::RuntimeOrigin>::signed(delegate),
+                netuid,
+                -10
+            ),
+            Error::<Test>::NegativeSigmoidSteepness
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
+            steepness
+        );
+
+        assert_ok!(AdminUtils::sudo_set_alpha_sigmoid_steepness(
+            <<Test as Config>::RuntimeOrigin>::signed(delegate),
+            netuid,
+            10
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_hyperparameter_min_notice() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_hyperparameter_min_notice(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                Hyperparameter::Kappa,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_min_notice(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Hyperparameter::Kappa,
+            100
+        ));
+
+        // The owner must now schedule the change.
+        assert_noop!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                10
+            ),
+            SubtensorError::<Test>::HyperparameterNoticeRequired
+        );
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            Hyperparameter::Kappa,
+            10,
+            101
+        ));
+
+        // Root is not bound by the notice, and other hyperparameters are unaffected.
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            10
+        ));
+        assert_ok!(AdminUtils::sudo_set_rho(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            10
+        ));
+
+        // Removing the notice restores immediate changes.
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_min_notice(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Hyperparameter::Kappa,
+            0
+        ));
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            20
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_hyperparameter_bounds() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let sn_owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
+
+        assert_eq!(
+            AdminUtils::sudo_set_hyperparameter_bounds(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                Hyperparameter::ImmunityPeriod,
+                Some((100, 1_000))
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameter_bounds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                Hyperparameter::ImmunityPeriod,
+                Some((1_000, 100))
+            ),
+            SubtensorError::<Test>::InvalidHyperparameterBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Hyperparameter::ImmunityPeriod,
+            Some((100, 1_000))
+        ));
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_bounds(),
+            vec![HyperparameterBound {
+                param: Hyperparameter::ImmunityPeriod,
+                min: 100,
+                max: 1_000,
+            }]
+        );
+
+        // The owner must stay within the range.
+        assert_noop!(
+            AdminUtils::sudo_set_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                99
+            ),
+            SubtensorError::<Test>::ValueOutOfBounds {
+                param: Hyperparameter::ImmunityPeriod
+            }
+        );
+        assert_noop!(
+            SubtensorModule::schedule_hyperparameter_change(
+                <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                netuid,
+                Hyperparameter::ImmunityPeriod,
+                1_001,
+                10
+            ),
+            SubtensorError::<Test>::ValueOutOfBounds {
+                param: Hyperparameter::ImmunityPeriod
+            }
+        );
+        assert_ok!(AdminUtils::sudo_set_immunity_period(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            500
+        ));
+
+        // Root is not bound by the range.
+        assert_ok!(AdminUtils::sudo_set_immunity_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5_000
+        ));
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 5_000);
+
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Hyperparameter::ImmunityPeriod,
+            None
+        ));
+        assert!(SubtensorModule::get_hyperparameter_bounds().is_empty());
+    });
+}
@@ -130,7 +130,7 @@ pub mod pallet {
     /// Identity data by account
     #[pallet::storage]
     #[pallet::getter(fn commitment_of)]
-    pub(super) type CommitmentOf<T: Config> = StorageDoubleMap<
+    pub type CommitmentOf<T: Config> = StorageDoubleMap<
         _,
         Identity,
         NetUid,
@@ -166,7 +166,7 @@ pub mod pallet {
 
     #[pallet::storage]
     #[pallet::getter(fn revealed_commitments)]
-    pub(super) type RevealedCommitments<T: Config> = StorageDoubleMap<
+    pub type RevealedCommitments<T: Config> = StorageDoubleMap<
         _,
         Identity,
         NetUid,
@@ -40,7 +40,10 @@ extern crate alloc;
 
 use alloc::{format, string::String, vec, vec::Vec};
 use codec::Encode;
-use frame_support::{pallet_prelude::*, traits::Randomness};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Randomness, UnixTime},
+};
 use frame_system::{
     offchain::{
         AppCrypto, CreateInherent, CreateSignedTransaction, SendUnsignedTransaction, SignedPayload,
@@ -92,6 +95,26 @@ const CHAIN_HASH: &str = QUICKNET_CHAIN_HASH;
 
 pub const MAX_PULSES_TO_FETCH: u64 = 50;
 
+/// the number of rounds between the current drand round and the earliest round that can be
+/// requested, so that the randomness of a requested round isn't public yet
+pub const RANDOMNESS_REQUEST_MARGIN: RoundNumber = 20;
+
+/// the latest round that can be requested, relative to the current drand round
+pub const MAX_RANDOMNESS_REQUEST_HORIZON: RoundNumber = 28_800;
+
+/// the number of rounds a fulfilled request is kept before it is pruned
+pub const RANDOMNESS_REQUEST_RETENTION: RoundNumber = 1_200;
+
+/// the maximum number of stored requests per requester
+pub const MAX_RANDOMNESS_REQUESTS_PER_ACCOUNT: u32 = 16;
+
+/// the maximum number of requests pruned per block
+pub const MAX_RANDOMNESS_REQUESTS_PRUNED_PER_BLOCK: u64 = 32;
+
+/// the maximum number of rounds whose requests are pruned per block, above the number of
+/// pulses stored per block so that pruning keeps up with the chain
+pub const MAX_RANDOMNESS_ROUNDS_PRUNED_PER_BLOCK: u64 = 64;
+
 /// Defines application identifier for crypto keys of this module.
 ///
 /// Every module that deals with signatures needs to declare its unique identifier for
@@ -174,6 +197,8 @@ pub mod pallet {
         /// complete.
         #[pallet::constant]
         type HttpFetchTimeout: Get<u64>;
+        /// the wall clock, used to tell the current drand round
+        type UnixTime: UnixTime;
     }
 
     /// the drand beacon configuration
@@ -217,7 +242,34 @@ pub mod pallet {
     pub type Pulses<T: Config> = StorageMap<_, Blake2_128Concat, RoundNumber, Pulse, OptionQuery>;
 
     #[pallet::storage]
-    pub(super) type LastStoredRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+    pub type LastStoredRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+
+    /// map request id to randomness request
+    #[pallet::storage]
+    pub type RandomnessRequests<T: Config> =
+        StorageMap<_, Twox64Concat, u64, RandomnessRequest<T::AccountId>, OptionQuery>;
+
+    /// the id of the next randomness request
+    #[pallet::storage]
+    pub type NextRandomnessRequestId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// map round and request id to whether the request for that round is stored
+    #[pallet::storage]
+    pub type RandomnessRequestsByRound<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, RoundNumber, Twox64Concat, u64, bool, ValueQuery>;
+
+    /// the number of stored randomness requests
+    #[pallet::storage]
+    pub type PendingRandomnessRequests<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// the last round whose requests have been pruned
+    #[pallet::storage]
+    pub type PrunedRandomnessRound<T: Config> = StorageValue<_, RoundNumber, ValueQuery>;
+
+    /// map requester to the number of its stored requests
+    #[pallet::storage]
+    pub type RandomnessRequestCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
     /// Defines the block when next unsigned transaction will be accepted.
     ///
@@ -235,6 +287,12 @@ pub mod pallet {
         NewPulse {
             rounds: Vec<RoundNumber>,
         },
+        /// The randomness of a future round was requested.
+        RandomnessRequested {
+            request_id: u64,
+            requester: T::AccountId,
+            round: RoundNumber,
+        },
     }
 
     #[pallet::error]
@@ -251,10 +309,22 @@ pub mod pallet {
         InvalidRoundNumber,
         /// the pulse could not be verified
         PulseVerificationError,
+        /// the requested round already has a pulse
+        RoundAlreadyStored,
+        /// the requested round is too close to the current drand round
+        RoundTooSoon,
+        /// the requested round is too far after the current drand round
+        RoundTooFar,
+        /// the requester has too many stored requests
+        TooManyRandomnessRequests,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::prune_randomness_requests()
+        }
+
         fn offchain_worker(block_number: BlockNumberFor<T>) {
             log::debug!("Drand OCW working on block: {:?}", block_number);
             if let Err(e) = Self::fetch_drand_pulse_and_send_unsigned(block_number) {
@@ -381,6 +451,66 @@ pub mod pallet {
             Self::deposit_event(Event::BeaconConfigChanged {});
             Ok(())
         }
+
+        /// requests the randomness of a future round
+        /// the request is fulfilled once the pulse of the round is stored, see `fulfilled_randomness`.
+        /// fulfilled requests are pruned `RANDOMNESS_REQUEST_RETENTION` rounds later.
+        ///
+        /// * `origin`: the requester
+        /// * `round`: a round at least `RANDOMNESS_REQUEST_MARGIN` rounds after the current one
+        #[pallet::call_index(2)]
+        #[pallet::weight(Weight::from_parts(10_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(6_u64))
+        .saturating_add(T::DbWeight::get().writes(6_u64)))]
+        pub fn request_randomness(origin: OriginFor<T>, round: RoundNumber) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+            let last_stored_round = LastStoredRound::<T>::get();
+            ensure!(round > last_stored_round, Error::<T>::RoundAlreadyStored);
+
+            // pulses are stored some time after drand publishes them, so the last stored round
+            // lags behind the randomness that is already public
+            let current_round = Self::current_round();
+            ensure!(
+                round > current_round.saturating_add(RANDOMNESS_REQUEST_MARGIN),
+                Error::<T>::RoundTooSoon
+            );
+            ensure!(
+                round <= current_round.saturating_add(MAX_RANDOMNESS_REQUEST_HORIZON),
+                Error::<T>::RoundTooFar
+            );
+
+            let count = RandomnessRequestCount::<T>::get(&requester);
+            ensure!(
+                count < MAX_RANDOMNESS_REQUESTS_PER_ACCOUNT,
+                Error::<T>::TooManyRandomnessRequests
+            );
+            RandomnessRequestCount::<T>::insert(&requester, count.saturating_add(1));
+
+            // without stored requests, every round up to the last stored one is free of requests
+            let pending = PendingRandomnessRequests::<T>::get();
+            if pending == 0 {
+                PrunedRandomnessRound::<T>::put(last_stored_round);
+            }
+            PendingRandomnessRequests::<T>::put(pending.saturating_add(1));
+
+            let request_id = NextRandomnessRequestId::<T>::get();
+            NextRandomnessRequestId::<T>::put(request_id.saturating_add(1));
+            RandomnessRequestsByRound::<T>::insert(round, request_id, true);
+            RandomnessRequests::<T>::insert(
+                request_id,
+                RandomnessRequest {
+                    requester: requester.clone(),
+                    round,
+                },
+            );
+
+            Self::deposit_event(Event::RandomnessRequested {
+                request_id,
+                requester,
+                round,
+            });
+            Ok(())
+        }
     }
 }
 
@@ -564,6 +694,93 @@ impl<T: Config> Pallet<T> {
         Err("Drand: No valid response from any endpoint")
     }
 
+    /// the randomness fulfilling a request, `None` until the pulse of its round is stored
+    /// it is derived from the pulse and the request id, so requests for one round get different values.
+    pub fn fulfilled_randomness(request_id: u64) -> Option<[u8; 32]> {
+        let request = RandomnessRequests::<T>::get(request_id)?;
+        let pulse = Pulses::<T>::get(request.round)?;
+
+        Some(blake2_256(&(pulse.randomness, request_id).encode()))
+    }
+
+    /// the drand round at the current block's timestamp
+    pub fn current_round() -> RoundNumber {
+        let config = BeaconConfig::<T>::get();
+        let elapsed = T::UnixTime::now()
+            .as_secs()
+            .saturating_sub(u64::from(config.genesis_time));
+
+        elapsed
+            .checked_div(u64::from(config.period))
+            .unwrap_or_default()
+            .saturating_add(1)
+    }
+
+    /// prunes the requests whose round was stored `RANDOMNESS_REQUEST_RETENTION` rounds ago
+    /// requests are pruned in round order, so a request for a far round doesn't hold back the
+    /// pruning of requests for earlier rounds.
+    fn prune_randomness_requests() -> Weight {
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+        let mut pending = PendingRandomnessRequests::<T>::get();
+        if pending == 0 {
+            return T::DbWeight::get().reads(reads);
+        }
+
+        let prunable_round =
+            LastStoredRound::<T>::get().saturating_sub(RANDOMNESS_REQUEST_RETENTION);
+        let first_round = PrunedRandomnessRound::<T>::get();
+        let mut pruned_round = first_round;
+        let mut pruned: u64 = 0;
+        reads = reads.saturating_add(2);
+
+        for _ in 0..MAX_RANDOMNESS_ROUNDS_PRUNED_PER_BLOCK {
+            if pruned_round >= prunable_round || pending == 0 {
+                break;
+            }
+
+            let round = pruned_round.saturating_add(1);
+            let remaining = MAX_RANDOMNESS_REQUESTS_PRUNED_PER_BLOCK.saturating_sub(pruned);
+            // one id more than can be pruned tells whether the round is drained
+            let request_ids: Vec<u64> = RandomnessRequestsByRound::<T>::iter_key_prefix(round)
+                .take(remaining.saturating_add(1) as usize)
+                .collect();
+            reads = reads
+                .saturating_add(request_ids.len() as u64)
+                .saturating_add(1);
+
+            for request_id in request_ids.iter().take(remaining as usize) {
+                RandomnessRequestsByRound::<T>::remove(round, request_id);
+                if let Some(request) = RandomnessRequests::<T>::take(request_id) {
+                    RandomnessRequestCount::<T>::mutate_exists(&request.requester, |count| {
+                        *count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+                    });
+                    pending.saturating_dec();
+                    reads = reads.saturating_add(2);
+                    writes = writes.saturating_add(2);
+                }
+                writes.saturating_inc();
+                pruned.saturating_inc();
+            }
+
+            if request_ids.len() as u64 > remaining {
+                break;
+            }
+            pruned_round = round;
+        }
+
+        if pruned > 0 {
+            PendingRandomnessRequests::<T>::put(pending);
+            writes.saturating_inc();
+        }
+        if pruned_round != first_round {
+            PrunedRandomnessRound::<T>::put(pruned_round);
+            writes.saturating_inc();
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
     /// get the randomness at a specific block height
     /// returns [0u8;32] if it does not exist
     pub fn random_at(round: RoundNumber) -> [u8; 32] {
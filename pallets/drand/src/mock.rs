@@ -3,7 +3,7 @@ use crate::verifier::*;
 use crate::*;
 use frame_support::{
     derive_impl, parameter_types,
-    traits::{ConstU16, ConstU64, InherentBuilder},
+    traits::{ConstU16, ConstU64, InherentBuilder, UnixTime},
 };
 use sp_core::{H256, sr25519::Signature};
 use sp_keystore::{KeystoreExt, testing::MemoryKeystore};
@@ -94,6 +94,15 @@ where
 
 parameter_types! {
     pub const UnsignedPriority: u64 = 1 << 20;
+    pub static Now: u64 = 0;
+}
+
+pub struct MockUnixTime;
+
+impl UnixTime for MockUnixTime {
+    fn now() -> core::time::Duration {
+        core::time::Duration::from_secs(Now::get())
+    }
 }
 
 impl pallet_drand_bridge::Config for Test {
@@ -102,6 +111,7 @@ impl pallet_drand_bridge::Config for Test {
     type Verifier = QuicknetVerifier;
     type UnsignedPriority = UnsignedPriority;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type UnixTime = MockUnixTime;
 }
 
 // Build genesis storage according to the mock runtime.
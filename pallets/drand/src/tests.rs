@@ -16,12 +16,16 @@
 
 use crate::{
     BeaconConfig, BeaconConfigurationPayload, BeaconInfoResponse, Call, DrandResponseBody,
-    ENDPOINTS, Error, Pulse, Pulses, PulsesPayload, QUICKNET_CHAIN_HASH, mock::*,
+    ENDPOINTS, Error, LastStoredRound, MAX_RANDOMNESS_REQUESTS_PER_ACCOUNT,
+    PendingRandomnessRequests, PrunedRandomnessRound, Pulse, Pulses, PulsesPayload,
+    QUICKNET_CHAIN_HASH, RANDOMNESS_REQUEST_MARGIN, RANDOMNESS_REQUEST_RETENTION,
+    RandomnessRequestCount, RandomnessRequests, RandomnessRequestsByRound, mock::*,
 };
 use codec::Encode;
 use frame_support::{
     assert_noop, assert_ok,
     pallet_prelude::{InvalidTransaction, TransactionSource},
+    traits::Hooks,
 };
 use frame_system::RawOrigin;
 use sp_runtime::{
@@ -549,3 +553,123 @@ fn test_invalid_json_then_success() {
         assert_eq!(actual, expected_pulse);
     });
 }
+
+#[test]
+fn it_fulfils_randomness_requests_once_the_round_is_stored() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        let u_p: DrandResponseBody = serde_json::from_str(DRAND_PULSE).unwrap();
+        let p: Pulse = u_p.try_into_pulse().unwrap();
+        LastStoredRound::<Test>::put(ROUND_NUMBER - 1);
+
+        assert_ok!(Drand::request_randomness(
+            RuntimeOrigin::signed(alice),
+            ROUND_NUMBER
+        ));
+        assert_ok!(Drand::request_randomness(
+            RuntimeOrigin::signed(alice),
+            ROUND_NUMBER
+        ));
+        assert_eq!(
+            RandomnessRequests::<Test>::get(1).unwrap().round,
+            ROUND_NUMBER
+        );
+        assert_eq!(Drand::fulfilled_randomness(0), None);
+
+        Pulses::<Test>::insert(ROUND_NUMBER, p);
+        LastStoredRound::<Test>::put(ROUND_NUMBER);
+
+        let first = Drand::fulfilled_randomness(0).unwrap();
+        let second = Drand::fulfilled_randomness(1).unwrap();
+        assert_ne!(first, second);
+
+        assert_noop!(
+            Drand::request_randomness(RuntimeOrigin::signed(alice), ROUND_NUMBER),
+            Error::<Test>::RoundAlreadyStored
+        );
+    });
+}
+
+#[test]
+fn it_rejects_randomness_requests_for_rounds_that_may_be_public() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        let config = BeaconConfig::<Test>::get();
+        // the current drand round is `ROUND_NUMBER`, although no pulse is stored yet
+        Now::set(u64::from(config.genesis_time) + u64::from(config.period) * (ROUND_NUMBER - 1));
+        assert_eq!(Drand::current_round(), ROUND_NUMBER);
+
+        assert_noop!(
+            Drand::request_randomness(
+                RuntimeOrigin::signed(alice),
+                ROUND_NUMBER + RANDOMNESS_REQUEST_MARGIN
+            ),
+            Error::<Test>::RoundTooSoon
+        );
+        assert_ok!(Drand::request_randomness(
+            RuntimeOrigin::signed(alice),
+            ROUND_NUMBER + RANDOMNESS_REQUEST_MARGIN + 1
+        ));
+    });
+}
+
+#[test]
+fn it_caps_and_prunes_randomness_requests() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        LastStoredRound::<Test>::put(ROUND_NUMBER - 1);
+
+        for _ in 0..MAX_RANDOMNESS_REQUESTS_PER_ACCOUNT {
+            assert_ok!(Drand::request_randomness(
+                RuntimeOrigin::signed(alice),
+                ROUND_NUMBER
+            ));
+        }
+        assert_noop!(
+            Drand::request_randomness(RuntimeOrigin::signed(alice), ROUND_NUMBER),
+            Error::<Test>::TooManyRandomnessRequests
+        );
+
+        // fulfilled requests are kept for the retention period
+        LastStoredRound::<Test>::put(ROUND_NUMBER + RANDOMNESS_REQUEST_RETENTION - 1);
+        Drand::on_initialize(1);
+        assert!(RandomnessRequests::<Test>::contains_key(0));
+
+        LastStoredRound::<Test>::put(ROUND_NUMBER + RANDOMNESS_REQUEST_RETENTION);
+        Drand::on_initialize(2);
+        assert_eq!(RandomnessRequests::<Test>::iter().count(), 0);
+        assert_eq!(RandomnessRequestsByRound::<Test>::iter().count(), 0);
+        assert_eq!(PendingRandomnessRequests::<Test>::get(), 0);
+        assert_eq!(PrunedRandomnessRound::<Test>::get(), ROUND_NUMBER);
+        assert!(!RandomnessRequestCount::<Test>::contains_key(alice));
+    });
+}
+
+#[test]
+fn it_prunes_randomness_requests_behind_a_far_request() {
+    new_test_ext().execute_with(|| {
+        let alice = sp_keyring::Sr25519Keyring::Alice.public();
+        let bob = sp_keyring::Sr25519Keyring::Bob.public();
+        let far_round = ROUND_NUMBER + 10 * RANDOMNESS_REQUEST_RETENTION;
+        LastStoredRound::<Test>::put(ROUND_NUMBER - 1);
+
+        assert_ok!(Drand::request_randomness(
+            RuntimeOrigin::signed(alice),
+            far_round
+        ));
+        assert_ok!(Drand::request_randomness(
+            RuntimeOrigin::signed(bob),
+            ROUND_NUMBER
+        ));
+
+        LastStoredRound::<Test>::put(ROUND_NUMBER + RANDOMNESS_REQUEST_RETENTION);
+        Drand::on_initialize(1);
+
+        // the request made later for an earlier round is pruned first
+        assert!(RandomnessRequests::<Test>::contains_key(0));
+        assert!(!RandomnessRequests::<Test>::contains_key(1));
+        assert!(!RandomnessRequestCount::<Test>::contains_key(bob));
+        assert_eq!(RandomnessRequestCount::<Test>::get(alice), 1);
+        assert_eq!(PendingRandomnessRequests::<Test>::get(), 1);
+    });
+}
@@ -203,3 +203,13 @@ pub struct PulsesPayload<Public, BlockNumber> {
     pub pulses: Vec<Pulse>,
     pub public: Public,
 }
+
+/// A request for the randomness of a future round.
+#[freeze_struct("34ff682073676c79")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RandomnessRequest<AccountId> {
+    /// the account that made the request
+    pub requester: AccountId,
+    /// the round whose pulse fulfils the request
+    pub round: RoundNumber,
+}
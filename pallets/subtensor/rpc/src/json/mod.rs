@@ -0,0 +1,121 @@
+//! Typed JSON representations of the subtensor runtime API results.
+//!
+//! The SCALE methods of [`crate::SubtensorCustomApi`] return the runtime types as opaque bytes.
+//! The JSON methods map the same results to the versioned view types of this module, whose
+//! field names only change with a new version. In every version:
+//! * accounts are SS58 strings,
+//! * TAO and alpha amounts are decimal strings with 9 decimals (`"1.500000000"`),
+//! * fixed-point values are decimal strings,
+//! * identity fields and subnet names are UTF-8 strings,
+//! * IP addresses are formatted as IPv4 or IPv6 strings,
+//! * hashes are `0x`-prefixed hex strings,
+//! * enum variants are snake_case strings (`"alpha_sigmoid_steepness"`).
+
+use codec::Compact;
+use sp_runtime::AccountId32;
+use std::borrow::Borrow;
+use std::fmt::{Display, LowerHex};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use subtensor_runtime_common::AlphaCurrency;
+
+pub mod v1;
+
+/// Number of rao in one TAO or alpha.
+const RAO_PER_UNIT: u128 = 1_000_000_000;
+
+fn account(account: impl Borrow<AccountId32>) -> String {
+    account.borrow().to_string()
+}
+
+fn accounts(accounts: Vec<AccountId32>) -> Vec<String> {
+    accounts.into_iter().map(account).collect()
+}
+
+fn amount(rao: impl Into<u128>) -> String {
+    let rao: u128 = rao.into();
+    format!(
+        "{}.{:09}",
+        rao.saturating_div(RAO_PER_UNIT),
+        rao.checked_rem(RAO_PER_UNIT).unwrap_or_default()
+    )
+}
+
+fn balance<T: Into<u128>>(value: Compact<T>) -> String {
+    amount(value.0)
+}
+
+fn balances(values: Vec<Compact<u64>>) -> Vec<String> {
+    values.into_iter().map(balance).collect()
+}
+
+fn alpha(value: Compact<AlphaCurrency>) -> String {
+    amount(u64::from(value.0))
+}
+
+fn alphas(values: Vec<Compact<AlphaCurrency>>) -> Vec<String> {
+    values.into_iter().map(alpha).collect()
+}
+
+fn fixed(value: impl Display) -> String {
+    value.to_string()
+}
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+fn compact_text(bytes: Vec<Compact<u8>>) -> String {
+    text(&bytes.into_iter().map(compact).collect::<Vec<u8>>())
+}
+
+fn compact<T>(value: Compact<T>) -> T {
+    value.0
+}
+
+fn compacts<T>(values: Vec<Compact<T>>) -> Vec<T> {
+    values.into_iter().map(compact).collect()
+}
+
+fn hash(hash: impl LowerHex) -> String {
+    format!("{hash:#x}")
+}
+
+fn ip(ip: u128, ip_type: u8) -> String {
+    match u32::try_from(ip) {
+        Ok(ipv4) if ip_type == 4 => Ipv4Addr::from(ipv4).to_string(),
+        _ => Ipv6Addr::from(ip).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amount_has_nine_decimals() {
+        assert_eq!(amount(0_u64), "0.000000000");
+        assert_eq!(amount(1_500_000_000_u64), "1.500000000");
+        assert_eq!(amount(42_u64), "0.000000042");
+        assert_eq!(
+            alpha(Compact(AlphaCurrency::from(123_000_000_001))),
+            "123.000000001"
+        );
+    }
+
+    #[test]
+    fn hash_is_full_hex() {
+        assert_eq!(
+            hash(sp_runtime::testing::H256::repeat_byte(0xab)),
+            format!("0x{}", "ab".repeat(32))
+        );
+    }
+
+    #[test]
+    fn ip_is_formatted_by_type() {
+        assert_eq!(
+            ip(u128::from(u32::from(Ipv4Addr::new(1, 2, 3, 4))), 4),
+            "1.2.3.4"
+        );
+        assert_eq!(ip(1, 6), "::1");
+    }
+}
@@ -0,0 +1,1191 @@
+//! Version 1 of the JSON view types.
+
+use codec::Compact;
+use pallet_subtensor::rpc_info::{
+    delegate_info, dynamic_info, metagraph, neuron_info, show_subnet, subnet_info, weight_commits,
+};
+use pallet_subtensor::subnets::{hyperparameter_schedule, roles};
+use pallet_subtensor::utils::identity_attestation;
+use serde::{Deserialize, Serialize};
+use sp_runtime::AccountId32;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+use super::{
+    account, accounts, alpha, alphas, balance, balances, compact, compact_text, compacts, fixed,
+    hash, ip, text,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AxonInfo {
+    pub block: u64,
+    pub version: u32,
+    pub ip: String,
+    pub port: u16,
+    pub ip_type: u8,
+    pub protocol: u8,
+}
+
+impl From<pallet_subtensor::AxonInfo> for AxonInfo {
+    fn from(axon: pallet_subtensor::AxonInfo) -> Self {
+        Self {
+            block: axon.block,
+            version: axon.version,
+            ip: ip(axon.ip, axon.ip_type),
+            port: axon.port,
+            ip_type: axon.ip_type,
+            protocol: axon.protocol,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrometheusInfo {
+    pub block: u64,
+    pub version: u32,
+    pub ip: String,
+    pub port: u16,
+    pub ip_type: u8,
+}
+
+impl From<pallet_subtensor::PrometheusInfo> for PrometheusInfo {
+    fn from(prometheus: pallet_subtensor::PrometheusInfo) -> Self {
+        Self {
+            block: prometheus.block,
+            version: prometheus.version,
+            ip: ip(prometheus.ip, prometheus.ip_type),
+            port: prometheus.port,
+            ip_type: prometheus.ip_type,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AxonEndpoint {
+    pub protocol: u8,
+    pub ip: String,
+    pub ip_type: u8,
+    pub port: u16,
+    pub priority: u8,
+}
+
+impl From<pallet_subtensor::AxonEndpoint> for AxonEndpoint {
+    fn from(endpoint: pallet_subtensor::AxonEndpoint) -> Self {
+        Self {
+            protocol: endpoint.protocol,
+            ip: ip(endpoint.ip, endpoint.ip_type),
+            ip_type: endpoint.ip_type,
+            port: endpoint.port,
+            priority: endpoint.priority,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorWeightMetrics {
+    pub block: u64,
+    pub consensus_similarity: u16,
+    pub clipped_share: u16,
+    pub bond_share: u16,
+    pub bonds_growth: i32,
+    pub blocks_since_update: u64,
+}
+
+impl From<pallet_subtensor::ValidatorWeightMetrics> for ValidatorWeightMetrics {
+    fn from(metrics: pallet_subtensor::ValidatorWeightMetrics) -> Self {
+        Self {
+            block: metrics.block,
+            consensus_similarity: metrics.consensus_similarity,
+            clipped_share: metrics.clipped_share,
+            bond_share: metrics.bond_share,
+            bonds_growth: metrics.bonds_growth,
+            blocks_since_update: metrics.blocks_since_update,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainIdentity {
+    pub name: String,
+    pub url: String,
+    pub github_repo: String,
+    pub image: String,
+    pub discord: String,
+    pub description: String,
+    pub additional: String,
+}
+
+impl From<pallet_subtensor::ChainIdentityV2> for ChainIdentity {
+    fn from(identity: pallet_subtensor::ChainIdentityV2) -> Self {
+        Self {
+            name: text(&identity.name),
+            url: text(&identity.url),
+            github_repo: text(&identity.github_repo),
+            image: text(&identity.image),
+            discord: text(&identity.discord),
+            description: text(&identity.description),
+            additional: text(&identity.additional),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubnetIdentity {
+    pub subnet_name: String,
+    pub github_repo: String,
+    pub subnet_contact: String,
+    pub subnet_url: String,
+    pub discord: String,
+    pub description: String,
+    pub logo_url: String,
+    pub additional: String,
+}
+
+impl From<pallet_subtensor::SubnetIdentityV3> for SubnetIdentity {
+    fn from(identity: pallet_subtensor::SubnetIdentityV3) -> Self {
+        Self {
+            subnet_name: text(&identity.subnet_name),
+            github_repo: text(&identity.github_repo),
+            subnet_contact: text(&identity.subnet_contact),
+            subnet_url: text(&identity.subnet_url),
+            discord: text(&identity.discord),
+            description: text(&identity.description),
+            logo_url: text(&identity.logo_url),
+            additional: text(&identity.additional),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityField {
+    Name,
+    Url,
+    GithubRepo,
+    Image,
+    Discord,
+    Description,
+    Additional,
+    Contact,
+    LogoUrl,
+}
+
+impl From<identity_attestation::IdentityField> for IdentityField {
+    fn from(field: identity_attestation::IdentityField) -> Self {
+        use identity_attestation::IdentityField as Field;
+
+        match field {
+            Field::Name => Self::Name,
+            Field::Url => Self::Url,
+            Field::GithubRepo => Self::GithubRepo,
+            Field::Image => Self::Image,
+            Field::Discord => Self::Discord,
+            Field::Description => Self::Description,
+            Field::Additional => Self::Additional,
+            Field::Contact => Self::Contact,
+            Field::LogoUrl => Self::LogoUrl,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdentityAttestation {
+    pub attester: String,
+    pub field: IdentityField,
+    pub value_hash: String,
+    pub attested_at: u64,
+    pub expires_at: u64,
+}
+
+impl From<identity_attestation::IdentityAttestation<AccountId32>> for IdentityAttestation {
+    fn from(attestation: identity_attestation::IdentityAttestation<AccountId32>) -> Self {
+        Self {
+            attester: account(&attestation.attester),
+            field: attestation.field.into(),
+            value_hash: hash(attestation.value_hash),
+            attested_at: attestation.attested_at,
+            expires_at: attestation.expires_at,
+        }
+    }
+}
+
+fn attestations(
+    attestations: Vec<identity_attestation::IdentityAttestation<AccountId32>>,
+) -> Vec<IdentityAttestation> {
+    attestations.into_iter().map(Into::into).collect()
+}
+
+/// Stake of a coldkey on a neuron.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NeuronStake {
+    pub coldkey: String,
+    pub stake: String,
+}
+
+/// Weight or bond set by a neuron on another UID.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UidValue {
+    pub uid: u16,
+    pub value: u16,
+}
+
+fn uid_values(values: &[(Compact<u16>, Compact<u16>)]) -> Vec<UidValue> {
+    values
+        .iter()
+        .map(|(uid, value)| UidValue {
+            uid: uid.0,
+            value: value.0,
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NeuronInfoLite {
+    pub hotkey: String,
+    pub coldkey: String,
+    pub uid: u16,
+    pub netuid: u16,
+    pub active: bool,
+    pub axon_info: AxonInfo,
+    pub prometheus_info: PrometheusInfo,
+    pub stake: Vec<NeuronStake>,
+    pub rank: u16,
+    pub emission: String,
+    pub incentive: u16,
+    pub consensus: u16,
+    pub trust: u16,
+    pub validator_trust: u16,
+    pub dividends: u16,
+    pub last_update: u64,
+    pub validator_permit: bool,
+    pub pruning_score: u16,
+}
+
+impl From<neuron_info::NeuronInfoLite<AccountId32>> for NeuronInfoLite {
+    fn from(neuron: neuron_info::NeuronInfoLite<AccountId32>) -> Self {
+        Self {
+            hotkey: account(&neuron.hotkey),
+            coldkey: account(&neuron.coldkey),
+            uid: neuron.uid.0,
+            netuid: neuron.netuid.0.into(),
+            active: neuron.active,
+            axon_info: neuron.axon_info.into(),
+            prometheus_info: neuron.prometheus_info.into(),
+            stake: neuron_stake(&neuron.stake),
+            rank: neuron.rank.0,
+            emission: alpha(neuron.emission),
+            incentive: neuron.incentive.0,
+            consensus: neuron.consensus.0,
+            trust: neuron.trust.0,
+            validator_trust: neuron.validator_trust.0,
+            dividends: neuron.dividends.0,
+            last_update: neuron.last_update.0,
+            validator_permit: neuron.validator_permit,
+            pruning_score: neuron.pruning_score.0,
+        }
+    }
+}
+
+fn neuron_stake(stake: &[(AccountId32, Compact<AlphaCurrency>)]) -> Vec<NeuronStake> {
+    stake
+        .iter()
+        .map(|(coldkey, stake)| NeuronStake {
+            coldkey: account(coldkey),
+            stake: alpha(*stake),
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NeuronInfo {
+    #[serde(flatten)]
+    pub neuron: NeuronInfoLite,
+    pub weights: Vec<UidValue>,
+    pub bonds: Vec<UidValue>,
+}
+
+impl From<neuron_info::NeuronInfo<AccountId32>> for NeuronInfo {
+    fn from(neuron: neuron_info::NeuronInfo<AccountId32>) -> Self {
+        Self {
+            neuron: NeuronInfoLite {
+                hotkey: account(&neuron.hotkey),
+                coldkey: account(&neuron.coldkey),
+                uid: neuron.uid.0,
+                netuid: neuron.netuid.0.into(),
+                active: neuron.active,
+                axon_info: neuron.axon_info.into(),
+                prometheus_info: neuron.prometheus_info.into(),
+                stake: neuron_stake(&neuron.stake),
+                rank: neuron.rank.0,
+                emission: alpha(neuron.emission),
+                incentive: neuron.incentive.0,
+                consensus: neuron.consensus.0,
+                trust: neuron.trust.0,
+                validator_trust: neuron.validator_trust.0,
+                dividends: neuron.dividends.0,
+                last_update: neuron.last_update.0,
+                validator_permit: neuron.validator_permit,
+                pruning_score: neuron.pruning_score.0,
+            },
+            weights: uid_values(&neuron.weights),
+            bonds: uid_values(&neuron.bonds),
+        }
+    }
+}
+
+/// Stake of a nominator on a subnet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubnetStake {
+    pub netuid: u16,
+    pub stake: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Nominator {
+    pub nominator: String,
+    pub stakes: Vec<SubnetStake>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DelegateInfo {
+    pub delegate_ss58: String,
+    pub take: u16,
+    pub nominators: Vec<Nominator>,
+    pub owner_ss58: String,
+    pub registrations: Vec<u16>,
+    pub validator_permits: Vec<u16>,
+    pub return_per_1000: String,
+    pub total_daily_return: String,
+    pub identity: Option<ChainIdentity>,
+    pub identity_attestations: Vec<IdentityAttestation>,
+}
+
+fn netuids(netuids: &[Compact<NetUid>]) -> Vec<u16> {
+    netuids.iter().map(|netuid| netuid.0.into()).collect()
+}
+
+impl From<delegate_info::DelegateInfoV2<AccountId32>> for DelegateInfo {
+    fn from(delegate: delegate_info::DelegateInfoV2<AccountId32>) -> Self {
+        Self {
+            delegate_ss58: account(&delegate.delegate_ss58),
+            take: delegate.take.0,
+            nominators: delegate
+                .nominators
+                .iter()
+                .map(|(nominator, stakes)| Nominator {
+                    nominator: account(nominator),
+                    stakes: stakes
+                        .iter()
+                        .map(|(netuid, stake)| SubnetStake {
+                            netuid: netuid.0.into(),
+                            stake: balance(*stake),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            owner_ss58: account(&delegate.owner_ss58),
+            registrations: netuids(&delegate.registrations),
+            validator_permits: netuids(&delegate.validator_permits),
+            return_per_1000: balance(delegate.return_per_1000),
+            total_daily_return: balance(delegate.total_daily_return),
+            identity: delegate.identity.map(Into::into),
+            identity_attestations: attestations(delegate.identity_attestations),
+        }
+    }
+}
+
+/// A delegate and the stake of the queried account on one of its subnets.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DelegatedInfo {
+    pub delegate: DelegateInfo,
+    pub netuid: u16,
+    pub stake: String,
+}
+
+impl
+    From<(
+        delegate_info::DelegateInfoV2<AccountId32>,
+        (Compact<NetUid>, Compact<AlphaCurrency>),
+    )> for DelegatedInfo
+{
+    fn from(
+        (delegate, (netuid, stake)): (
+            delegate_info::DelegateInfoV2<AccountId32>,
+            (Compact<NetUid>, Compact<AlphaCurrency>),
+        ),
+    ) -> Self {
+        Self {
+            delegate: delegate.into(),
+            netuid: netuid.0.into(),
+            stake: alpha(stake),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubnetInfo {
+    pub netuid: u16,
+    pub rho: u16,
+    pub kappa: u16,
+    pub difficulty: u64,
+    pub immunity_period: u16,
+    pub max_allowed_validators: u16,
+    pub min_allowed_weights: u16,
+    pub max_weights_limit: u16,
+    pub scaling_law_power: u16,
+    pub subnetwork_n: u16,
+    pub max_allowed_uids: u16,
+    pub blocks_since_last_step: u64,
+    pub tempo: u16,
+    pub network_modality: u16,
+    pub network_connect: Vec<[u16; 2]>,
+    pub emission_value: String,
+    pub burn: String,
+    pub owner: String,
+    pub identity: Option<SubnetIdentity>,
+}
+
+impl From<subnet_info::SubnetInfov2<AccountId32>> for SubnetInfo {
+    fn from(subnet: subnet_info::SubnetInfov2<AccountId32>) -> Self {
+        Self {
+            netuid: subnet.netuid.0.into(),
+            rho: subnet.rho.0,
+            kappa: subnet.kappa.0,
+            difficulty: subnet.difficulty.0,
+            immunity_period: subnet.immunity_period.0,
+            max_allowed_validators: subnet.max_allowed_validators.0,
+            min_allowed_weights: subnet.min_allowed_weights.0,
+            max_weights_limit: subnet.max_weights_limit.0,
+            scaling_law_power: subnet.scaling_law_power.0,
+            subnetwork_n: subnet.subnetwork_n.0,
+            max_allowed_uids: subnet.max_allowed_uids.0,
+            blocks_since_last_step: subnet.blocks_since_last_step.0,
+            tempo: subnet.tempo.0,
+            network_modality: subnet.network_modality.0,
+            network_connect: subnet.network_connect,
+            emission_value: balance(subnet.emission_value),
+            burn: balance(subnet.burn),
+            owner: account(&subnet.owner),
+            identity: subnet.identity.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubnetHyperparams {
+    pub rho: u16,
+    pub kappa: u16,
+    pub immunity_period: u16,
+    pub min_allowed_weights: u16,
+    pub max_weights_limit: u16,
+    pub tempo: u16,
+    pub min_difficulty: u64,
+    pub max_difficulty: u64,
+    pub weights_version: u64,
+    pub weights_rate_limit: u64,
+    pub adjustment_interval: u16,
+    pub activity_cutoff: u16,
+    pub registration_allowed: bool,
+    pub target_regs_per_interval: u16,
+    pub min_burn: String,
+    pub max_burn: String,
+    pub bonds_moving_avg: u64,
+    pub max_regs_per_block: u16,
+    pub serving_rate_limit: u64,
+    pub max_validators: u16,
+    pub adjustment_alpha: u64,
+    pub difficulty: u64,
+    pub commit_reveal_period: u64,
+    pub commit_reveal_weights_enabled: bool,
+    pub alpha_high: u16,
+    pub alpha_low: u16,
+    pub liquid_alpha_enabled: bool,
+    pub alpha_sigmoid_steepness: String,
+    pub yuma_version: u16,
+    pub subnet_is_active: bool,
+    pub transfers_enabled: bool,
+    pub bonds_reset_enabled: bool,
+    pub user_liquidity_enabled: bool,
+}
+
+impl From<subnet_info::SubnetHyperparamsV2> for SubnetHyperparams {
+    fn from(params: subnet_info::SubnetHyperparamsV2) -> Self {
+        Self {
+            rho: params.rho.0,
+            kappa: params.kappa.0,
+            immunity_period: params.immunity_period.0,
+            min_allowed_weights: params.min_allowed_weights.0,
+            max_weights_limit: params.max_weights_limit.0,
+            tempo: params.tempo.0,
+            min_difficulty: params.min_difficulty.0,
+            max_difficulty: params.max_difficulty.0,
+            weights_version: params.weights_version.0,
+            weights_rate_limit: params.weights_rate_limit.0,
+            adjustment_interval: params.adjustment_interval.0,
+            activity_cutoff: params.activity_cutoff.0,
+            registration_allowed: params.registration_allowed,
+            target_regs_per_interval: params.target_regs_per_interval.0,
+            min_burn: balance(params.min_burn),
+            max_burn: balance(params.max_burn),
+            bonds_moving_avg: params.bonds_moving_avg.0,
+            max_regs_per_block: params.max_regs_per_block.0,
+            serving_rate_limit: params.serving_rate_limit.0,
+            max_validators: params.max_validators.0,
+            adjustment_alpha: params.adjustment_alpha.0,
+            difficulty: params.difficulty.0,
+            commit_reveal_period: params.commit_reveal_period.0,
+            commit_reveal_weights_enabled: params.commit_reveal_weights_enabled,
+            alpha_high: params.alpha_high.0,
+            alpha_low: params.alpha_low.0,
+            liquid_alpha_enabled: params.liquid_alpha_enabled,
+            alpha_sigmoid_steepness: fixed(params.alpha_sigmoid_steepness),
+            yuma_version: params.yuma_version.0,
+            subnet_is_active: params.subnet_is_active,
+            transfers_enabled: params.transfers_enabled,
+            bonds_reset_enabled: params.bonds_reset_enabled,
+            user_liquidity_enabled: params.user_liquidity_enabled,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DynamicInfo {
+    pub netuid: u16,
+    pub owner_hotkey: String,
+    pub owner_coldkey: String,
+    pub subnet_name: String,
+    pub token_symbol: String,
+    pub tempo: u16,
+    pub last_step: u64,
+    pub blocks_since_last_step: u64,
+    pub emission: String,
+    pub alpha_in: String,
+    pub alpha_out: String,
+    pub tao_in: String,
+    pub alpha_out_emission: String,
+    pub alpha_in_emission: String,
+    pub tao_in_emission: String,
+    pub pending_alpha_emission: String,
+    pub pending_root_emission: String,
+    pub subnet_volume: String,
+    pub network_registered_at: u64,
+    pub subnet_identity: Option<SubnetIdentity>,
+    pub moving_price: String,
+}
+
+impl From<dynamic_info::DynamicInfo<AccountId32>> for DynamicInfo {
+    fn from(info: dynamic_info::DynamicInfo<AccountId32>) -> Self {
+        Self {
+            netuid: info.netuid.0.into(),
+            owner_hotkey: account(&info.owner_hotkey),
+            owner_coldkey: account(&info.owner_coldkey),
+            subnet_name: compact_text(info.subnet_name),
+            token_symbol: compact_text(info.token_symbol),
+            tempo: info.tempo.0,
+            last_step: info.last_step.0,
+            blocks_since_last_step: info.blocks_since_last_step.0,
+            emission: balance(info.emission),
+            alpha_in: alpha(info.alpha_in),
+            alpha_out: alpha(info.alpha_out),
+            tao_in: balance(info.tao_in),
+            alpha_out_emission: alpha(info.alpha_out_emission),
+            alpha_in_emission: alpha(info.alpha_in_emission),
+            tao_in_emission: balance(info.tao_in_emission),
+            pending_alpha_emission: alpha(info.pending_alpha_emission),
+            pending_root_emission: balance(info.pending_root_emission),
+            subnet_volume: balance(info.subnet_volume),
+            network_registered_at: info.network_registered_at.0,
+            subnet_identity: info.subnet_identity.map(Into::into),
+            moving_price: fixed(info.moving_price),
+        }
+    }
+}
+
+/// Dividend paid to a hotkey.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotkeyAmount {
+    pub hotkey: String,
+    pub amount: String,
+}
+
+/// A full or selective metagraph. Fields that were not selected are omitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metagraph {
+    pub netuid: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<Option<SubnetIdentity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_registered_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_hotkey: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_coldkey: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tempo: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_step: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks_since_last_step: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subnet_emission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_in: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_out: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tao_in: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_out_emission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_in_emission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tao_in_emission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_alpha_emission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_root_emission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subnet_volume: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moving_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rho: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kappa: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_allowed_weights: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_weights_limit: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weights_version: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weights_rate_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_cutoff: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_validators: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_uids: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uids: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration_allowed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pow_registration_allowed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub immunity_period: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_difficulty: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_difficulty: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_burn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_burn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjustment_alpha: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjustment_interval: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_regs_per_interval: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_regs_per_block: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serving_rate_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_reveal_weights_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_reveal_period: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liquid_alpha_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_high: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_low: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bonds_moving_avg: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hotkeys: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coldkeys: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identities: Option<Vec<Option<ChainIdentity>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub axons: Option<Vec<AxonInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<Vec<bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator_permit: Option<Vec<bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pruning_score: Option<Vec<u16>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_update: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emission: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dividends: Option<Vec<u16>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incentives: Option<Vec<u16>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consensus: Option<Vec<u16>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust: Option<Vec<u16>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<Vec<u16>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_at_registration: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_stake: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tao_stake: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_stake: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tao_dividends_per_hotkey: Option<Vec<HotkeyAmount>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_dividends_per_hotkey: Option<Vec<HotkeyAmount>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validators: Option<Vec<u16>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub axon_endpoints: Option<Vec<Vec<AxonEndpoint>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_attestations: Option<Vec<Vec<IdentityAttestation>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subnet_identity_attestations: Option<Vec<IdentityAttestation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator_weight_metrics: Option<Vec<Vec<ValidatorWeightMetrics>>>,
+}
+
+fn tao_dividends(dividends: Vec<(AccountId32, Compact<u64>)>) -> Vec<HotkeyAmount> {
+    dividends
+        .into_iter()
+        .map(|(hotkey, dividend)| HotkeyAmount {
+            hotkey: account(hotkey),
+            amount: balance(dividend),
+        })
+        .collect()
+}
+
+fn alpha_dividends(dividends: Vec<(AccountId32, Compact<AlphaCurrency>)>) -> Vec<HotkeyAmount> {
+    dividends
+        .into_iter()
+        .map(|(hotkey, dividend)| HotkeyAmount {
+            hotkey: account(hotkey),
+            amount: alpha(dividend),
+        })
+        .collect()
+}
+
+fn subnet_identity(identity: Option<pallet_subtensor::SubnetIdentityV3>) -> Option<SubnetIdentity> {
+    identity.map(Into::into)
+}
+
+fn identities(
+    identities: Vec<Option<pallet_subtensor::ChainIdentityV2>>,
+) -> Vec<Option<ChainIdentity>> {
+    identities
+        .into_iter()
+        .map(|identity| identity.map(Into::into))
+        .collect()
+}
+
+fn axons(axons: Vec<pallet_subtensor::AxonInfo>) -> Vec<AxonInfo> {
+    axons.into_iter().map(Into::into).collect()
+}
+
+fn axon_endpoints(endpoints: Vec<Vec<pallet_subtensor::AxonEndpoint>>) -> Vec<Vec<AxonEndpoint>> {
+    endpoints
+        .into_iter()
+        .map(|endpoints| endpoints.into_iter().map(Into::into).collect())
+        .collect()
+}
+
+fn validator_weight_metrics(
+    metrics: Vec<Vec<pallet_subtensor::ValidatorWeightMetrics>>,
+) -> Vec<Vec<ValidatorWeightMetrics>> {
+    metrics
+        .into_iter()
+        .map(|history| history.into_iter().map(Into::into).collect())
+        .collect()
+}
+
+fn uid_attestations(
+    attestations_per_uid: Vec<Vec<identity_attestation::IdentityAttestation<AccountId32>>>,
+) -> Vec<Vec<IdentityAttestation>> {
+    attestations_per_uid.into_iter().map(attestations).collect()
+}
+
+/// Builds a [`Metagraph`] from the fields of a runtime metagraph. `$field` is applied to every
+/// field except `netuid`, so the same mapping serves the full and the selective metagraph.
+macro_rules! metagraph_v1 {
+    ($metagraph:ident, $field:ident) => {
+        Metagraph {
+            netuid: $metagraph.netuid.0.into(),
+            name: $field!($metagraph.name, compact_text),
+            symbol: $field!($metagraph.symbol, compact_text),
+            identity: $field!($metagraph.identity, subnet_identity),
+            network_registered_at: $field!($metagraph.network_registered_at, compact),
+            owner_hotkey: $field!($metagraph.owner_hotkey, account),
+            owner_coldkey: $field!($metagraph.owner_coldkey, account),
+            block: $field!($metagraph.block, compact),
+            tempo: $field!($metagraph.tempo, compact),
+            last_step: $field!($metagraph.last_step, compact),
+            blocks_since_last_step: $field!($metagraph.blocks_since_last_step, compact),
+            subnet_emission: $field!($metagraph.subnet_emission, balance),
+            alpha_in: $field!($metagraph.alpha_in, alpha),
+            alpha_out: $field!($metagraph.alpha_out, alpha),
+            tao_in: $field!($metagraph.tao_in, balance),
+            alpha_out_emission: $field!($metagraph.alpha_out_emission, alpha),
+            alpha_in_emission: $field!($metagraph.alpha_in_emission, alpha),
+            tao_in_emission: $field!($metagraph.tao_in_emission, balance),
+            pending_alpha_emission: $field!($metagraph.pending_alpha_emission, alpha),
+            pending_root_emission: $field!($metagraph.pending_root_emission, balance),
+            subnet_volume: $field!($metagraph.subnet_volume, balance),
+            moving_price: $field!($metagraph.moving_price, fixed),
+            rho: $field!($metagraph.rho, compact),
+            kappa: $field!($metagraph.kappa, compact),
+            min_allowed_weights: $field!($metagraph.min_allowed_weights, compact),
+            max_weights_limit: $field!($metagraph.max_weights_limit, compact),
+            weights_version: $field!($metagraph.weights_version, compact),
+            weights_rate_limit: $field!($metagraph.weights_rate_limit, compact),
+            activity_cutoff: $field!($metagraph.activity_cutoff, compact),
+            max_validators: $field!($metagraph.max_validators, compact),
+            num_uids: $field!($metagraph.num_uids, compact),
+            max_uids: $field!($metagraph.max_uids, compact),
+            burn: $field!($metagraph.burn, balance),
+            difficulty: $field!($metagraph.difficulty, compact),
+            registration_allowed: $field!($metagraph.registration_allowed),
+            pow_registration_allowed: $field!($metagraph.pow_registration_allowed),
+            immunity_period: $field!($metagraph.immunity_period, compact),
+            min_difficulty: $field!($metagraph.min_difficulty, compact),
+            max_difficulty: $field!($metagraph.max_difficulty, compact),
+            min_burn: $field!($metagraph.min_burn, balance),
+            max_burn: $field!($metagraph.max_burn, balance),
+            adjustment_alpha: $field!($metagraph.adjustment_alpha, compact),
+            adjustment_interval: $field!($metagraph.adjustment_interval, compact),
+            target_regs_per_interval: $field!($metagraph.target_regs_per_interval, compact),
+            max_regs_per_block: $field!($metagraph.max_regs_per_block, compact),
+            serving_rate_limit: $field!($metagraph.serving_rate_limit, compact),
+            commit_reveal_weights_enabled: $field!($metagraph.commit_reveal_weights_enabled),
+            commit_reveal_period: $field!($metagraph.commit_reveal_period, compact),
+            liquid_alpha_enabled: $field!($metagraph.liquid_alpha_enabled),
+            alpha_high: $field!($metagraph.alpha_high, compact),
+            alpha_low: $field!($metagraph.alpha_low, compact),
+            bonds_moving_avg: $field!($metagraph.bonds_moving_avg, compact),
+            hotkeys: $field!($metagraph.hotkeys, accounts),
+            coldkeys: $field!($metagraph.coldkeys, accounts),
+            identities: $field!($metagraph.identities, identities),
+            axons: $field!($metagraph.axons, axons),
+            active: $field!($metagraph.active),
+            validator_permit: $field!($metagraph.validator_permit),
+            pruning_score: $field!($metagraph.pruning_score, compacts),
+            last_update: $field!($metagraph.last_update, compacts),
+            emission: $field!($metagraph.emission, alphas),
+            dividends: $field!($metagraph.dividends, compacts),
+            incentives: $field!($metagraph.incentives, compacts),
+            consensus: $field!($metagraph.consensus, compacts),
+            trust: $field!($metagraph.trust, compacts),
+            rank: $field!($metagraph.rank, compacts),
+            block_at_registration: $field!($metagraph.block_at_registration, compacts),
+            alpha_stake: $field!($metagraph.alpha_stake, balances),
+            tao_stake: $field!($metagraph.tao_stake, balances),
+            total_stake: $field!($metagraph.total_stake, balances),
+            tao_dividends_per_hotkey: $field!($metagraph.tao_dividends_per_hotkey, tao_dividends),
+            alpha_dividends_per_hotkey: $field!(
+                $metagraph.alpha_dividends_per_hotkey,
+                alpha_dividends
+            ),
+            axon_endpoints: $field!($metagraph.axon_endpoints, axon_endpoints),
+            identity_attestations: $field!($metagraph.identity_attestations, uid_attestations),
+            subnet_identity_attestations: $field!(
+                $metagraph.subnet_identity_attestations,
+                attestations
+            ),
+            validator_weight_metrics: $field!(
+                $metagraph.validator_weight_metrics,
+                validator_weight_metrics
+            ),
+            ..Default::default()
+        }
+    };
+}
+
+/// Maps a field that is always present.
+macro_rules! present {
+    ($value:expr) => {
+        Some($value)
+    };
+    ($value:expr, $map:expr) => {
+        Some($map($value))
+    };
+}
+
+/// Maps a field that may not have been selected.
+macro_rules! selected {
+    ($value:expr) => {
+        $value
+    };
+    ($value:expr, $map:expr) => {
+        $value.map($map)
+    };
+}
+
+impl From<metagraph::Metagraph<AccountId32>> for Metagraph {
+    fn from(metagraph: metagraph::Metagraph<AccountId32>) -> Self {
+        metagraph_v1!(metagraph, present)
+    }
+}
+
+impl From<metagraph::SelectiveMetagraph<AccountId32>> for Metagraph {
+    fn from(mut metagraph: metagraph::SelectiveMetagraph<AccountId32>) -> Self {
+        let validators = metagraph.validators.take().map(compacts);
+        Metagraph {
+            validators,
+            ..metagraph_v1!(metagraph, selected)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubnetState {
+    pub netuid: u16,
+    pub hotkeys: Vec<String>,
+    pub coldkeys: Vec<String>,
+    pub active: Vec<bool>,
+    pub validator_permit: Vec<bool>,
+    pub pruning_score: Vec<u16>,
+    pub last_update: Vec<u64>,
+    pub emission: Vec<String>,
+    pub dividends: Vec<u16>,
+    pub incentives: Vec<u16>,
+    pub consensus: Vec<u16>,
+    pub trust: Vec<u16>,
+    pub rank: Vec<u16>,
+    pub block_at_registration: Vec<u64>,
+    pub alpha_stake: Vec<String>,
+    pub tao_stake: Vec<String>,
+    pub total_stake: Vec<String>,
+    pub emission_history: Vec<Vec<String>>,
+}
+
+impl From<show_subnet::SubnetState<AccountId32>> for SubnetState {
+    fn from(state: show_subnet::SubnetState<AccountId32>) -> Self {
+        Self {
+            netuid: state.netuid.0.into(),
+            hotkeys: accounts(state.hotkeys),
+            coldkeys: accounts(state.coldkeys),
+            active: state.active,
+            validator_permit: state.validator_permit,
+            pruning_score: compacts(state.pruning_score),
+            last_update: compacts(state.last_update),
+            emission: alphas(state.emission),
+            dividends: compacts(state.dividends),
+            incentives: compacts(state.incentives),
+            consensus: compacts(state.consensus),
+            trust: compacts(state.trust),
+            rank: compacts(state.rank),
+            block_at_registration: compacts(state.block_at_registration),
+            alpha_stake: balances(state.alpha_stake),
+            tao_stake: balances(state.tao_stake),
+            total_stake: balances(state.total_stake),
+            emission_history: state.emission_history.into_iter().map(alphas).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hyperparameter {
+    ServingRateLimit,
+    MaxDifficulty,
+    WeightsVersionKey,
+    AdjustmentAlpha,
+    MaxWeightLimit,
+    ImmunityPeriod,
+    MinAllowedWeights,
+    Kappa,
+    Rho,
+    ActivityCutoff,
+    NetworkPowRegistrationAllowed,
+    BondsMovingAverage,
+    BondsPenalty,
+    CommitRevealWeightsEnabled,
+    LiquidAlphaEnabled,
+    AlphaLow,
+    AlphaHigh,
+    CommitRevealWeightsInterval,
+    TransferEnabled,
+    AlphaSigmoidSteepness,
+    Yuma3Enabled,
+    BondsResetEnabled,
+}
+
+impl From<roles::Hyperparameter> for Hyperparameter {
+    fn from(param: roles::Hyperparameter) -> Self {
+        use roles::Hyperparameter as Param;
+
+        match param {
+            Param::ServingRateLimit => Self::ServingRateLimit,
+            Param::MaxDifficulty => Self::MaxDifficulty,
+            Param::WeightsVersionKey => Self::WeightsVersionKey,
+            Param::AdjustmentAlpha => Self::AdjustmentAlpha,
+            Param::MaxWeightLimit => Self::MaxWeightLimit,
+            Param::ImmunityPeriod => Self::ImmunityPeriod,
+            Param::MinAllowedWeights => Self::MinAllowedWeights,
+            Param::Kappa => Self::Kappa,
+            Param::Rho => Self::Rho,
+            Param::ActivityCutoff => Self::ActivityCutoff,
+            Param::NetworkPowRegistrationAllowed => Self::NetworkPowRegistrationAllowed,
+            Param::BondsMovingAverage => Self::BondsMovingAverage,
+            Param::BondsPenalty => Self::BondsPenalty,
+            Param::CommitRevealWeightsEnabled => Self::CommitRevealWeightsEnabled,
+            Param::LiquidAlphaEnabled => Self::LiquidAlphaEnabled,
+            Param::AlphaLow => Self::AlphaLow,
+            Param::AlphaHigh => Self::AlphaHigh,
+            Param::CommitRevealWeightsInterval => Self::CommitRevealWeightsInterval,
+            Param::TransferEnabled => Self::TransferEnabled,
+            Param::AlphaSigmoidSteepness => Self::AlphaSigmoidSteepness,
+            Param::Yuma3Enabled => Self::Yuma3Enabled,
+            Param::BondsResetEnabled => Self::BondsResetEnabled,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HyperparameterGroup {
+    Registration,
+    Weights,
+    Consensus,
+    Network,
+}
+
+impl From<roles::HyperparameterGroup> for HyperparameterGroup {
+    fn from(group: roles::HyperparameterGroup) -> Self {
+        use roles::HyperparameterGroup as Group;
+
+        match group {
+            Group::Registration => Self::Registration,
+            Group::Weights => Self::Weights,
+            Group::Consensus => Self::Consensus,
+            Group::Network => Self::Network,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HyperparameterBound {
+    pub param: Hyperparameter,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl From<roles::HyperparameterBound> for HyperparameterBound {
+    fn from(bound: roles::HyperparameterBound) -> Self {
+        Self {
+            param: bound.param.into(),
+            min: bound.min,
+            max: bound.max,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubnetRole {
+    pub delegate: String,
+    pub groups: Vec<HyperparameterGroup>,
+    pub bounds: Vec<HyperparameterBound>,
+    pub expires_at: Option<u64>,
+    pub active: bool,
+}
+
+impl From<subnet_info::SubnetRoleInfo<AccountId32>> for SubnetRole {
+    fn from(role: subnet_info::SubnetRoleInfo<AccountId32>) -> Self {
+        Self {
+            delegate: account(&role.delegate),
+            groups: role.groups.into_iter().map(Into::into).collect(),
+            bounds: role.bounds.into_iter().map(Into::into).collect(),
+            expires_at: role.expires_at,
+            active: role.active,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledHyperparameterChange {
+    pub param: Hyperparameter,
+    pub value: u64,
+    pub scheduled_at: u64,
+    pub effective_block: u64,
+    pub by_root: bool,
+}
+
+impl From<hyperparameter_schedule::ScheduledHyperparameterChange>
+    for ScheduledHyperparameterChange
+{
+    fn from(change: hyperparameter_schedule::ScheduledHyperparameterChange) -> Self {
+        Self {
+            param: change.param.into(),
+            value: change.value,
+            scheduled_at: change.scheduled_at,
+            effective_block: change.effective_block,
+            by_root: change.by_root,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightCommitKind {
+    Hash,
+    Timelocked,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeightCommit {
+    pub hotkey: String,
+    pub netuid: NetUid,
+    pub kind: WeightCommitKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reveal_round: Option<u64>,
+    pub commit_block: u64,
+    pub reveal_epoch: u64,
+    pub first_reveal_block: u64,
+    pub last_reveal_block: u64,
+}
+
+impl From<weight_commits::WeightCommitInfo<AccountId32>> for WeightCommit {
+    fn from(commit: weight_commits::WeightCommitInfo<AccountId32>) -> Self {
+        let (kind, hash, reveal_round) = match commit.kind {
+            weight_commits::WeightCommitKind::Hash(commit_hash) => {
+                (WeightCommitKind::Hash, Some(hash(commit_hash)), None)
+            }
+            weight_commits::WeightCommitKind::Timelocked { reveal_round } => {
+                (WeightCommitKind::Timelocked, None, Some(reveal_round))
+            }
+        };
+
+        Self {
+            hotkey: account(&commit.hotkey),
+            netuid: compact(commit.netuid),
+            kind,
+            hash,
+            reveal_round,
+            commit_block: compact(commit.commit_block),
+            reveal_epoch: compact(commit.reveal_epoch),
+            first_reveal_block: compact(commit.first_reveal_block),
+            last_reveal_block: compact(commit.last_reveal_block),
+        }
+    }
+}
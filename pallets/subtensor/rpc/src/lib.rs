@@ -11,6 +11,9 @@ use sp_runtime::{AccountId32, traits::Block as BlockT};
 use std::sync::Arc;
 use subtensor_runtime_common::NetUid;
 
+pub mod json;
+use json::v1;
+
 use sp_api::ProvideRuntimeApi;
 
 pub use subtensor_custom_rpc_runtime_api::{
@@ -34,6 +37,20 @@ pub trait SubtensorCustomApi<BlockHash> {
         delegatee_account_vec: Vec<u8>,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegatesV2")]
+    fn get_delegates_v2(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegateV2")]
+    fn get_delegate_v2(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegatedV2")]
+    fn get_delegated_v2(
+        &self,
+        delegatee_account_vec: Vec<u8>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "neuronInfo_getNeuronsLite")]
     fn get_neurons_lite(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
@@ -48,6 +65,20 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_neurons(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "neuronInfo_getNeuron")]
     fn get_neuron(&self, netuid: NetUid, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getAxonEndpoints")]
+    fn get_neuron_axon_endpoints(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getWeightsKey")]
+    fn get_neuron_weights_key(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetInfo")]
     fn get_subnet_info(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetsInfo")]
@@ -83,6 +114,155 @@ pub trait SubtensorCustomApi<BlockHash> {
         metagraph_index: Vec<u16>,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetRoles")]
+    fn get_subnet_roles(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getScheduledHyperparameterChanges")]
+    fn get_scheduled_hyperparameter_changes(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getHyperparameterBounds")]
+    fn get_hyperparameter_bounds(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getWeightCommits")]
+    fn get_weight_commits(
+        &self,
+        netuid: NetUid,
+        hotkey_account_vec: Option<Vec<u8>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+
+    // Typed JSON variants of the methods above, see [`json`].
+    #[method(name = "delegateInfo_getDelegatesJson_v1")]
+    fn get_delegates_json_v1(&self, at: Option<BlockHash>) -> RpcResult<Vec<v1::DelegateInfo>>;
+    #[method(name = "delegateInfo_getDelegateJson_v1")]
+    fn get_delegate_json_v1(
+        &self,
+        delegate_account: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::DelegateInfo>>;
+    #[method(name = "delegateInfo_getDelegatedJson_v1")]
+    fn get_delegated_json_v1(
+        &self,
+        delegatee_account: AccountId32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<v1::DelegatedInfo>>;
+    #[method(name = "neuronInfo_getNeuronsLiteJson_v1")]
+    fn get_neurons_lite_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<v1::NeuronInfoLite>>;
+    #[method(name = "neuronInfo_getNeuronLiteJson_v1")]
+    fn get_neuron_lite_json_v1(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::NeuronInfoLite>>;
+    #[method(name = "neuronInfo_getNeuronsJson_v1")]
+    fn get_neurons_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<v1::NeuronInfo>>;
+    #[method(name = "neuronInfo_getNeuronJson_v1")]
+    fn get_neuron_json_v1(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::NeuronInfo>>;
+    #[method(name = "neuronInfo_getAxonEndpointsJson_v1")]
+    fn get_neuron_axon_endpoints_json_v1(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<v1::AxonEndpoint>>;
+    #[method(name = "neuronInfo_getWeightsKeyJson_v1")]
+    fn get_neuron_weights_key_json_v1(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<String>>;
+    #[method(name = "subnetInfo_getSubnetInfoJson_v1")]
+    fn get_subnet_info_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::SubnetInfo>>;
+    #[method(name = "subnetInfo_getSubnetsInfoJson_v1")]
+    fn get_subnets_info_json_v1(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<v1::SubnetInfo>>>;
+    #[method(name = "subnetInfo_getSubnetHyperparamsJson_v1")]
+    fn get_subnet_hyperparams_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::SubnetHyperparams>>;
+    #[method(name = "subnetInfo_getAllDynamicInfoJson_v1")]
+    fn get_all_dynamic_info_json_v1(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<v1::DynamicInfo>>>;
+    #[method(name = "subnetInfo_getDynamicInfoJson_v1")]
+    fn get_dynamic_info_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::DynamicInfo>>;
+    #[method(name = "subnetInfo_getAllMetagraphsJson_v1")]
+    fn get_all_metagraphs_json_v1(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<v1::Metagraph>>>;
+    #[method(name = "subnetInfo_getMetagraphJson_v1")]
+    fn get_metagraph_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::Metagraph>>;
+    #[method(name = "subnetInfo_getSubnetStateJson_v1")]
+    fn get_subnet_state_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::SubnetState>>;
+    #[method(name = "subnetInfo_getSelectiveMetagraphJson_v1")]
+    fn get_selective_metagraph_json_v1(
+        &self,
+        netuid: NetUid,
+        metagraph_index: Vec<u16>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<v1::Metagraph>>;
+    #[method(name = "subnetInfo_getSubnetRolesJson_v1")]
+    fn get_subnet_roles_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<v1::SubnetRole>>;
+    #[method(name = "subnetInfo_getScheduledHyperparameterChangesJson_v1")]
+    fn get_scheduled_hyperparameter_changes_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<v1::ScheduledHyperparameterChange>>;
+    #[method(name = "subnetInfo_getHyperparameterBoundsJson_v1")]
+    fn get_hyperparameter_bounds_json_v1(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<v1::HyperparameterBound>>;
+    #[method(name = "subnetInfo_getWeightCommitsJson_v1")]
+    fn get_weight_commits_json_v1(
+        &self,
+        netuid: NetUid,
+        hotkey_account: Option<AccountId32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<v1::WeightCommit>>;
 }
 
 pub struct SubtensorCustom<C, P> {
@@ -192,6 +372,66 @@ where
         }
     }
 
+    fn get_delegates_v2(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_delegates_v2(at) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_delegate_v2(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let delegate_account = match AccountId32::decode(&mut &delegate_account_vec[..]) {
+            Ok(delegate_account) => delegate_account,
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into(),
+                );
+            }
+        };
+        match api.get_delegate_v2(at, delegate_account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_delegated_v2(
+        &self,
+        delegatee_account_vec: Vec<u8>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let delegatee_account = match AccountId32::decode(&mut &delegatee_account_vec[..]) {
+            Ok(delegatee_account) => delegatee_account,
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into(),
+                );
+            }
+        };
+        match api.get_delegated_v2(at, delegatee_account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into())
+            }
+        }
+    }
+
     fn get_neurons_lite(
         &self,
         netuid: NetUid,
@@ -258,6 +498,44 @@ where
         }
     }
 
+    fn get_neuron_axon_endpoints(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_neuron_axon_endpoints(at, netuid, uid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get neuron axon endpoints: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_neuron_weights_key(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_neuron_weights_key(at, netuid, uid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get neuron weights key: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
     fn get_subnet_info(
         &self,
         netuid: NetUid,
@@ -448,4 +726,457 @@ where
             .into()),
         }
     }
+
+    fn get_subnet_roles(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_roles(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet roles: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_scheduled_hyperparameter_changes(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_scheduled_hyperparameter_changes(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get scheduled hyperparameter changes: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_hyperparameter_bounds(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_hyperparameter_bounds(at) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get hyperparameter bounds: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_weight_commits(
+        &self,
+        netuid: NetUid,
+        hotkey_account_vec: Option<Vec<u8>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let hotkey_account = match hotkey_account_vec
+            .map(|vec| AccountId32::decode(&mut &vec[..]))
+            .transpose()
+        {
+            Ok(hotkey_account) => hotkey_account,
+            Err(e) => {
+                return Err(
+                    Error::RuntimeError(format!("Unable to get weight commits: {:?}", e)).into(),
+                );
+            }
+        };
+
+        match api.get_weight_commits(at, netuid, hotkey_account) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get weight commits: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_delegates_json_v1(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::DelegateInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_delegates_v2(at) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_delegate_json_v1(
+        &self,
+        delegate_account: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::DelegateInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_delegate_v2(at, delegate_account) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_delegated_json_v1(
+        &self,
+        delegatee_account: AccountId32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::DelegatedInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_delegated_v2(at, delegatee_account) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_neurons_lite_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::NeuronInfoLite>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_neurons_lite(at, netuid) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_neuron_lite_json_v1(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::NeuronInfoLite>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_neuron_lite(at, netuid, uid) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_neurons_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::NeuronInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_neurons(at, netuid) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get neurons info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_neuron_json_v1(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::NeuronInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_neuron(at, netuid, uid) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_neuron_axon_endpoints_json_v1(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::AxonEndpoint>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_neuron_axon_endpoints(at, netuid, uid) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get neuron axon endpoints: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_neuron_weights_key_json_v1(
+        &self,
+        netuid: NetUid,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<String>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_neuron_weights_key(at, netuid, uid) {
+            Ok(result) => Ok(result.map(|weights_key| weights_key.to_string())),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get neuron weights key: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_subnet_info_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::SubnetInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_info_v2(at, netuid) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_subnets_info_json_v1(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Option<v1::SubnetInfo>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnets_info_v2(at) {
+            Ok(result) => Ok(result
+                .into_iter()
+                .map(|item| item.map(Into::into))
+                .collect()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnets info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_subnet_hyperparams_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::SubnetHyperparams>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_hyperparams_v2(at, netuid) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_all_dynamic_info_json_v1(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Option<v1::DynamicInfo>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_all_dynamic_info(at) {
+            Ok(result) => Ok(result
+                .into_iter()
+                .map(|item| item.map(Into::into))
+                .collect()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get dynamic subnets info: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_dynamic_info_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::DynamicInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_dynamic_info(at, netuid) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get dynamic subnets info: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_all_metagraphs_json_v1(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Option<v1::Metagraph>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_all_metagraphs(at) {
+            Ok(result) => Ok(result
+                .into_iter()
+                .map(|item| item.map(Into::into))
+                .collect()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get metagraphs info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_metagraph_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::Metagraph>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_metagraph(at, netuid) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get metagraph info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_subnet_state_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::SubnetState>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_state(at, netuid) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet state info: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_selective_metagraph_json_v1(
+        &self,
+        netuid: NetUid,
+        metagraph_index: Vec<u16>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<v1::Metagraph>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_selective_metagraph(at, netuid, metagraph_index) {
+            Ok(result) => Ok(result.map(Into::into)),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get selective metagraph: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_subnet_roles_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::SubnetRole>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_roles(at, netuid) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet roles: {:?}", e)).into())
+            }
+        }
+    }
+
+    fn get_scheduled_hyperparameter_changes_json_v1(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::ScheduledHyperparameterChange>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_scheduled_hyperparameter_changes(at, netuid) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get scheduled hyperparameter changes: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_hyperparameter_bounds_json_v1(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::HyperparameterBound>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_hyperparameter_bounds(at) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get hyperparameter bounds: {:?}",
+                e
+            ))
+            .into()),
+        }
+    }
+
+    fn get_weight_commits_json_v1(
+        &self,
+        netuid: NetUid,
+        hotkey_account: Option<AccountId32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<v1::WeightCommit>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_weight_commits(at, netuid, hotkey_account) {
+            Ok(result) => Ok(result.into_iter().map(Into::into).collect()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get weight commits: {:?}", e)).into())
+            }
+        }
+    }
 }
@@ -2,15 +2,21 @@
 extern crate alloc;
 use alloc::vec::Vec;
 use codec::Compact;
+use pallet_subtensor::AxonEndpoint;
 use pallet_subtensor::rpc_info::{
-    delegate_info::DelegateInfo,
+    delegate_info::{DelegateInfo, DelegateInfoV2},
     dynamic_info::DynamicInfo,
     metagraph::{Metagraph, SelectiveMetagraph},
     neuron_info::{NeuronInfo, NeuronInfoLite},
     show_subnet::SubnetState,
     stake_info::StakeInfo,
-    subnet_info::{SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2},
+    subnet_info::{
+        SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2, SubnetRoleInfo,
+    },
+    weight_commits::WeightCommitInfo,
 };
+use pallet_subtensor::subnets::hyperparameter_schedule::ScheduledHyperparameterChange;
+use pallet_subtensor::subnets::roles::HyperparameterBound;
 use sp_runtime::AccountId32;
 use subtensor_runtime_common::{AlphaCurrency, NetUid};
 
@@ -21,6 +27,9 @@ sp_api::decl_runtime_apis! {
         fn get_delegates() -> Vec<DelegateInfo<AccountId32>>;
         fn get_delegate( delegate_account: AccountId32 ) -> Option<DelegateInfo<AccountId32>>;
         fn get_delegated( delegatee_account: AccountId32 ) -> Vec<(DelegateInfo<AccountId32>, (Compact<NetUid>, Compact<AlphaCurrency>))>;
+        fn get_delegates_v2() -> Vec<DelegateInfoV2<AccountId32>>;
+        fn get_delegate_v2( delegate_account: AccountId32 ) -> Option<DelegateInfoV2<AccountId32>>;
+        fn get_delegated_v2( delegatee_account: AccountId32 ) -> Vec<(DelegateInfoV2<AccountId32>, (Compact<NetUid>, Compact<AlphaCurrency>))>;
     }
 
     pub trait NeuronInfoRuntimeApi {
@@ -28,6 +37,8 @@ sp_api::decl_runtime_apis! {
         fn get_neuron(netuid: NetUid, uid: u16) -> Option<NeuronInfo<AccountId32>>;
         fn get_neurons_lite(netuid: NetUid) -> Vec<NeuronInfoLite<AccountId32>>;
         fn get_neuron_lite(netuid: NetUid, uid: u16) -> Option<NeuronInfoLite<AccountId32>>;
+        fn get_neuron_axon_endpoints(netuid: NetUid, uid: u16) -> Vec<AxonEndpoint>;
+        fn get_neuron_weights_key(netuid: NetUid, uid: u16) -> Option<AccountId32>;
     }
 
     pub trait SubnetInfoRuntimeApi {
@@ -43,6 +54,10 @@ sp_api::decl_runtime_apis! {
         fn get_dynamic_info(netuid: NetUid) -> Option<DynamicInfo<AccountId32>>;
         fn get_subnet_state(netuid: NetUid) -> Option<SubnetState<AccountId32>>;
         fn get_selective_metagraph(netuid: NetUid, metagraph_indexes: Vec<u16>) -> Option<SelectiveMetagraph<AccountId32>>;
+        fn get_subnet_roles(netuid: NetUid) -> Vec<SubnetRoleInfo<AccountId32>>;
+        fn get_scheduled_hyperparameter_changes(netuid: NetUid) -> Vec<ScheduledHyperparameterChange>;
+        fn get_hyperparameter_bounds() -> Vec<HyperparameterBound>;
+        fn get_weight_commits(netuid: NetUid, hotkey: Option<AccountId32>) -> Vec<WeightCommitInfo<AccountId32>>;
     }
 
     pub trait StakeInfoRuntimeApi {
@@ -672,7 +672,7 @@ mod pallet_benchmarks {
         assert_eq!(FirstEmissionBlockNumber::<T>::get(netuid), None);
 
         let current_block: u64 = Subtensor::<T>::get_current_block_as_u64();
-        let duration = <T as Config>::DurationOfStartCall::get();
+        let duration = StartCallDelay::<T>::get();
         let block: BlockNumberFor<T> = (current_block + duration)
             .try_into()
             .ok()
@@ -1168,6 +1168,45 @@ mod pallet_benchmarks {
         );
     }
 
+    #[benchmark]
+    fn serve_axons() {
+        let netuid = NetUid::from(1);
+        let caller: T::AccountId = whitelisted_caller();
+        let version: u32 = 2;
+        let endpoints: Vec<AxonEndpointOf> = (0..MAX_AXON_ENDPOINTS)
+            .map(|i| AxonEndpoint {
+                protocol: 0,
+                ip: 1676056785,
+                ip_type: 4,
+                port: 128u16.saturating_add(i as u16),
+                priority: i as u8,
+            })
+            .collect();
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        SubtokenEnabled::<T>::insert(netuid, true);
+        Subtensor::<T>::set_max_allowed_uids(netuid, 4096);
+
+        let reg_fee: u64 = Subtensor::<T>::get_burn_as_u64(netuid);
+        let deposit = reg_fee.saturating_mul(2);
+        Subtensor::<T>::add_balance_to_coldkey_account(&caller, deposit);
+
+        assert_ok!(Subtensor::<T>::do_burned_registration(
+            RawOrigin::Signed(caller.clone()).into(),
+            netuid,
+            caller.clone()
+        ));
+        Subtensor::<T>::set_serving_rate_limit(netuid, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            netuid,
+            version,
+            endpoints,
+        );
+    }
+
     #[benchmark]
     fn serve_axon_tls() {
         let caller: T::AccountId = whitelisted_caller();
@@ -1598,4 +1637,84 @@ mod pallet_benchmarks {
 
         assert_eq!(TokenSymbol::<T>::get(netuid), new_symbol);
     }
+
+    #[benchmark]
+    fn grant_subnet_role() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("Delegate", 0, 1);
+        let netuid = NetUid::from(1);
+        Subtensor::<T>::init_new_network(netuid, 1);
+        SubnetOwner::<T>::insert(netuid, coldkey.clone());
+
+        let groups = vec![
+            subnets::roles::HyperparameterGroup::Registration,
+            subnets::roles::HyperparameterGroup::Weights,
+        ];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey),
+            netuid,
+            delegate.clone(),
+            groups,
+            vec![],
+            None,
+        );
+
+        assert!(SubnetRoles::<T>::contains_key(netuid, delegate));
+    }
+
+    #[benchmark]
+    fn revoke_subnet_role() {
+        let coldkey: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("Delegate", 0, 1);
+        let netuid = NetUid::from(1);
+        Subtensor::<T>::init_new_network(netuid, 1);
+        SubnetOwner::<T>::insert(netuid, coldkey.clone());
+
+        assert_ok!(Subtensor::<T>::grant_subnet_role(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            netuid,
+            delegate.clone(),
+            vec![subnets::roles::HyperparameterGroup::Consensus],
+            vec![],
+            None,
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey), netuid, delegate.clone());
+
+        assert!(!SubnetRoles::<T>::contains_key(netuid, delegate));
+    }
+
+    #[benchmark]
+    fn attest_identity() {
+        let coldkey: T::AccountId = account("Coldkey", 0, 1);
+        let attester: T::AccountId = whitelisted_caller();
+        IdentitiesV2::<T>::insert(
+            coldkey.clone(),
+            ChainIdentityV2 {
+                name: b"name".to_vec(),
+                url: vec![],
+                github_repo: b"https://github.com/opentensor".to_vec(),
+                image: vec![],
+                discord: vec![],
+                description: vec![],
+                additional: vec![],
+            },
+        );
+        IdentityAttesters::<T>::insert(attester.clone(), true);
+
+        let target = utils::identity_attestation::IdentityAttestationTarget::Coldkey(coldkey);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(attester),
+            target.clone(),
+            utils::identity_attestation::IdentityField::GithubRepo,
+            1_000,
+        );
+
+        assert_eq!(IdentityAttestations::<T>::get(target).len(), 1);
+    }
 }
@@ -3,6 +3,7 @@ use ark_serialize::CanonicalDeserialize;
 use codec::Decode;
 use frame_support::dispatch;
 use frame_support::traits::OriginTrait;
+use pallet_drand::types::RoundNumber;
 use subtensor_runtime_common::NetUid;
 use tle::curves::drand::TinyBLS381;
 use tle::stream_ciphers::AESGCMStreamCipherProvider;
@@ -23,6 +24,28 @@ pub struct WeightsTlockPayload {
     pub version_key: u64,
 }
 
+/// Why a timelocked weights commit could not be revealed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RevealFailureReason {
+    /// The commit is not a valid timelock ciphertext.
+    CommitDeserialization,
+    /// The drand pulse of the commit's reveal round is not on chain.
+    MissingPulse {
+        /// The drand round the commit was encrypted to.
+        round: RoundNumber,
+    },
+    /// The signature of the drand pulse is malformed.
+    SignatureDeserialization,
+    /// The commit could not be decrypted with the drand pulse.
+    Decryption,
+    /// The decrypted commit is not a weights payload.
+    PayloadDeserialization,
+    /// The weights version key of the payload is outdated.
+    VersionKeyMismatch,
+    /// Setting the revealed weights failed.
+    InvalidWeights(DispatchError),
+}
+
 impl<T: Config> Pallet<T> {
     /// The `reveal_crv3_commits` function is run at the very beginning of epoch `n`,
     pub fn reveal_crv3_commits(netuid: NetUid) -> dispatch::DispatchResult {
@@ -52,105 +75,115 @@ impl<T: Config> Pallet<T> {
         while let Some((who, _commit_block, serialized_compresssed_commit, round_number)) =
             entries.pop_front()
         {
-            let reader = &mut &serialized_compresssed_commit[..];
-            let commit = match TLECiphertext::<TinyBLS381>::deserialize_compressed(reader) {
-                Ok(c) => c,
-                Err(e) => {
-                    log::warn!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing the commit: {:?}",
-                        netuid,
-                        who,
-                        e
-                    );
-                    continue;
-                }
-            };
-
-            // Try to get the round number from pallet_drand.
-            let pulse = match pallet_drand::Pulses::<T>::get(round_number) {
-                Some(p) => p,
-                None => {
-                    // Round number used was not found on the chain. Skip this commit.
-                    log::warn!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to missing round number {} at time of reveal.",
-                        netuid,
-                        who,
-                        round_number
-                    );
-                    continue;
-                }
-            };
-
-            let signature_bytes = pulse
-                .signature
-                .strip_prefix(b"0x")
-                .unwrap_or(&pulse.signature);
-
-            let sig_reader = &mut &signature_bytes[..];
-            let sig = match <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(
-                sig_reader,
-            ) {
-                Ok(s) => s,
-                Err(e) => {
-                    log::error!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing signature from drand pallet: {:?}",
-                        netuid,
-                        who,
-                        e
-                    );
-                    continue;
-                }
-            };
-
-            let decrypted_bytes: Vec<u8> = match tld::<TinyBLS381, AESGCMStreamCipherProvider>(
-                commit, sig,
-            ) {
-                Ok(d) => d,
-                Err(e) => {
-                    log::warn!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to error decrypting the commit: {:?}",
-                        netuid,
-                        who,
-                        e
-                    );
-                    continue;
-                }
-            };
-
-            // Decrypt the bytes into WeightsPayload
-            let mut reader = &decrypted_bytes[..];
-            let payload: WeightsTlockPayload = match Decode::decode(&mut reader) {
-                Ok(w) => w,
-                Err(e) => {
-                    log::warn!(
-                        "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing WeightsPayload: {:?}",
-                        netuid,
-                        who,
-                        e
-                    );
-                    continue;
-                }
-            };
-
-            if let Err(e) = Self::do_set_weights(
-                T::RuntimeOrigin::signed(who.clone()),
+            match Self::reveal_crv3_commit(
                 netuid,
-                payload.uids,
-                payload.values,
-                payload.version_key,
+                &who,
+                &serialized_compresssed_commit,
+                round_number,
             ) {
+                Ok(()) => Self::deposit_event(Event::CRV3WeightsRevealed(netuid, who)),
+                Err(reason) => Self::deposit_event(Event::CRV3WeightsRevealFailed {
+                    netuid,
+                    hotkey: who,
+                    reason,
+                }),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decrypts a timelocked commit with its drand pulse and sets the weights it holds.
+    fn reveal_crv3_commit(
+        netuid: NetUid,
+        who: &T::AccountId,
+        serialized_compresssed_commit: &[u8],
+        round_number: RoundNumber,
+    ) -> Result<(), RevealFailureReason> {
+        let reader = &mut &serialized_compresssed_commit[..];
+        let commit = TLECiphertext::<TinyBLS381>::deserialize_compressed(reader).map_err(|e| {
+            log::warn!(
+                "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing the commit: {:?}",
+                netuid,
+                who,
+                e
+            );
+            RevealFailureReason::CommitDeserialization
+        })?;
+
+        // Try to get the round number from pallet_drand.
+        let pulse = pallet_drand::Pulses::<T>::get(round_number).ok_or_else(|| {
+            // Round number used was not found on the chain. Skip this commit.
+            log::warn!(
+                "Failed to reveal commit for subnet {} submitted by {:?} due to missing round number {} at time of reveal.",
+                netuid,
+                who,
+                round_number
+            );
+            RevealFailureReason::MissingPulse {
+                round: round_number,
+            }
+        })?;
+
+        let signature_bytes = pulse
+            .signature
+            .strip_prefix(b"0x")
+            .unwrap_or(&pulse.signature);
+
+        let sig_reader = &mut &signature_bytes[..];
+        let sig = <TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(sig_reader)
+            .map_err(|e| {
+                log::error!(
+                    "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing signature from drand pallet: {:?}",
+                    netuid,
+                    who,
+                    e
+                );
+                RevealFailureReason::SignatureDeserialization
+            })?;
+
+        let decrypted_bytes: Vec<u8> = tld::<TinyBLS381, AESGCMStreamCipherProvider>(commit, sig)
+            .map_err(|e| {
                 log::warn!(
-                    "Failed to `do_set_weights` for subnet {} submitted by {:?}: {:?}",
+                    "Failed to reveal commit for subnet {} submitted by {:?} due to error decrypting the commit: {:?}",
                     netuid,
                     who,
                     e
                 );
-                continue;
-            } else {
-                Self::deposit_event(Event::CRV3WeightsRevealed(netuid, who));
-            };
-        }
+                RevealFailureReason::Decryption
+            })?;
 
-        Ok(())
+        // Decrypt the bytes into WeightsPayload
+        let mut reader = &decrypted_bytes[..];
+        let payload: WeightsTlockPayload = Decode::decode(&mut reader).map_err(|e| {
+            log::warn!(
+                "Failed to reveal commit for subnet {} submitted by {:?} due to error deserializing WeightsPayload: {:?}",
+                netuid,
+                who,
+                e
+            );
+            RevealFailureReason::PayloadDeserialization
+        })?;
+
+        Self::do_set_weights(
+            T::RuntimeOrigin::signed(who.clone()),
+            netuid,
+            payload.uids,
+            payload.values,
+            payload.version_key,
+        )
+        .map_err(|e| {
+            log::warn!(
+                "Failed to `do_set_weights` for subnet {} submitted by {:?}: {:?}",
+                netuid,
+                who,
+                e
+            );
+            if e == Error::<T>::IncorrectWeightVersionKey.into() {
+                RevealFailureReason::VersionKeyMismatch
+            } else {
+                RevealFailureReason::InvalidWeights(e)
+            }
+        })
     }
 }
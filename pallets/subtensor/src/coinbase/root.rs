@@ -16,6 +16,7 @@
 // DEALINGS IN THE SOFTWARE.
 
 use super::*;
+use crate::utils::identity_attestation::IdentityAttestationTarget;
 use frame_support::dispatch::Pays;
 use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::weights::Weight;
@@ -449,6 +450,9 @@ impl<T: Config> Pallet<T> {
         let keys = Keys::<T>::iter_prefix(netuid).collect::<Vec<_>>();
         let _ = Keys::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Bonds::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = ValidatorWeightMetricsHistory::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = AlphaTokenHotkey::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = AlphaTokenAllowance::<T>::clear_prefix((netuid,), u32::MAX, None);
 
         // --- 8. Removes the weights for this subnet (do not remove).
         let _ = Weights::<T>::clear_prefix(netuid, u32::MAX, None);
@@ -511,6 +515,17 @@ impl<T: Config> Pallet<T> {
             SubnetIdentitiesV3::<T>::remove(netuid);
             Self::deposit_event(Event::SubnetIdentityRemoved(netuid));
         }
+        IdentityAttestations::<T>::remove(IdentityAttestationTarget::Subnet(netuid));
+
+        // --- 14. Remove the roles delegated by the subnet owner.
+        let _ = SubnetRoles::<T>::clear_prefix(netuid, u32::MAX, None);
+
+        // --- 14a. Remove the weights keys authorized by the hotkeys.
+        let _ = WeightsKeys::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightsKeyHotkey::<T>::clear_prefix(netuid, u32::MAX, None);
+
+        // --- 15. Drop the pending hyperparameter changes.
+        Self::clear_scheduled_hyperparameter_changes(netuid);
     }
 
     #[allow(clippy::arithmetic_side_effects)]
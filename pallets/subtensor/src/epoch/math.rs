@@ -378,6 +378,45 @@ pub fn row_sum_sparse(sparse_matrix: &[Vec<(u16, I32F32)>]) -> Vec<I32F32> {
         .collect()
 }
 
+// Cosine similarity of each row of a sparse matrix to a vector, as a u16 proportion.
+// Rows without mass, or a vector without mass, have a similarity of 0.
+#[allow(dead_code)]
+pub fn row_cosine_similarity_sparse(
+    sparse_matrix: &[Vec<(u16, I32F32)>],
+    vector: &[I32F32],
+) -> Vec<u16> {
+    // Keep 24 fractional bits so that the squared norms of proportions fit in u128.
+    let to_int = |x: &I32F32| -> u128 {
+        u128::try_from(x.to_bits())
+            .unwrap_or_default()
+            .checked_shr(8)
+            .unwrap_or_default()
+    };
+    let vector: Vec<u128> = vector.iter().map(to_int).collect();
+    let vector_norm: u128 = vector
+        .iter()
+        .fold(0, |acc, x| acc.saturating_add(x.saturating_mul(*x)));
+
+    sparse_matrix
+        .iter()
+        .map(|row| {
+            let (dot, row_norm) = row.iter().fold((0u128, 0u128), |(dot, norm), (j, value)| {
+                let value = to_int(value);
+                let other = vector.get(*j as usize).copied().unwrap_or_default();
+                (
+                    dot.saturating_add(value.saturating_mul(other)),
+                    norm.saturating_add(value.saturating_mul(value)),
+                )
+            });
+            let denominator = row_norm.saturating_mul(vector_norm).isqrt();
+            dot.saturating_mul(u16::MAX as u128)
+                .checked_div(denominator)
+                .map(|similarity| similarity.min(u16::MAX as u128) as u16)
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
 // Sum across each column (dim=1) of a matrix.
 #[allow(dead_code)]
 pub fn col_sum(x: &[Vec<I32F32>]) -> Vec<I32F32> {
@@ -1,3 +1,4 @@
 use super::*;
 pub mod math;
 pub mod run_epoch;
+pub mod weight_metrics;
@@ -1,5 +1,6 @@
 use super::*;
 use crate::epoch::math::*;
+use crate::subnets::roles::Hyperparameter;
 use frame_support::IterableStorageDoubleMap;
 use safe_math::*;
 use sp_std::vec;
@@ -771,6 +772,17 @@ impl<T: Config> Pallet<T> {
             inplace_col_max_upscale_sparse(&mut ema_bonds, n);
         }
 
+        // Track how each validator sets weights, to spot stale validators and weight copiers.
+        Self::record_validator_weight_metrics(
+            netuid,
+            &validator_permits,
+            &weights,
+            &consensus,
+            &validator_trust,
+            &ema_bonds,
+            &last_update,
+        );
+
         // =================================
         // == Emission and Pruning scores ==
         // =================================
@@ -1383,27 +1395,26 @@ impl<T: Config> Pallet<T> {
         // --- 1. Ensure the function caller is a signed user.
         ensure_signed(origin.clone())?;
 
-        // --- 2. Ensure the function caller is the subnet owner or root.
-        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        // --- 2. Ensure the function caller is the subnet owner, root or holds a role covering alpha.
+        Self::ensure_subnet_owner_or_root_or_role(
+            origin.clone(),
+            netuid,
+            Hyperparameter::AlphaLow,
+            alpha_low.into(),
+        )?;
+        Self::ensure_subnet_owner_or_root_or_role(
+            origin,
+            netuid,
+            Hyperparameter::AlphaHigh,
+            alpha_high.into(),
+        )?;
 
-        // --- 3. Ensure liquid alpha is enabled
-        ensure!(
+        // --- 3. Ensure liquid alpha is enabled and the values are within range
+        Self::ensure_valid_alpha_values(
             Self::get_liquid_alpha_enabled(netuid),
-            Error::<T>::LiquidAlphaDisabled
-        );
-
-        let max_u16: u32 = u16::MAX as u32; // 65535
-        let min_alpha_low: u16 = (max_u16.safe_div(40)) as u16; // 1638
-        let min_alpha_high: u16 = min_alpha_low;
-
-        // --- 4. Ensure alpha high is greater than the minimum
-        ensure!(alpha_high >= min_alpha_high, Error::<T>::AlphaHighTooLow);
-
-        // -- 5. Ensure alpha low is within range
-        ensure!(
-            alpha_low >= min_alpha_low && alpha_low <= alpha_high,
-            Error::<T>::AlphaLowOutOfRange
-        );
+            alpha_low,
+            alpha_high,
+        )?;
 
         AlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
 
@@ -1416,6 +1427,26 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Checks that liquid alpha is enabled and that `alpha_low` and `alpha_high` are a valid pair.
+    pub(crate) fn ensure_valid_alpha_values(
+        liquid_alpha_enabled: bool,
+        alpha_low: u16,
+        alpha_high: u16,
+    ) -> Result<(), DispatchError> {
+        ensure!(liquid_alpha_enabled, Error::<T>::LiquidAlphaDisabled);
+
+        // Ensure alpha high is greater than the minimum
+        ensure!(alpha_high >= MIN_LIQUID_ALPHA, Error::<T>::AlphaHighTooLow);
+
+        // Ensure alpha low is within range
+        ensure!(
+            alpha_low >= MIN_LIQUID_ALPHA && alpha_low <= alpha_high,
+            Error::<T>::AlphaLowOutOfRange
+        );
+
+        Ok(())
+    }
+
     pub fn do_reset_bonds(netuid: NetUid, account_id: &T::AccountId) -> Result<(), DispatchError> {
         // check bonds reset enabled for this subnet
         let bonds_reset_enabled: bool = Self::get_bonds_reset(netuid);
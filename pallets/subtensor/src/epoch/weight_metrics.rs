@@ -0,0 +1,75 @@
+use super::*;
+use crate::epoch::math::*;
+use safe_math::*;
+use substrate_fixed::types::I32F32;
+use subtensor_runtime_common::NetUid;
+
+/// Number of epochs of weight metrics kept per validator.
+pub const VALIDATOR_WEIGHT_METRICS_HISTORY: usize = 16;
+
+impl<T: Config> Pallet<T> {
+    /// Records the weight-setting metrics of this epoch for every validator that held a permit
+    /// going into it, dropping entries older than [`VALIDATOR_WEIGHT_METRICS_HISTORY`] epochs.
+    ///
+    /// `weights` are the masked, row-normalized weights the epoch ran on, `validator_trust` the
+    /// row sums of those weights after clipping at `consensus`, and `bonds` the updated bonds.
+    pub(crate) fn record_validator_weight_metrics(
+        netuid: NetUid,
+        validator_permits: &[bool],
+        weights: &[Vec<(u16, I32F32)>],
+        consensus: &[I32F32],
+        validator_trust: &[I32F32],
+        bonds: &[Vec<(u16, I32F32)>],
+        last_update: &[u64],
+    ) {
+        let current_block = Self::get_current_block_as_u64();
+        let consensus_similarity = row_cosine_similarity_sparse(weights, consensus);
+        let weight_mass = row_sum_sparse(weights);
+        let mut bond_share = row_sum_sparse(bonds);
+        inplace_normalize(&mut bond_share);
+
+        for (uid, permit) in validator_permits.iter().enumerate() {
+            if !permit {
+                continue;
+            }
+            let Ok(uid_u16) = u16::try_from(uid) else {
+                break;
+            };
+
+            let mass = weight_mass.get(uid).copied().unwrap_or_default();
+            let kept = validator_trust.get(uid).copied().unwrap_or_default();
+            let clipped_share = mass.saturating_sub(kept).safe_div(mass);
+            let bond_share =
+                fixed_proportion_to_u16(bond_share.get(uid).copied().unwrap_or_default());
+
+            ValidatorWeightMetricsHistory::<T>::mutate(netuid, uid_u16, |history| {
+                let previous_bond_share = history
+                    .back()
+                    .map(|metrics| metrics.bond_share)
+                    .unwrap_or_default();
+                history.push_back(ValidatorWeightMetrics {
+                    block: current_block,
+                    consensus_similarity: consensus_similarity
+                        .get(uid)
+                        .copied()
+                        .unwrap_or_default(),
+                    clipped_share: fixed_proportion_to_u16(clipped_share),
+                    bond_share,
+                    bonds_growth: i32::from(bond_share)
+                        .saturating_sub(i32::from(previous_bond_share)),
+                    blocks_since_update: current_block
+                        .saturating_sub(last_update.get(uid).copied().unwrap_or_default()),
+                });
+                while history.len() > VALIDATOR_WEIGHT_METRICS_HISTORY {
+                    history.pop_front();
+                }
+            });
+        }
+    }
+
+    /// Returns the weight-setting metrics of a uid over its last epochs as a validator, oldest
+    /// first.
+    pub fn get_validator_weight_metrics(netuid: NetUid, uid: u16) -> Vec<ValidatorWeightMetrics> {
+        ValidatorWeightMetricsHistory::<T>::get(netuid, uid).into()
+    }
+}
@@ -62,6 +62,18 @@ extern crate alloc;
 
 pub const MAX_CRV3_COMMIT_SIZE_BYTES: u32 = 5000;
 
+/// Maximum number of endpoints a neuron can serve through `serve_axons`.
+pub const MAX_AXON_ENDPOINTS: u32 = 8;
+
+/// The maximum bonds moving average a subnet owner can set.
+pub const MAX_OWNER_BONDS_MOVING_AVERAGE: u64 = 975_000;
+
+/// The maximum commit-reveal period, in epochs.
+pub const MAX_COMMIT_REVEAL_PERIODS: u64 = 100;
+
+/// The minimum liquid alpha low and high values (`u16::MAX / 40`).
+pub const MIN_LIQUID_ALPHA: u16 = 1638;
+
 #[deny(missing_docs)]
 #[import_section(errors::errors)]
 #[import_section(events::events)]
@@ -72,8 +84,15 @@ pub const MAX_CRV3_COMMIT_SIZE_BYTES: u32 = 5000;
 #[frame_support::pallet]
 pub mod pallet {
     use crate::RateLimitKey;
+    use crate::coinbase::reveal_commits::RevealFailureReason;
     use crate::migrations;
+    use crate::subnets::hyperparameter_schedule::ScheduledHyperparameterChanges;
     use crate::subnets::leasing::{LeaseId, SubnetLeaseOf};
+    use crate::subnets::roles::{Hyperparameter, SubnetRole};
+    use crate::utils::identity_attestation::{
+        IdentityAttestationTarget, IdentityAttestationsOf, IdentityField,
+    };
+    use crate::utils::snapshot::SubtensorSnapshot;
     use frame_support::Twox64Concat;
     use frame_support::{
         BoundedVec,
@@ -149,6 +168,43 @@ pub mod pallet {
         pub placeholder2: u8,
     }
 
+    /// Struct for AxonEndpoint.
+    pub type AxonEndpointOf = AxonEndpoint;
+
+    /// Data structure for a single endpoint of a neuron serving several addresses.
+    #[crate::freeze_struct("eb6829d425494c82")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct AxonEndpoint {
+        ///  Endpoint protocol. TCP, UDP, other.
+        pub protocol: u8,
+        ///  Endpoint u128 encoded ip address of type v6 or v4.
+        pub ip: u128,
+        ///  Endpoint ip type, 4 for ipv4 and 6 for ipv6.
+        pub ip_type: u8,
+        ///  Endpoint u16 encoded port.
+        pub port: u16,
+        ///  Endpoint priority, lower values are preferred.
+        pub priority: u8,
+    }
+
+    /// Data structure for the weight-setting quality of a validator over one epoch.
+    #[crate::freeze_struct("621ace8b1631f1b7")]
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct ValidatorWeightMetrics {
+        ///  Block of the epoch.
+        pub block: u64,
+        ///  Cosine similarity of the validator weights to consensus, as a u16 proportion.
+        pub consensus_similarity: u16,
+        ///  Share of the validator weight mass clipped at consensus, as a u16 proportion.
+        pub clipped_share: u16,
+        ///  Share of the subnet bonds held by the validator, as a u16 proportion.
+        pub bond_share: u16,
+        ///  Change of `bond_share` since the previous epoch.
+        pub bonds_growth: i32,
+        ///  Blocks between the last weights update of the validator and the epoch.
+        pub blocks_since_update: u64,
+    }
+
     /// Struct for NeuronCertificate.
     pub type NeuronCertificateOf = NeuronCertificate;
     /// Data structure for NeuronCertificate information.
@@ -223,8 +279,19 @@ pub mod pallet {
     pub type ChainIdentityOfV2 = ChainIdentityV2;
 
     /// Data structure for Chain Identities.
-    #[crate::freeze_struct("ad72a270be7b59d7")]
-    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    #[crate::freeze_struct("1227dcbadeb3823c")]
+    #[derive(
+        Encode,
+        Decode,
+        Default,
+        TypeInfo,
+        Clone,
+        PartialEq,
+        Eq,
+        Debug,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
     pub struct ChainIdentityV2 {
         /// The name of the chain identity
         pub name: Vec<u8>,
@@ -281,8 +348,19 @@ pub mod pallet {
     ///  Struct for SubnetIdentitiesV3.
     pub type SubnetIdentityOfV3 = SubnetIdentityV3;
     /// Data structure for Subnet Identities
-    #[crate::freeze_struct("3618af6beb882a23")]
-    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    #[crate::freeze_struct("cfc182ebc328803e")]
+    #[derive(
+        Encode,
+        Decode,
+        Default,
+        TypeInfo,
+        Clone,
+        PartialEq,
+        Eq,
+        Debug,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
     pub struct SubnetIdentityV3 {
         /// The name of the subnet
         pub subnet_name: Vec<u8>,
@@ -1563,6 +1641,17 @@ pub mod pallet {
         AxonInfoOf,
         OptionQuery,
     >;
+    #[pallet::storage]
+    /// --- MAP ( netuid, hotkey ) --> axon_endpoints
+    pub type AxonEndpoints<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<AxonEndpointOf, ConstU32<MAX_AXON_ENDPOINTS>>,
+        OptionQuery,
+    >;
     /// --- MAP ( netuid, hotkey ) --> certificate
     #[pallet::storage]
     pub type NeuronCertificates<T: Config> = StorageDoubleMap<
@@ -1605,6 +1694,19 @@ pub mod pallet {
     pub type SubnetIdentitiesV3<T: Config> =
         StorageMap<_, Blake2_128Concat, NetUid, SubnetIdentityOfV3, OptionQuery>;
 
+    #[pallet::storage] // --- MAP ( attester ) --> is_attester | Accounts appointed by governance to attest identities.
+    pub type IdentityAttesters<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    #[pallet::storage] // --- MAP ( target ) --> attestations | Attestations of a coldkey or subnet identity.
+    pub type IdentityAttestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        IdentityAttestationTarget<T::AccountId>,
+        IdentityAttestationsOf<T>,
+        ValueQuery,
+    >;
+
     /// =================================
     /// ==== Axon / Promo Endpoints =====
     /// =================================
@@ -1679,6 +1781,39 @@ pub mod pallet {
         ValueQuery,
     >;
     #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> weights_key | The key a hotkey authorized to set, commit and reveal weights on its behalf.
+    pub type WeightsKeys<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, weights_key ) --> hotkey | The hotkey a weights key acts for.
+    pub type WeightsKeyHotkey<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> metrics | The weight-setting metrics of a validator over its last epochs, oldest first.
+    pub type ValidatorWeightMetricsHistory<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Identity,
+        u16,
+        VecDeque<ValidatorWeightMetrics>,
+        ValueQuery,
+    >;
+    #[pallet::storage]
     /// --- Map (netuid) --> Number of epochs allowed for commit reveal periods
     pub type RevealPeriodEpochs<T: Config> =
         StorageMap<_, Twox64Concat, NetUid, u64, ValueQuery, DefaultRevealPeriodEpochs<T>>;
@@ -1709,6 +1844,30 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    /// --- DMAP ( netuid, coldkey ) --> hotkey | The hotkey whose stake backs the coldkey's alpha token balance.
+    pub type AlphaTokenHotkey<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- NMAP ( netuid, owner, spender ) --> alpha | The alpha tokens a spender may transfer from the owner.
+    pub type AlphaTokenAllowance<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, NetUid>,               // subnet
+            NMapKey<Blake2_128Concat, T::AccountId>, // owner
+            NMapKey<Blake2_128Concat, T::AccountId>, // spender
+        ),
+        AlphaCurrency,
+        ValueQuery,
+    >;
+
     /// =============================
     /// ==== EVM related storage ====
     /// =============================
@@ -1744,6 +1903,44 @@ pub mod pallet {
     pub type AccumulatedLeaseDividends<T: Config> =
         StorageMap<_, Twox64Concat, LeaseId, AlphaCurrency, ValueQuery, DefaultZeroAlpha<T>>;
 
+    /// ======================
+    /// ==== Subnet Roles ====
+    /// ======================
+    #[pallet::storage]
+    /// --- DMAP ( netuid, delegate ) --> role | The hyperparameter rights granted to a delegate by the subnet owner.
+    pub type SubnetRoles<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        SubnetRole,
+        OptionQuery,
+    >;
+
+    /// ========================================
+    /// ==== Scheduled Hyperparameter Changes ====
+    /// ========================================
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> pending changes | The hyperparameter changes queued on a subnet.
+    pub type PendingHyperparameterChanges<T: Config> =
+        StorageMap<_, Identity, NetUid, ScheduledHyperparameterChanges, ValueQuery>;
+
+    #[pallet::storage]
+    /// --- DMAP ( block, netuid ) --> due | The subnets with hyperparameter changes taking effect at a block.
+    pub type HyperparameterChangesDue<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u64, Identity, NetUid, bool, ValueQuery>;
+
+    #[pallet::storage]
+    /// --- MAP ( hyperparameter ) --> blocks | The minimum notice required to change a hyperparameter, set by root.
+    pub type HyperparameterMinNotice<T: Config> =
+        StorageMap<_, Twox64Concat, Hyperparameter, u64, ValueQuery>;
+
+    #[pallet::storage]
+    /// --- MAP ( hyperparameter ) --> (min, max) | The inclusive range subnet owners may set for a hyperparameter, set by root.
+    pub type HyperparameterBounds<T: Config> =
+        StorageMap<_, Twox64Concat, Hyperparameter, (u64, u64), OptionQuery>;
+
     /// ==================
     /// ==== Genesis =====
     /// ==================
@@ -1761,19 +1958,59 @@ pub mod pallet {
     pub type PendingChildKeyCooldown<T: Config> =
         StorageValue<_, u64, ValueQuery, DefaultPendingChildKeyCooldown<T>>;
 
+    #[pallet::type_value]
+    /// Default number of blocks a subnet waits after registration before it can be started.
+    pub fn DefaultStartCallDelay<T: Config>() -> u64 {
+        T::DurationOfStartCall::get()
+    }
+
+    #[pallet::storage]
+    /// Number of blocks a subnet waits after registration before it can be started.
+    pub type StartCallDelay<T: Config> = StorageValue<_, u64, ValueQuery, DefaultStartCallDelay<T>>;
+
+    #[pallet::type_value]
+    /// Default rate limit for setting children.
+    pub fn DefaultSetChildrenRateLimit<T: Config>() -> u64 {
+        150 // 30 minutes
+    }
+
+    #[pallet::storage]
+    /// Rate limit for setting children, in blocks.
+    pub type SetChildrenRateLimit<T: Config> =
+        StorageValue<_, u64, ValueQuery, DefaultSetChildrenRateLimit<T>>;
+
+    #[pallet::storage]
+    /// Tempo given to newly registered subnets.
+    pub type InitialSubnetTempo<T: Config> = StorageValue<_, u16, ValueQuery, DefaultTempo<T>>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
-        /// Stakes record in genesis.
-        pub stakes: Vec<(T::AccountId, Vec<(T::AccountId, (u64, u16))>)>,
+        /// Chain state to rebuild instead of the default subnet.
+        pub snapshot: Option<SubtensorSnapshot<T::AccountId>>,
         /// The total issued balance in genesis
         pub balances_issuance: u64,
+        /// Blocks a subnet waits after registration before it can be started.
+        pub start_call_delay: Option<u64>,
+        /// Blocks before pending childkeys are applied.
+        pub pending_childkey_cooldown: Option<u64>,
+        /// Rate limit for setting children, in blocks.
+        pub set_children_rate_limit: Option<u64>,
+        /// Rate limit for childkey take changes, in blocks.
+        pub childkey_take_rate_limit: Option<u64>,
+        /// Tempo given to newly registered subnets.
+        pub initial_subnet_tempo: Option<u16>,
     }
 
     impl<T: Config> Default for GenesisConfig<T> {
         fn default() -> Self {
             Self {
-                stakes: Default::default(),
+                snapshot: None,
                 balances_issuance: 0,
+                start_call_delay: None,
+                pending_childkey_cooldown: None,
+                set_children_rate_limit: None,
+                childkey_take_rate_limit: None,
+                initial_subnet_tempo: None,
             }
         }
     }
@@ -2035,8 +2272,9 @@ where
 
         match call.is_sub_type() {
             Some(Call::commit_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+                let hotkey = Pallet::<T>::get_weights_signer_hotkey(*netuid, who);
+                if Self::check_weights_min_stake(&hotkey, *netuid) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     let validity = ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2047,9 +2285,11 @@ where
                     Err(CustomTransactionError::StakeAmountTooLow.into())
                 }
             }
-            Some(Call::reveal_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+            Some(Call::reveal_weights { netuid, .. })
+            | Some(Call::reveal_weights_delta { netuid, .. }) => {
+                let hotkey = Pallet::<T>::get_weights_signer_hotkey(*netuid, who);
+                if Self::check_weights_min_stake(&hotkey, *netuid) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     let validity = ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2061,8 +2301,9 @@ where
                 }
             }
             Some(Call::batch_reveal_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+                let hotkey = Pallet::<T>::get_weights_signer_hotkey(*netuid, who);
+                if Self::check_weights_min_stake(&hotkey, *netuid) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     let validity = ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2073,9 +2314,11 @@ where
                     Err(CustomTransactionError::StakeAmountTooLow.into())
                 }
             }
-            Some(Call::set_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Self::get_priority_set_weights(who, *netuid);
+            Some(Call::set_weights { netuid, .. })
+            | Some(Call::set_weights_delta { netuid, .. }) => {
+                let hotkey = Pallet::<T>::get_weights_signer_hotkey(*netuid, who);
+                if Self::check_weights_min_stake(&hotkey, *netuid) {
+                    let priority: u64 = Self::get_priority_set_weights(&hotkey, *netuid);
                     let validity = ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2100,8 +2343,9 @@ where
                 }
             }
             Some(Call::commit_crv3_weights { netuid, .. }) => {
-                if Self::check_weights_min_stake(who, *netuid) {
-                    let priority: u64 = Pallet::<T>::get_priority_set_weights(who, *netuid);
+                let hotkey = Pallet::<T>::get_weights_signer_hotkey(*netuid, who);
+                if Self::check_weights_min_stake(&hotkey, *netuid) {
+                    let priority: u64 = Pallet::<T>::get_priority_set_weights(&hotkey, *netuid);
                     let validity = ValidTransaction {
                         priority,
                         longevity: 1,
@@ -2418,6 +2662,16 @@ where
                 )
                 .map(|validity| (validity, Some(who.clone()), origin.clone()))
             }
+            Some(Call::serve_axons {
+                netuid, endpoints, ..
+            }) => {
+                // Fully validate the user input
+                Self::result_to_validity(
+                    Pallet::<T>::validate_serve_axons(who, *netuid, endpoints),
+                    Self::get_priority_vanilla(),
+                )
+                .map(|validity| (validity, Some(who.clone()), origin.clone()))
+            }
             _ => {
                 if let Some(
                     BalancesCall::transfer_keep_alive { .. }
@@ -2458,9 +2712,12 @@ where
             Some(Call::set_weights { .. }) => Ok(Some(CallType::SetWeights)),
             Some(Call::commit_weights { .. }) => Ok(Some(CallType::SetWeights)),
             Some(Call::reveal_weights { .. }) => Ok(Some(CallType::SetWeights)),
+            Some(Call::set_weights_delta { .. }) => Ok(Some(CallType::SetWeights)),
+            Some(Call::reveal_weights_delta { .. }) => Ok(Some(CallType::SetWeights)),
             Some(Call::register { .. }) => Ok(Some(CallType::Register)),
             Some(Call::serve_axon { .. }) => Ok(Some(CallType::Serve)),
             Some(Call::serve_axon_tls { .. }) => Ok(Some(CallType::Serve)),
+            Some(Call::serve_axons { .. }) => Ok(Some(CallType::Serve)),
             Some(Call::register_network { .. }) => Ok(Some(CallType::RegisterNetwork)),
             _ => Ok(Some(CallType::Other)),
         }
@@ -6,6 +6,8 @@ use frame_support::pallet_macros::pallet_section;
 #[pallet_section]
 mod dispatches {
     use crate::subnets::leasing::SubnetLeasingWeightInfo;
+    use crate::subnets::roles::{Hyperparameter, HyperparameterBound, HyperparameterGroup};
+    use crate::utils::identity_attestation::{IdentityAttestationTarget, IdentityField};
     use frame_support::traits::schedule::DispatchTime;
     use frame_support::traits::schedule::v3::Anon as ScheduleAnon;
     use frame_system::pallet_prelude::BlockNumberFor;
@@ -806,6 +808,65 @@ mod dispatches {
             )
         }
 
+        /// Serves a bounded list of axon endpoints for the neuron associated with the caller.
+        /// The preferred endpoint (lowest priority value) is also written to the single axon
+        /// view, so clients reading `Axons` keep working.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The u16 network identifier.
+        ///
+        /// * 'version' (u32):
+        /// 	- The bittensor version identifier.
+        ///
+        /// * 'endpoints' (Vec<AxonEndpoint>):
+        /// 	- The endpoints served by the neuron, each with protocol, ip, ip type, port and priority.
+        ///
+        /// # Event:
+        /// * AxonEndpointsServed;
+        /// 	- On successfully serving the axon endpoints.
+        ///
+        /// # Raises:
+        /// * 'NotRegistered':
+        /// 	- Attempting to serve from a non registered account.
+        ///
+        /// * 'NoAxonEndpoints':
+        /// 	- The endpoint list is empty.
+        ///
+        /// * 'TooManyAxonEndpoints':
+        /// 	- The endpoint list is longer than `MAX_AXON_ENDPOINTS`.
+        ///
+        /// * 'DuplicateAxonEndpoint':
+        /// 	- The same protocol, ip and port is listed twice.
+        ///
+        /// * 'InvalidIpType':
+        /// 	- An ip type is not 4 or 6.
+        ///
+        /// * 'InvalidIpAddress':
+        /// 	- A numerically encoded ip address does not resolve to a proper ip.
+        ///
+        /// * 'InvalidPort':
+        /// 	- A port is zero.
+        ///
+        /// * 'ServingRateLimitExceeded':
+        /// 	- Attempting to serve axon information within the rate limit min.
+        ///
+        #[pallet::call_index(113)]
+        #[pallet::weight((Weight::from_parts(38_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn serve_axons(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            version: u32,
+            endpoints: Vec<AxonEndpointOf>,
+        ) -> DispatchResult {
+            Self::do_serve_axons(origin, netuid, version, endpoints)
+        }
+
         /// ---- Set prometheus information for the neuron.
         /// # Args:
         /// * 'origin': (<T as frame_system::Config>Origin):
@@ -2175,5 +2236,502 @@ mod dispatches {
             Self::deposit_event(Event::SymbolUpdated { netuid, symbol });
             Ok(())
         }
+
+        /// Grants a delegate the right to change a set of hyperparameter groups on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `delegate` - The account receiving the role.
+        /// * `groups` - The hyperparameter groups the delegate may change.
+        /// * `bounds` - Optional inclusive bounds on the values the delegate may set.
+        /// * `expires_at` - The block at which the role expires, if any.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The transaction is not signed by the subnet owner.
+        /// * The role has no groups, too many entries, inverted bounds or targets the owner.
+        /// * The expiry block is not in the future.
+        ///
+        /// # Events
+        /// Emits a `SubnetRoleGranted` event on success.
+        #[pallet::call_index(114)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn grant_subnet_role(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            delegate: T::AccountId,
+            groups: Vec<HyperparameterGroup>,
+            bounds: Vec<HyperparameterBound>,
+            expires_at: Option<u64>,
+        ) -> DispatchResult {
+            Self::do_grant_subnet_role(origin, netuid, delegate, groups, bounds, expires_at)
+        }
+
+        /// Revokes the hyperparameter rights of a delegate on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or root.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `delegate` - The account whose role is revoked.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The transaction is not signed by the subnet owner or root.
+        /// * The delegate holds no role on the subnet.
+        ///
+        /// # Events
+        /// Emits a `SubnetRoleRevoked` event on success.
+        #[pallet::call_index(115)]
+        #[pallet::weight((
+            Weight::from_parts(12_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn revoke_subnet_role(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            delegate: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_revoke_subnet_role(origin, netuid, delegate)
+        }
+
+        /// Queues a hyperparameter change on a subnet, applied automatically at `effective_block`.
+        ///
+        /// The change is checked again when it takes effect and dropped with a
+        /// `HyperparameterChangeFailed` event if it no longer passes. Changes queued by the owner
+        /// or its delegates are cancelled if the subnet changes hands.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner, root or a
+        ///   delegate holding a role covering the hyperparameter.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `param` - The hyperparameter to change.
+        /// * `value` - The new value of the hyperparameter. Signed hyperparameters are passed as
+        ///   the two's complement of the value.
+        /// * `effective_block` - The block at which the change takes effect.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The subnet does not exist.
+        /// * The origin has no right to change the hyperparameter.
+        /// * The value is not valid for the hyperparameter, or conflicts with the liquid alpha
+        ///   settings the subnet will have at the effective block.
+        /// * The effective block does not respect the minimum notice for the hyperparameter.
+        /// * Too many changes are already pending on the subnet.
+        ///
+        /// # Events
+        /// Emits a `HyperparameterChangeScheduled` event on success.
+        #[pallet::call_index(116)]
+        #[pallet::weight((
+            Weight::from_parts(20_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(4, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn schedule_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            param: Hyperparameter,
+            value: u64,
+            effective_block: u64,
+        ) -> DispatchResult {
+            Self::do_schedule_hyperparameter_change(origin, netuid, param, value, effective_block)
+        }
+
+        /// Cancels a pending hyperparameter change on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the subnet owner or root.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `param` - The hyperparameter whose pending change is cancelled.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The transaction is not signed by the subnet owner or root.
+        /// * No change is pending for the hyperparameter.
+        ///
+        /// # Events
+        /// Emits a `HyperparameterChangeCancelled` event on success.
+        #[pallet::call_index(117)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn cancel_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            param: Hyperparameter,
+        ) -> DispatchResult {
+            Self::do_cancel_hyperparameter_change(origin, netuid, param)
+        }
+
+        /// Appoints an account as identity attester.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be root.
+        /// * `attester` - The account allowed to attest identities.
+        ///
+        /// # Events
+        /// Emits an `IdentityAttesterAdded` event on success.
+        #[pallet::call_index(118)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn add_identity_attester(
+            origin: OriginFor<T>,
+            attester: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_add_identity_attester(origin, attester)
+        }
+
+        /// Removes an identity attester. Its attestations are no longer returned by the runtime APIs.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be root.
+        /// * `attester` - The attester to remove.
+        ///
+        /// # Errors
+        /// * `NotIdentityAttester` - The account is not an attester.
+        ///
+        /// # Events
+        /// Emits an `IdentityAttesterRemoved` event on success.
+        #[pallet::call_index(119)]
+        #[pallet::weight((
+            Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
+        pub fn remove_identity_attester(
+            origin: OriginFor<T>,
+            attester: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_remove_identity_attester(origin, attester)
+        }
+
+        /// Attests that a field of a coldkey or subnet identity was verified.
+        ///
+        /// The attestation is bound to the current value of the field and is only returned
+        /// while the value is unchanged, the attester is appointed and `expires_at` is not reached.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be an identity attester.
+        /// * `target` - The coldkey or subnet whose identity is attested.
+        /// * `field` - The verified field.
+        /// * `expires_at` - The block at which the attestation expires.
+        ///
+        /// # Errors
+        /// * `NotIdentityAttester` - The caller is not an attester.
+        /// * `IdentityAttestationExpired` - The expiry is not in the future.
+        /// * `IdentityFieldNotSet` - The identity or the field is not set.
+        /// * `TooManyIdentityAttestations` - The identity has too many attestations.
+        ///
+        /// # Events
+        /// Emits an `IdentityAttested` event on success.
+        #[pallet::call_index(120)]
+        #[pallet::weight((
+            Weight::from_parts(25_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn attest_identity(
+            origin: OriginFor<T>,
+            target: IdentityAttestationTarget<T::AccountId>,
+            field: IdentityField,
+            expires_at: u64,
+        ) -> DispatchResult {
+            Self::do_attest_identity(origin, target, field, expires_at)
+        }
+
+        /// Revokes an identity attestation made by the caller.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the attester.
+        /// * `target` - The coldkey or subnet whose identity was attested.
+        /// * `field` - The field whose attestation is revoked.
+        ///
+        /// # Errors
+        /// * `IdentityAttestationNotFound` - The caller has no attestation for the field.
+        ///
+        /// # Events
+        /// Emits an `IdentityAttestationRevoked` event on success.
+        #[pallet::call_index(121)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn revoke_identity_attestation(
+            origin: OriginFor<T>,
+            target: IdentityAttestationTarget<T::AccountId>,
+            field: IdentityField,
+        ) -> DispatchResult {
+            Self::do_revoke_identity_attestation(origin, target, field)
+        }
+
+        /// Authorizes a weights key to set, commit and reveal weights on behalf of the calling
+        /// hotkey on a subnet, so the hotkey secret does not have to live on the validator.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the hotkey.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `weights_key` - The key allowed to submit weights, replacing any previous one.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The hotkey is not registered on the subnet.
+        /// * The weights key is itself registered on the subnet.
+        /// * The weights key already acts for another hotkey on the subnet.
+        ///
+        /// # Events
+        /// Emits a `WeightsKeySet` event on success.
+        #[pallet::call_index(122)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(4, 3)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn set_weights_key(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            weights_key: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_set_weights_key(origin, netuid, weights_key)
+        }
+
+        /// Revokes the weights key of the calling hotkey on a subnet.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the hotkey.
+        /// * `netuid` - The unique identifier of the subnet.
+        ///
+        /// # Errors
+        /// Returns an error if the hotkey has no weights key on the subnet.
+        ///
+        /// # Events
+        /// Emits a `WeightsKeyRemoved` event on success.
+        #[pallet::call_index(123)]
+        #[pallet::weight((
+            Weight::from_parts(12_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 2)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn remove_weights_key(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_remove_weights_key(origin, netuid)
+        }
+
+        /// --- Sets the caller weights as changes to the weights they last set, so that only the
+        /// changed (uid, weight) pairs are submitted. The merged weights go through the same
+        /// checks as `set_weights`.
+        ///
+        /// # Args:
+        /// * `origin`: (<T as frame_system::Config>Origin):
+        ///     - The caller, a hotkey or its weights key.
+        ///
+        /// * `netuid` (u16):
+        ///     - The network uid.
+        ///
+        /// * `dests` (Vec<u16>):
+        ///     - The uids whose weight changes.
+        ///
+        /// * `weights` (Vec<u16>):
+        ///     - The new weights of `dests` on the scale of the stored, max-normalized weights.
+        ///       A zero weight removes the uid.
+        ///
+        /// * `version_key` (u64):
+        ///     - The network version key.
+        ///
+        /// # Raises:
+        /// * `CommitRevealEnabled`:
+        ///     - Commit-reveal is enabled; commit the changes and use `reveal_weights_delta`.
+        ///
+        /// * `DuplicateUids`:
+        ///     - A uid is changed twice.
+        ///
+        /// * The errors of `set_weights` for the merged weights.
+        #[pallet::call_index(124)]
+        #[pallet::weight((Weight::from_parts(20_730_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(4112))
+        .saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn set_weights_delta(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            dests: Vec<u16>,
+            weights: Vec<u16>,
+            version_key: u64,
+        ) -> DispatchResult {
+            if Self::get_commit_reveal_weights_enabled(netuid) {
+                Err(Error::<T>::CommitRevealEnabled.into())
+            } else {
+                Self::do_set_weights_delta(origin, netuid, dests, weights, version_key)
+            }
+        }
+
+        /// ---- Reveals weight changes committed with `commit_weights`, the commit hash being the
+        /// hash of `(b"delta", hotkey, netuid, uids, values, salt, version_key)`. The changes are
+        /// merged into the weights stored at reveal time, see `set_weights_delta`.
+        ///
+        /// # Args:
+        /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+        ///   - The signature of the revealing hotkey or of its weights key.
+        ///
+        /// * `netuid` (`u16`):
+        ///   - The u16 network identifier.
+        ///
+        /// * `uids` (`Vec<u16>`):
+        ///   - The uids whose weight changes.
+        ///
+        /// * `values` (`Vec<u16>`):
+        ///   - The new weights of `uids`, a zero weight removing the uid.
+        ///
+        /// * `salt` (`Vec<u16>`):
+        ///   - The salt used to generate the commit hash.
+        ///
+        /// * `version_key` (`u64`):
+        ///   - The network version key.
+        ///
+        /// # Raises:
+        /// * The errors of `reveal_weights` and `set_weights_delta`.
+        #[pallet::call_index(125)]
+        #[pallet::weight((Weight::from_parts(122_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(17))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn reveal_weights_delta(
+            origin: T::RuntimeOrigin,
+            netuid: NetUid,
+            uids: Vec<u16>,
+            values: Vec<u16>,
+            salt: Vec<u16>,
+            version_key: u64,
+        ) -> DispatchResult {
+            Self::do_reveal_weights_delta(origin, netuid, uids, values, salt, version_key)
+        }
+
+        /// Chooses the hotkey whose stake backs the caller's alpha token balance on a subnet.
+        /// Alpha token transfers move stake from and to the chosen hotkeys.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the coldkey holding the tokens.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `hotkey` - The hotkey backing the tokens, replacing any previous one.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The subnet does not exist.
+        /// * The hotkey does not exist.
+        ///
+        /// # Events
+        /// Emits an `AlphaTokenHotkeySet` event on success.
+        #[pallet::call_index(126)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn set_alpha_token_hotkey(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_set_alpha_token_hotkey(origin, netuid, hotkey)
+        }
+
+        /// Allows a spender to transfer up to `amount` of the caller's alpha tokens on a subnet
+        /// with `transfer_alpha_token_from`.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the coldkey holding the tokens.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `spender` - The account allowed to transfer the tokens.
+        /// * `amount` - The allowed amount, replacing any previous allowance.
+        ///
+        /// # Errors
+        /// Returns an error if the subnet does not exist.
+        ///
+        /// # Events
+        /// Emits an `AlphaTokenApproved` event on success.
+        #[pallet::call_index(127)]
+        #[pallet::weight((
+            Weight::from_parts(15_000_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn approve_alpha_token(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            spender: T::AccountId,
+            amount: AlphaCurrency,
+        ) -> DispatchResult {
+            Self::do_approve_alpha_token(origin, netuid, spender, amount)
+        }
+
+        /// Transfers alpha tokens of the caller to another coldkey on a subnet, moving the stake
+        /// from the caller's token hotkey to the destination's. A destination that has not chosen
+        /// a token hotkey gets the caller's.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the coldkey holding the tokens.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `destination_coldkey` - The coldkey receiving the tokens.
+        /// * `amount` - The amount of alpha to transfer.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The caller has not chosen a token hotkey on the subnet.
+        /// * The caller has insufficient stake on its token hotkey.
+        /// * Transfers are disabled on the subnet.
+        /// * The transfer amount is below the minimum stake requirement.
+        ///
+        /// # Events
+        /// Emits an `AlphaTokenTransferred` event on success.
+        #[pallet::call_index(128)]
+        #[pallet::weight((Weight::from_parts(154_800_000, 0)
+        .saturating_add(T::DbWeight::get().reads(15_u64))
+        .saturating_add(T::DbWeight::get().writes(7_u64)), DispatchClass::Normal, Pays::Yes))]
+        pub fn transfer_alpha_token(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            destination_coldkey: T::AccountId,
+            amount: AlphaCurrency,
+        ) -> DispatchResult {
+            Self::do_transfer_alpha_token(origin, netuid, destination_coldkey, amount)
+        }
+
+        /// Transfers alpha tokens of `owner` to another coldkey on a subnet, spending the
+        /// allowance `owner` gave the caller with `approve_alpha_token`.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call, which must be the spender.
+        /// * `netuid` - The unique identifier of the subnet.
+        /// * `owner` - The coldkey holding the tokens.
+        /// * `destination_coldkey` - The coldkey receiving the tokens.
+        /// * `amount` - The amount of alpha to transfer.
+        ///
+        /// # Errors
+        /// Returns an error if:
+        /// * The amount exceeds the caller's allowance.
+        /// * Any of the `transfer_alpha_token` checks fails for `owner`.
+        ///
+        /// # Events
+        /// Emits an `AlphaTokenTransferred` event on success.
+        #[pallet::call_index(129)]
+        #[pallet::weight((Weight::from_parts(160_000_000, 0)
+        .saturating_add(T::DbWeight::get().reads(16_u64))
+        .saturating_add(T::DbWeight::get().writes(8_u64)), DispatchClass::Normal, Pays::Yes))]
+        pub fn transfer_alpha_token_from(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            owner: T::AccountId,
+            destination_coldkey: T::AccountId,
+            amount: AlphaCurrency,
+        ) -> DispatchResult {
+            Self::do_transfer_alpha_token_from(origin, netuid, owner, destination_coldkey, amount)
+        }
     }
 }
@@ -242,5 +242,57 @@ mod errors {
         SymbolDoesNotExist,
         /// Symbol already in use.
         SymbolAlreadyInUse,
+        /// No axon endpoints were provided.
+        NoAxonEndpoints,
+        /// Too many axon endpoints were provided.
+        TooManyAxonEndpoints,
+        /// The same axon endpoint was provided more than once.
+        DuplicateAxonEndpoint,
+        /// The subnet role is malformed: no groups, too many entries, inverted bounds or granted to the owner.
+        InvalidSubnetRole,
+        /// The subnet role has expired.
+        SubnetRoleExpired,
+        /// The value is outside the bounds of the subnet role.
+        SubnetRoleBoundsExceeded,
+        /// No subnet role exists for the delegate.
+        SubnetRoleNotFound,
+        /// Root requires a minimum notice for this hyperparameter; schedule the change instead.
+        HyperparameterNoticeRequired,
+        /// The effective block does not respect the minimum notice for this hyperparameter.
+        HyperparameterNoticeTooShort,
+        /// The value is not valid for this hyperparameter.
+        InvalidHyperparameterValue,
+        /// Too many hyperparameter changes are pending on the subnet.
+        TooManyScheduledHyperparameterChanges,
+        /// No pending change exists for this hyperparameter.
+        ScheduledHyperparameterChangeNotFound,
+        /// The value is outside the range root allows for this hyperparameter. The range is
+        /// available through the `subnetInfo_getHyperparameterBounds` RPC.
+        ValueOutOfBounds {
+            /// The hyperparameter whose range was exceeded.
+            param: Hyperparameter,
+        },
+        /// The minimum of the range is greater than its maximum.
+        InvalidHyperparameterBounds,
+        /// The account is not an identity attester.
+        NotIdentityAttester,
+        /// The attestation expiry is not in the future.
+        IdentityAttestationExpired,
+        /// The attested identity field is not set.
+        IdentityFieldNotSet,
+        /// Too many attestations are stored for the identity.
+        TooManyIdentityAttestations,
+        /// No attestation by the caller exists for the identity field.
+        IdentityAttestationNotFound,
+        /// The weights key is a hotkey registered on the subnet.
+        InvalidWeightsKey,
+        /// The weights key already acts for another hotkey on the subnet.
+        WeightsKeyAlreadyInUse,
+        /// The hotkey has no weights key on the subnet.
+        WeightsKeyNotFound,
+        /// The coldkey has no hotkey backing its alpha tokens on the subnet.
+        AlphaTokenHotkeyNotSet,
+        /// The transfer exceeds the alpha tokens the owner allowed the caller to spend.
+        InsufficientAlphaTokenAllowance,
     }
 }
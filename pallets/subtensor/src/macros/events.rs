@@ -330,6 +330,16 @@ mod events {
         /// - **who**: The account ID of the user revealing the weights.
         CRV3WeightsRevealed(NetUid, T::AccountId),
 
+        /// A timelocked weights commit could not be revealed and was dropped.
+        CRV3WeightsRevealFailed {
+            /// The network identifier.
+            netuid: NetUid,
+            /// The hotkey that committed the weights.
+            hotkey: T::AccountId,
+            /// Why the reveal failed.
+            reason: RevealFailureReason,
+        },
+
         /// Commit-Reveal periods has been successfully set.
         ///
         /// - **netuid**: The network identifier.
@@ -380,5 +390,171 @@ mod events {
             /// The symbol that has been updated.
             symbol: Vec<u8>,
         },
+
+        /// A neuron has served a list of axon endpoints.
+        AxonEndpointsServed {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The hotkey serving the endpoints.
+            hotkey: T::AccountId,
+            /// The number of endpoints served.
+            count: u32,
+        },
+
+        /// A subnet owner has granted hyperparameter rights to a delegate.
+        SubnetRoleGranted {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The account receiving the role.
+            delegate: T::AccountId,
+            /// The block at which the role expires, if any.
+            expires_at: Option<u64>,
+        },
+
+        /// A subnet role has been revoked.
+        SubnetRoleRevoked {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The account whose role was revoked.
+            delegate: T::AccountId,
+        },
+
+        /// A hyperparameter change has been queued on a subnet.
+        HyperparameterChangeScheduled {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The hyperparameter being changed.
+            param: Hyperparameter,
+            /// The new value.
+            value: u64,
+            /// The block at which the change takes effect.
+            effective_block: u64,
+        },
+
+        /// A pending hyperparameter change has been cancelled.
+        HyperparameterChangeCancelled {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The hyperparameter whose change was cancelled.
+            param: Hyperparameter,
+        },
+
+        /// A scheduled hyperparameter change has taken effect.
+        HyperparameterChangeApplied {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The hyperparameter that changed.
+            param: Hyperparameter,
+            /// The new value.
+            value: u64,
+        },
+
+        /// A scheduled hyperparameter change no longer passed its checks and was dropped.
+        HyperparameterChangeFailed {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The hyperparameter that was to change.
+            param: Hyperparameter,
+            /// The value that was to be set.
+            value: u64,
+            /// The check that failed.
+            error: sp_runtime::DispatchError,
+        },
+
+        /// Root has set the minimum notice for a hyperparameter.
+        HyperparameterMinNoticeSet {
+            /// The hyperparameter.
+            param: Hyperparameter,
+            /// The minimum notice, in blocks.
+            blocks: u64,
+        },
+
+        /// Root has set the range subnet owners may use for a hyperparameter.
+        HyperparameterBoundsSet {
+            /// The hyperparameter.
+            param: Hyperparameter,
+            /// The inclusive (min, max) range, or `None` if the range was removed.
+            bounds: Option<(u64, u64)>,
+        },
+
+        /// An identity attester has been appointed.
+        IdentityAttesterAdded(T::AccountId),
+
+        /// An identity attester has been removed.
+        IdentityAttesterRemoved(T::AccountId),
+
+        /// An attester has attested an identity field.
+        IdentityAttested {
+            /// The attester.
+            attester: T::AccountId,
+            /// The attested identity.
+            target: IdentityAttestationTarget<T::AccountId>,
+            /// The attested field.
+            field: IdentityField,
+            /// The block at which the attestation expires.
+            expires_at: u64,
+        },
+
+        /// An attester has revoked an identity attestation.
+        IdentityAttestationRevoked {
+            /// The attester.
+            attester: T::AccountId,
+            /// The attested identity.
+            target: IdentityAttestationTarget<T::AccountId>,
+            /// The field whose attestation was revoked.
+            field: IdentityField,
+        },
+
+        /// A hotkey has authorized a weights key on a subnet.
+        WeightsKeySet {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The hotkey the weights key acts for.
+            hotkey: T::AccountId,
+            /// The key allowed to set, commit and reveal weights.
+            weights_key: T::AccountId,
+        },
+
+        /// The weights key of a hotkey on a subnet has been removed.
+        WeightsKeyRemoved {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The hotkey whose weights key was removed.
+            hotkey: T::AccountId,
+        },
+
+        /// A coldkey has chosen the hotkey backing its alpha tokens on a subnet.
+        AlphaTokenHotkeySet {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The token holder.
+            coldkey: T::AccountId,
+            /// The hotkey whose stake backs the holder's tokens.
+            hotkey: T::AccountId,
+        },
+
+        /// A coldkey has allowed a spender to transfer its alpha tokens on a subnet.
+        AlphaTokenApproved {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The token holder.
+            owner: T::AccountId,
+            /// The account allowed to transfer the tokens.
+            spender: T::AccountId,
+            /// The allowed amount.
+            amount: AlphaCurrency,
+        },
+
+        /// Alpha tokens have been transferred on a subnet.
+        AlphaTokenTransferred {
+            /// The subnet ID
+            netuid: NetUid,
+            /// The sending coldkey.
+            from: T::AccountId,
+            /// The receiving coldkey.
+            to: T::AccountId,
+            /// The transferred alpha.
+            amount: AlphaCurrency,
+        },
     }
 }
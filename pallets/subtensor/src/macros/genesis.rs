@@ -11,6 +11,23 @@ mod genesis {
             // Set initial total issuance from balances
             TotalIssuance::<T>::put(self.balances_issuance);
 
+            // Override timing constants, e.g. to run an accelerated test network.
+            if let Some(delay) = self.start_call_delay {
+                StartCallDelay::<T>::put(delay);
+            }
+            if let Some(cooldown) = self.pending_childkey_cooldown {
+                PendingChildKeyCooldown::<T>::put(cooldown);
+            }
+            if let Some(limit) = self.set_children_rate_limit {
+                SetChildrenRateLimit::<T>::put(limit);
+            }
+            if let Some(limit) = self.childkey_take_rate_limit {
+                TxChildkeyTakeRateLimit::<T>::put(limit);
+            }
+            if let Some(tempo) = self.initial_subnet_tempo {
+                InitialSubnetTempo::<T>::put(tempo);
+            }
+
             // Set the root network as added.
             NetworksAdded::<T>::insert(NetUid::ROOT, true);
 
@@ -47,6 +64,16 @@ mod genesis {
                 Pallet::<T>::get_symbol_for_subnet(NetUid::ROOT),
             );
 
+            // Rebuild a snapshot of a live chain, its staked TAO is issued on top of balances.
+            if let Some(snapshot) = &self.snapshot {
+                Pallet::<T>::apply_snapshot(snapshot);
+                TotalIssuance::<T>::put(
+                    self.balances_issuance
+                        .saturating_add(TotalStake::<T>::get()),
+                );
+                return;
+            }
+
             let netuid = NetUid::from(1);
             let hotkey = DefaultAccount::<T>::get();
             SubnetMechanism::<T>::insert(netuid, 1); // Make dynamic.
@@ -16,6 +16,8 @@ mod hooks {
         // 		- The number of the block we are initializing.
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
             let hotkey_swap_clean_up_weight = Self::clean_up_hotkey_swap_records(block_number);
+            let scheduled_hyperparameters_weight =
+                Self::apply_scheduled_hyperparameter_changes(Self::get_current_block_as_u64());
 
             let block_step_result = Self::block_step();
             match block_step_result {
@@ -26,6 +28,7 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(scheduled_hyperparameters_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -34,6 +37,7 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(scheduled_hyperparameters_weight)
                 }
             }
         }
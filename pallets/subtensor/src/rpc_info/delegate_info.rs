@@ -4,6 +4,7 @@ use frame_support::pallet_prelude::{Decode, Encode};
 use safe_math::*;
 use substrate_fixed::types::U64F64;
 extern crate alloc;
+use crate::utils::identity_attestation::{IdentityAttestation, IdentityAttestationTarget};
 use alloc::collections::BTreeMap;
 use codec::Compact;
 use subtensor_runtime_common::{AlphaCurrency, NetUid};
@@ -21,6 +22,21 @@ pub struct DelegateInfo<AccountId: TypeInfo + Encode + Decode> {
     pub total_daily_return: Compact<u64>, // Delegators current daily return
 }
 
+#[freeze_struct("77c0ae601be6fbcf")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct DelegateInfoV2<AccountId: TypeInfo + Encode + Decode> {
+    pub delegate_ss58: AccountId,
+    pub take: Compact<u16>,
+    pub nominators: Vec<(AccountId, Vec<(Compact<NetUid>, Compact<u64>)>)>, // map of nominator_ss58 to netuid and stake amount
+    pub owner_ss58: AccountId,
+    pub registrations: Vec<Compact<NetUid>>, // Vec of netuid this delegate is registered on
+    pub validator_permits: Vec<Compact<NetUid>>, // Vec of netuid this delegate has validator permit on
+    pub return_per_1000: Compact<u64>, // Delegators current daily return per 1000 TAO staked minus take fee
+    pub total_daily_return: Compact<u64>, // Delegators current daily return
+    pub identity: Option<ChainIdentityOfV2>, // Identity of the owner coldkey
+    pub identity_attestations: Vec<IdentityAttestation<AccountId>>, // Valid attestations of the owner identity
+}
+
 impl<T: Config> Pallet<T> {
     fn return_per_1000_tao(
         take: Compact<u16>,
@@ -130,6 +146,29 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    fn get_delegate_v2_by_existing_account(
+        delegate: AccountIdOf<T>,
+        skip_nominators: bool,
+    ) -> DelegateInfoV2<T::AccountId> {
+        let info = Self::get_delegate_by_existing_account(delegate, skip_nominators);
+        let owner = info.owner_ss58.clone();
+
+        DelegateInfoV2 {
+            delegate_ss58: info.delegate_ss58,
+            take: info.take,
+            nominators: info.nominators,
+            owner_ss58: info.owner_ss58,
+            registrations: info.registrations,
+            validator_permits: info.validator_permits,
+            return_per_1000: info.return_per_1000,
+            total_daily_return: info.total_daily_return,
+            identity: IdentitiesV2::<T>::get(&owner),
+            identity_attestations: Self::get_valid_identity_attestations(
+                &IdentityAttestationTarget::Coldkey(owner),
+            ),
+        }
+    }
+
     pub fn get_delegate(delegate: T::AccountId) -> Option<DelegateInfo<T::AccountId>> {
         // Check delegate exists
         if !<Delegates<T>>::contains_key(delegate.clone()) {
@@ -184,6 +223,55 @@ impl<T: Config> Pallet<T> {
         delegates
     }
 
+    pub fn get_delegate_v2(delegate: T::AccountId) -> Option<DelegateInfoV2<T::AccountId>> {
+        if !<Delegates<T>>::contains_key(delegate.clone()) {
+            return None;
+        }
+
+        Some(Self::get_delegate_v2_by_existing_account(delegate, false))
+    }
+
+    /// get all delegates info, with the identity of their owner, from storage
+    ///
+    pub fn get_delegates_v2() -> Vec<DelegateInfoV2<T::AccountId>> {
+        <Delegates<T> as IterableStorageMap<T::AccountId, u16>>::iter_keys()
+            .map(|delegate| Self::get_delegate_v2_by_existing_account(delegate, false))
+            .collect()
+    }
+
+    /// get all delegate info, with the identity of their owner, and staked token amount for a
+    /// given delegatee account
+    ///
+    pub fn get_delegated_v2(
+        delegatee: T::AccountId,
+    ) -> Vec<(
+        DelegateInfoV2<T::AccountId>,
+        (Compact<NetUid>, Compact<AlphaCurrency>),
+    )> {
+        let mut delegates: Vec<(
+            DelegateInfoV2<T::AccountId>,
+            (Compact<NetUid>, Compact<AlphaCurrency>),
+        )> = Vec::new();
+        for delegate in <Delegates<T> as IterableStorageMap<T::AccountId, u16>>::iter_keys() {
+            for (netuid, _) in Alpha::<T>::iter_prefix((delegate.clone(), delegatee.clone())) {
+                let delegate_info =
+                    Self::get_delegate_v2_by_existing_account(delegate.clone(), true);
+                delegates.push((
+                    delegate_info,
+                    (
+                        netuid.into(),
+                        Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+                            &delegate, &delegatee, netuid,
+                        )
+                        .into(),
+                    ),
+                ));
+            }
+        }
+
+        delegates
+    }
+
     // Helper function to get the coldkey associated with a hotkey
     pub fn get_coldkey_for_hotkey(hotkey: &T::AccountId) -> T::AccountId {
         Owner::<T>::get(hotkey)
@@ -6,30 +6,30 @@ use substrate_fixed::types::I96F32;
 use subtensor_macros::freeze_struct;
 use subtensor_runtime_common::{AlphaCurrency, NetUid};
 
-#[freeze_struct("944ecd330621c61e")]
+#[freeze_struct("e55125094cd7ec3f")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct DynamicInfo<AccountId: TypeInfo + Encode + Decode> {
-    netuid: Compact<NetUid>,
-    owner_hotkey: AccountId,
-    owner_coldkey: AccountId,
-    subnet_name: Vec<Compact<u8>>,
-    token_symbol: Vec<Compact<u8>>,
-    tempo: Compact<u16>,
-    last_step: Compact<u64>,
-    blocks_since_last_step: Compact<u64>,
-    emission: Compact<u64>,
-    alpha_in: Compact<AlphaCurrency>,
-    alpha_out: Compact<AlphaCurrency>,
-    tao_in: Compact<u64>,
-    alpha_out_emission: Compact<AlphaCurrency>,
-    alpha_in_emission: Compact<AlphaCurrency>,
-    tao_in_emission: Compact<u64>,
-    pending_alpha_emission: Compact<AlphaCurrency>,
-    pending_root_emission: Compact<u64>,
-    subnet_volume: Compact<u128>,
-    network_registered_at: Compact<u64>,
-    subnet_identity: Option<SubnetIdentityV3>,
-    moving_price: I96F32,
+    pub netuid: Compact<NetUid>,
+    pub owner_hotkey: AccountId,
+    pub owner_coldkey: AccountId,
+    pub subnet_name: Vec<Compact<u8>>,
+    pub token_symbol: Vec<Compact<u8>>,
+    pub tempo: Compact<u16>,
+    pub last_step: Compact<u64>,
+    pub blocks_since_last_step: Compact<u64>,
+    pub emission: Compact<u64>,
+    pub alpha_in: Compact<AlphaCurrency>,
+    pub alpha_out: Compact<AlphaCurrency>,
+    pub tao_in: Compact<u64>,
+    pub alpha_out_emission: Compact<AlphaCurrency>,
+    pub alpha_in_emission: Compact<AlphaCurrency>,
+    pub tao_in_emission: Compact<u64>,
+    pub pending_alpha_emission: Compact<AlphaCurrency>,
+    pub pending_root_emission: Compact<u64>,
+    pub subnet_volume: Compact<u128>,
+    pub network_registered_at: Compact<u64>,
+    pub subnet_identity: Option<SubnetIdentityV3>,
+    pub moving_price: I96F32,
 }
 
 impl<T: Config> Pallet<T> {
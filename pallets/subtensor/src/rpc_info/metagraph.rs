@@ -1,6 +1,7 @@
 use super::*;
 extern crate alloc;
 use crate::epoch::math::*;
+use crate::utils::identity_attestation::{IdentityAttestation, IdentityAttestationTarget};
 use codec::Compact;
 use frame_support::IterableStorageDoubleMap;
 use frame_support::pallet_prelude::{Decode, Encode};
@@ -9,207 +10,227 @@ use substrate_fixed::types::I96F32;
 use subtensor_macros::freeze_struct;
 use subtensor_runtime_common::{AlphaCurrency, NetUid};
 
-#[freeze_struct("c25c5560ffd47ccb")]
+#[freeze_struct("adff35c37bfe184d")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct Metagraph<AccountId: TypeInfo + Encode + Decode> {
     // Subnet index
-    netuid: Compact<NetUid>,
+    pub netuid: Compact<NetUid>,
 
     // Name and symbol
-    name: Vec<Compact<u8>>,              // name
-    symbol: Vec<Compact<u8>>,            // token symbol
-    identity: Option<SubnetIdentityV3>,  // identity information.
-    network_registered_at: Compact<u64>, // block at registration
+    pub name: Vec<Compact<u8>>,              // name
+    pub symbol: Vec<Compact<u8>>,            // token symbol
+    pub identity: Option<SubnetIdentityV3>,  // identity information.
+    pub network_registered_at: Compact<u64>, // block at registration
 
     // Keys for owner.
-    owner_hotkey: AccountId,  // hotkey
-    owner_coldkey: AccountId, // coldkey.
+    pub owner_hotkey: AccountId,  // hotkey
+    pub owner_coldkey: AccountId, // coldkey.
 
     // Tempo terms.
-    block: Compact<u64>,                  // block at call.
-    tempo: Compact<u16>,                  // epoch tempo
-    last_step: Compact<u64>,              // last epoch
-    blocks_since_last_step: Compact<u64>, // blocks since last epoch.
+    pub block: Compact<u64>,                  // block at call.
+    pub tempo: Compact<u16>,                  // epoch tempo
+    pub last_step: Compact<u64>,              // last epoch
+    pub blocks_since_last_step: Compact<u64>, // blocks since last epoch.
 
     // Subnet emission terms
-    subnet_emission: Compact<u64>,     // subnet emission via stao
-    alpha_in: Compact<AlphaCurrency>,  // amount of alpha in reserve
-    alpha_out: Compact<AlphaCurrency>, // amount of alpha outstanding
-    tao_in: Compact<u64>,              // amount of tao injected per block
-    alpha_out_emission: Compact<AlphaCurrency>, // amount injected in alpha reserves per block
-    alpha_in_emission: Compact<AlphaCurrency>, // amount injected outstanding per block
-    tao_in_emission: Compact<u64>,     // amount of tao injected per block
-    pending_alpha_emission: Compact<AlphaCurrency>, // pending alpha to be distributed
-    pending_root_emission: Compact<u64>, // panding tao for root divs to be distributed
-    subnet_volume: Compact<u128>,      // volume of the subnet in TAO
-    moving_price: I96F32,              // subnet moving price.
+    pub subnet_emission: Compact<u64>, // subnet emission via stao
+    pub alpha_in: Compact<AlphaCurrency>, // amount of alpha in reserve
+    pub alpha_out: Compact<AlphaCurrency>, // amount of alpha outstanding
+    pub tao_in: Compact<u64>,          // amount of tao injected per block
+    pub alpha_out_emission: Compact<AlphaCurrency>, // amount injected in alpha reserves per block
+    pub alpha_in_emission: Compact<AlphaCurrency>, // amount injected outstanding per block
+    pub tao_in_emission: Compact<u64>, // amount of tao injected per block
+    pub pending_alpha_emission: Compact<AlphaCurrency>, // pending alpha to be distributed
+    pub pending_root_emission: Compact<u64>, // panding tao for root divs to be distributed
+    pub subnet_volume: Compact<u128>,  // volume of the subnet in TAO
+    pub moving_price: I96F32,          // subnet moving price.
 
     // Hparams for epoch
-    rho: Compact<u16>,   // subnet rho param
-    kappa: Compact<u16>, // subnet kappa param
+    pub rho: Compact<u16>,   // subnet rho param
+    pub kappa: Compact<u16>, // subnet kappa param
 
     // Validator params
-    min_allowed_weights: Compact<u16>, // min allowed weights per val
-    max_weights_limit: Compact<u16>,   // max allowed weights per val
-    weights_version: Compact<u64>,     // allowed weights version
-    weights_rate_limit: Compact<u64>,  // rate limit on weights.
-    activity_cutoff: Compact<u16>,     // validator weights cut off period in blocks
-    max_validators: Compact<u16>,      // max allowed validators.
+    pub min_allowed_weights: Compact<u16>, // min allowed weights per val
+    pub max_weights_limit: Compact<u16>,   // max allowed weights per val
+    pub weights_version: Compact<u64>,     // allowed weights version
+    pub weights_rate_limit: Compact<u64>,  // rate limit on weights.
+    pub activity_cutoff: Compact<u16>,     // validator weights cut off period in blocks
+    pub max_validators: Compact<u16>,      // max allowed validators.
 
     // Registration
-    num_uids: Compact<u16>,
-    max_uids: Compact<u16>,
-    burn: Compact<u64>,                     // current burn cost..
-    difficulty: Compact<u64>,               // current difficulty.
-    registration_allowed: bool,             // allows registrations.
-    pow_registration_allowed: bool,         // pow registration enabled.
-    immunity_period: Compact<u16>,          // subnet miner immunity period
-    min_difficulty: Compact<u64>,           // min pow difficulty
-    max_difficulty: Compact<u64>,           // max pow difficulty
-    min_burn: Compact<u64>,                 // min tao burn
-    max_burn: Compact<u64>,                 // max tao burn
-    adjustment_alpha: Compact<u64>,         // adjustment speed for registration params.
-    adjustment_interval: Compact<u16>,      // pow and burn adjustment interval
-    target_regs_per_interval: Compact<u16>, // target registrations per interval
-    max_regs_per_block: Compact<u16>,       // max registrations per block.
-    serving_rate_limit: Compact<u64>,       // axon serving rate limit
+    pub num_uids: Compact<u16>,
+    pub max_uids: Compact<u16>,
+    pub burn: Compact<u64>,                     // current burn cost..
+    pub difficulty: Compact<u64>,               // current difficulty.
+    pub registration_allowed: bool,             // allows registrations.
+    pub pow_registration_allowed: bool,         // pow registration enabled.
+    pub immunity_period: Compact<u16>,          // subnet miner immunity period
+    pub min_difficulty: Compact<u64>,           // min pow difficulty
+    pub max_difficulty: Compact<u64>,           // max pow difficulty
+    pub min_burn: Compact<u64>,                 // min tao burn
+    pub max_burn: Compact<u64>,                 // max tao burn
+    pub adjustment_alpha: Compact<u64>,         // adjustment speed for registration params.
+    pub adjustment_interval: Compact<u16>,      // pow and burn adjustment interval
+    pub target_regs_per_interval: Compact<u16>, // target registrations per interval
+    pub max_regs_per_block: Compact<u16>,       // max registrations per block.
+    pub serving_rate_limit: Compact<u64>,       // axon serving rate limit
 
     // CR
-    commit_reveal_weights_enabled: bool, // Is CR enabled.
-    commit_reveal_period: Compact<u64>,  // Commit reveal interval
+    pub commit_reveal_weights_enabled: bool, // Is CR enabled.
+    pub commit_reveal_period: Compact<u64>,  // Commit reveal interval
 
     // Bonds
-    liquid_alpha_enabled: bool,     // Bonds liquid enabled.
-    alpha_high: Compact<u16>,       // Alpha param high
-    alpha_low: Compact<u16>,        // Alpha param low
-    bonds_moving_avg: Compact<u64>, // Bonds moving avg
+    pub liquid_alpha_enabled: bool,     // Bonds liquid enabled.
+    pub alpha_high: Compact<u16>,       // Alpha param high
+    pub alpha_low: Compact<u16>,        // Alpha param low
+    pub bonds_moving_avg: Compact<u64>, // Bonds moving avg
 
     // Metagraph info.
-    hotkeys: Vec<AccountId>,                    // hotkey per UID
-    coldkeys: Vec<AccountId>,                   // coldkey per UID
-    identities: Vec<Option<ChainIdentityOfV2>>, // coldkeys identities
-    axons: Vec<AxonInfo>,                       // UID axons.
-    active: Vec<bool>,                          // Avtive per UID
-    validator_permit: Vec<bool>,                // Val permit per UID
-    pruning_score: Vec<Compact<u16>>,           // Pruning per UID
-    last_update: Vec<Compact<u64>>,             // Last update per UID
-    emission: Vec<Compact<AlphaCurrency>>,      // Emission per UID
-    dividends: Vec<Compact<u16>>,               // Dividends per UID
-    incentives: Vec<Compact<u16>>,              // Mining incentives per UID
-    consensus: Vec<Compact<u16>>,               // Consensus per UID
-    trust: Vec<Compact<u16>>,                   // Trust per UID
-    rank: Vec<Compact<u16>>,                    // Rank per UID
-    block_at_registration: Vec<Compact<u64>>,   // Reg block per UID
-    alpha_stake: Vec<Compact<u64>>,             // Alpha staked per UID
-    tao_stake: Vec<Compact<u64>>,               // TAO staked per UID
-    total_stake: Vec<Compact<u64>>,             // Total stake per UID
+    pub hotkeys: Vec<AccountId>,                    // hotkey per UID
+    pub coldkeys: Vec<AccountId>,                   // coldkey per UID
+    pub identities: Vec<Option<ChainIdentityOfV2>>, // coldkeys identities
+    pub axons: Vec<AxonInfo>,                       // UID axons.
+    pub active: Vec<bool>,                          // Avtive per UID
+    pub validator_permit: Vec<bool>,                // Val permit per UID
+    pub pruning_score: Vec<Compact<u16>>,           // Pruning per UID
+    pub last_update: Vec<Compact<u64>>,             // Last update per UID
+    pub emission: Vec<Compact<AlphaCurrency>>,      // Emission per UID
+    pub dividends: Vec<Compact<u16>>,               // Dividends per UID
+    pub incentives: Vec<Compact<u16>>,              // Mining incentives per UID
+    pub consensus: Vec<Compact<u16>>,               // Consensus per UID
+    pub trust: Vec<Compact<u16>>,                   // Trust per UID
+    pub rank: Vec<Compact<u16>>,                    // Rank per UID
+    pub block_at_registration: Vec<Compact<u64>>,   // Reg block per UID
+    pub alpha_stake: Vec<Compact<u64>>,             // Alpha staked per UID
+    pub tao_stake: Vec<Compact<u64>>,               // TAO staked per UID
+    pub total_stake: Vec<Compact<u64>>,             // Total stake per UID
 
     // Dividend break down.
-    tao_dividends_per_hotkey: Vec<(AccountId, Compact<u64>)>, // List of dividend payouts in tao via root.
-    alpha_dividends_per_hotkey: Vec<(AccountId, Compact<AlphaCurrency>)>, // List of dividend payout in alpha via subnet.
+    pub tao_dividends_per_hotkey: Vec<(AccountId, Compact<u64>)>, // List of dividend payouts in tao via root.
+    pub alpha_dividends_per_hotkey: Vec<(AccountId, Compact<AlphaCurrency>)>, // List of dividend payout in alpha via subnet.
+
+    // Endpoints
+    pub axon_endpoints: Vec<Vec<AxonEndpoint>>, // Served endpoints per UID
+
+    // Identity attestations
+    pub identity_attestations: Vec<Vec<IdentityAttestation<AccountId>>>, // Valid attestations of the coldkey identities
+    pub subnet_identity_attestations: Vec<IdentityAttestation<AccountId>>, // Valid attestations of the subnet identity
+
+    // Weight-setting quality
+    pub validator_weight_metrics: Vec<Vec<ValidatorWeightMetrics>>, // Recent weight metrics per UID
 }
 
-#[freeze_struct("2fa92e896b40a104")]
+#[freeze_struct("8f26d6b295a527aa")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SelectiveMetagraph<AccountId: TypeInfo + Encode + Decode + Clone> {
     // Subnet index
-    netuid: Compact<NetUid>,
+    pub netuid: Compact<NetUid>,
 
     // Name and symbol
-    name: Option<Vec<Compact<u8>>>,              // name
-    symbol: Option<Vec<Compact<u8>>>,            // token symbol
-    identity: Option<Option<SubnetIdentityV3>>,  // identity information.
-    network_registered_at: Option<Compact<u64>>, // block at registration
+    pub name: Option<Vec<Compact<u8>>>,              // name
+    pub symbol: Option<Vec<Compact<u8>>>,            // token symbol
+    pub identity: Option<Option<SubnetIdentityV3>>,  // identity information.
+    pub network_registered_at: Option<Compact<u64>>, // block at registration
 
     // Keys for owner.
-    owner_hotkey: Option<AccountId>,  // hotkey
-    owner_coldkey: Option<AccountId>, // coldkey.
+    pub owner_hotkey: Option<AccountId>,  // hotkey
+    pub owner_coldkey: Option<AccountId>, // coldkey.
 
     // Tempo terms.
-    block: Option<Compact<u64>>,                  // block at call.
-    tempo: Option<Compact<u16>>,                  // epoch tempo
-    last_step: Option<Compact<u64>>,              // last epoch
-    blocks_since_last_step: Option<Compact<u64>>, // blocks since last epoch.
+    pub block: Option<Compact<u64>>,     // block at call.
+    pub tempo: Option<Compact<u16>>,     // epoch tempo
+    pub last_step: Option<Compact<u64>>, // last epoch
+    pub blocks_since_last_step: Option<Compact<u64>>, // blocks since last epoch.
 
     // Subnet emission terms
-    subnet_emission: Option<Compact<u64>>, // subnet emission via stao
-    alpha_in: Option<Compact<AlphaCurrency>>, // amount of alpha in reserve
-    alpha_out: Option<Compact<AlphaCurrency>>, // amount of alpha outstanding
-    tao_in: Option<Compact<u64>>,          // amount of tao injected per block
-    alpha_out_emission: Option<Compact<AlphaCurrency>>, // amount injected in alpha reserves per block
-    alpha_in_emission: Option<Compact<AlphaCurrency>>,  // amount injected outstanding per block
-    tao_in_emission: Option<Compact<u64>>,              // amount of tao injected per block
-    pending_alpha_emission: Option<Compact<AlphaCurrency>>, // pending alpha to be distributed
-    pending_root_emission: Option<Compact<u64>>, // panding tao for root divs to be distributed
-    subnet_volume: Option<Compact<u128>>,        // volume of the subnet in TAO
-    moving_price: Option<I96F32>,                // subnet moving price.
+    pub subnet_emission: Option<Compact<u64>>, // subnet emission via stao
+    pub alpha_in: Option<Compact<AlphaCurrency>>, // amount of alpha in reserve
+    pub alpha_out: Option<Compact<AlphaCurrency>>, // amount of alpha outstanding
+    pub tao_in: Option<Compact<u64>>,          // amount of tao injected per block
+    pub alpha_out_emission: Option<Compact<AlphaCurrency>>, // amount injected in alpha reserves per block
+    pub alpha_in_emission: Option<Compact<AlphaCurrency>>,  // amount injected outstanding per block
+    pub tao_in_emission: Option<Compact<u64>>,              // amount of tao injected per block
+    pub pending_alpha_emission: Option<Compact<AlphaCurrency>>, // pending alpha to be distributed
+    pub pending_root_emission: Option<Compact<u64>>, // panding tao for root divs to be distributed
+    pub subnet_volume: Option<Compact<u128>>,        // volume of the subnet in TAO
+    pub moving_price: Option<I96F32>,                // subnet moving price.
 
     // Hparams for epoch
-    rho: Option<Compact<u16>>,   // subnet rho param
-    kappa: Option<Compact<u16>>, // subnet kappa param
+    pub rho: Option<Compact<u16>>,   // subnet rho param
+    pub kappa: Option<Compact<u16>>, // subnet kappa param
 
     // Validator params
-    min_allowed_weights: Option<Compact<u16>>, // min allowed weights per val
-    max_weights_limit: Option<Compact<u16>>,   // max allowed weights per val
-    weights_version: Option<Compact<u64>>,     // allowed weights version
-    weights_rate_limit: Option<Compact<u64>>,  // rate limit on weights.
-    activity_cutoff: Option<Compact<u16>>,     // validator weights cut off period in blocks
-    max_validators: Option<Compact<u16>>,      // max allowed validators.
+    pub min_allowed_weights: Option<Compact<u16>>, // min allowed weights per val
+    pub max_weights_limit: Option<Compact<u16>>,   // max allowed weights per val
+    pub weights_version: Option<Compact<u64>>,     // allowed weights version
+    pub weights_rate_limit: Option<Compact<u64>>,  // rate limit on weights.
+    pub activity_cutoff: Option<Compact<u16>>,     // validator weights cut off period in blocks
+    pub max_validators: Option<Compact<u16>>,      // max allowed validators.
 
     // Registration
-    num_uids: Option<Compact<u16>>,
-    max_uids: Option<Compact<u16>>,
-    burn: Option<Compact<u64>>,                     // current burn cost..
-    difficulty: Option<Compact<u64>>,               // current difficulty.
-    registration_allowed: Option<bool>,             // allows registrations.
-    pow_registration_allowed: Option<bool>,         // pow registration enabled.
-    immunity_period: Option<Compact<u16>>,          // subnet miner immunity period
-    min_difficulty: Option<Compact<u64>>,           // min pow difficulty
-    max_difficulty: Option<Compact<u64>>,           // max pow difficulty
-    min_burn: Option<Compact<u64>>,                 // min tao burn
-    max_burn: Option<Compact<u64>>,                 // max tao burn
-    adjustment_alpha: Option<Compact<u64>>,         // adjustment speed for registration params.
-    adjustment_interval: Option<Compact<u16>>,      // pow and burn adjustment interval
-    target_regs_per_interval: Option<Compact<u16>>, // target registrations per interval
-    max_regs_per_block: Option<Compact<u16>>,       // max registrations per block.
-    serving_rate_limit: Option<Compact<u64>>,       // axon serving rate limit
+    pub num_uids: Option<Compact<u16>>,
+    pub max_uids: Option<Compact<u16>>,
+    pub burn: Option<Compact<u64>>,             // current burn cost..
+    pub difficulty: Option<Compact<u64>>,       // current difficulty.
+    pub registration_allowed: Option<bool>,     // allows registrations.
+    pub pow_registration_allowed: Option<bool>, // pow registration enabled.
+    pub immunity_period: Option<Compact<u16>>,  // subnet miner immunity period
+    pub min_difficulty: Option<Compact<u64>>,   // min pow difficulty
+    pub max_difficulty: Option<Compact<u64>>,   // max pow difficulty
+    pub min_burn: Option<Compact<u64>>,         // min tao burn
+    pub max_burn: Option<Compact<u64>>,         // max tao burn
+    pub adjustment_alpha: Option<Compact<u64>>, // adjustment speed for registration params.
+    pub adjustment_interval: Option<Compact<u16>>, // pow and burn adjustment interval
+    pub target_regs_per_interval: Option<Compact<u16>>, // target registrations per interval
+    pub max_regs_per_block: Option<Compact<u16>>, // max registrations per block.
+    pub serving_rate_limit: Option<Compact<u64>>, // axon serving rate limit
 
     // CR
-    commit_reveal_weights_enabled: Option<bool>, // Is CR enabled.
-    commit_reveal_period: Option<Compact<u64>>,  // Commit reveal interval
+    pub commit_reveal_weights_enabled: Option<bool>, // Is CR enabled.
+    pub commit_reveal_period: Option<Compact<u64>>,  // Commit reveal interval
 
     // Bonds
-    liquid_alpha_enabled: Option<bool>, // Bonds liquid enabled.
-    alpha_high: Option<Compact<u16>>,   // Alpha param high
-    alpha_low: Option<Compact<u16>>,    // Alpha param low
-    bonds_moving_avg: Option<Compact<u64>>, // Bonds moving avg
+    pub liquid_alpha_enabled: Option<bool>, // Bonds liquid enabled.
+    pub alpha_high: Option<Compact<u16>>,   // Alpha param high
+    pub alpha_low: Option<Compact<u16>>,    // Alpha param low
+    pub bonds_moving_avg: Option<Compact<u64>>, // Bonds moving avg
 
     // Metagraph info.
-    hotkeys: Option<Vec<AccountId>>,  // hotkey per UID
-    coldkeys: Option<Vec<AccountId>>, // coldkey per UID
-    identities: Option<Vec<Option<ChainIdentityOfV2>>>, // coldkeys identities
-    axons: Option<Vec<AxonInfo>>,     // UID axons.
-    active: Option<Vec<bool>>,        // Avtive per UID
-    validator_permit: Option<Vec<bool>>, // Val permit per UID
-    pruning_score: Option<Vec<Compact<u16>>>, // Pruning per UID
-    last_update: Option<Vec<Compact<u64>>>, // Last update per UID
-    emission: Option<Vec<Compact<AlphaCurrency>>>, // Emission per UID
-    dividends: Option<Vec<Compact<u16>>>, // Dividends per UID
-    incentives: Option<Vec<Compact<u16>>>, // Mining incentives per UID
-    consensus: Option<Vec<Compact<u16>>>, // Consensus per UID
-    trust: Option<Vec<Compact<u16>>>, // Trust per UID
-    rank: Option<Vec<Compact<u16>>>,  // Rank per UID
-    block_at_registration: Option<Vec<Compact<u64>>>, // Reg block per UID
-    alpha_stake: Option<Vec<Compact<u64>>>, // Alpha staked per UID
-    tao_stake: Option<Vec<Compact<u64>>>, // TAO staked per UID
-    total_stake: Option<Vec<Compact<u64>>>, // Total stake per UID
+    pub hotkeys: Option<Vec<AccountId>>,  // hotkey per UID
+    pub coldkeys: Option<Vec<AccountId>>, // coldkey per UID
+    pub identities: Option<Vec<Option<ChainIdentityOfV2>>>, // coldkeys identities
+    pub axons: Option<Vec<AxonInfo>>,     // UID axons.
+    pub active: Option<Vec<bool>>,        // Avtive per UID
+    pub validator_permit: Option<Vec<bool>>, // Val permit per UID
+    pub pruning_score: Option<Vec<Compact<u16>>>, // Pruning per UID
+    pub last_update: Option<Vec<Compact<u64>>>, // Last update per UID
+    pub emission: Option<Vec<Compact<AlphaCurrency>>>, // Emission per UID
+    pub dividends: Option<Vec<Compact<u16>>>, // Dividends per UID
+    pub incentives: Option<Vec<Compact<u16>>>, // Mining incentives per UID
+    pub consensus: Option<Vec<Compact<u16>>>, // Consensus per UID
+    pub trust: Option<Vec<Compact<u16>>>, // Trust per UID
+    pub rank: Option<Vec<Compact<u16>>>,  // Rank per UID
+    pub block_at_registration: Option<Vec<Compact<u64>>>, // Reg block per UID
+    pub alpha_stake: Option<Vec<Compact<u64>>>, // Alpha staked per UID
+    pub tao_stake: Option<Vec<Compact<u64>>>, // TAO staked per UID
+    pub total_stake: Option<Vec<Compact<u64>>>, // Total stake per UID
 
     // Dividend break down.
-    tao_dividends_per_hotkey: Option<Vec<(AccountId, Compact<u64>)>>, // List of dividend payouts in tao via root.
-    alpha_dividends_per_hotkey: Option<Vec<(AccountId, Compact<AlphaCurrency>)>>, // List of dividend payout in alpha via subnet.
+    pub tao_dividends_per_hotkey: Option<Vec<(AccountId, Compact<u64>)>>, // List of dividend payouts in tao via root.
+    pub alpha_dividends_per_hotkey: Option<Vec<(AccountId, Compact<AlphaCurrency>)>>, // List of dividend payout in alpha via subnet.
 
     // validators
-    validators: Option<Vec<Compact<u16>>>, // List of validators
+    pub validators: Option<Vec<Compact<u16>>>, // List of validators
+
+    // Endpoints
+    pub axon_endpoints: Option<Vec<Vec<AxonEndpoint>>>, // Served endpoints per UID
+
+    // Identity attestations
+    pub identity_attestations: Option<Vec<Vec<IdentityAttestation<AccountId>>>>, // Valid attestations of the coldkey identities
+    pub subnet_identity_attestations: Option<Vec<IdentityAttestation<AccountId>>>, // Valid attestations of the subnet identity
+
+    // Weight-setting quality
+    pub validator_weight_metrics: Option<Vec<Vec<ValidatorWeightMetrics>>>, // Recent weight metrics per UID
 }
 
 impl<AccountId> SelectiveMetagraph<AccountId>
@@ -367,6 +388,18 @@ where
                 self.alpha_dividends_per_hotkey = other.alpha_dividends_per_hotkey.clone()
             }
             Some(SelectiveMetagraphIndex::Validators) => self.validators = other.validators.clone(),
+            Some(SelectiveMetagraphIndex::AxonEndpoints) => {
+                self.axon_endpoints = other.axon_endpoints.clone()
+            }
+            Some(SelectiveMetagraphIndex::IdentityAttestations) => {
+                self.identity_attestations = other.identity_attestations.clone()
+            }
+            Some(SelectiveMetagraphIndex::SubnetIdentityAttestations) => {
+                self.subnet_identity_attestations = other.subnet_identity_attestations.clone()
+            }
+            Some(SelectiveMetagraphIndex::ValidatorWeightMetrics) => {
+                self.validator_weight_metrics = other.validator_weight_metrics.clone()
+            }
             None => {}
         };
     }
@@ -451,6 +484,10 @@ where
             tao_dividends_per_hotkey: None,
             alpha_dividends_per_hotkey: None,
             validators: None,
+            axon_endpoints: None,
+            identity_attestations: None,
+            subnet_identity_attestations: None,
+            validator_weight_metrics: None,
         }
     }
 }
@@ -529,6 +566,10 @@ pub enum SelectiveMetagraphIndex {
     TaoDividendsPerHotkey,
     AlphaDividendsPerHotkey,
     Validators,
+    AxonEndpoints,
+    IdentityAttestations,
+    SubnetIdentityAttestations,
+    ValidatorWeightMetrics,
 }
 
 impl SelectiveMetagraphIndex {
@@ -607,6 +648,10 @@ impl SelectiveMetagraphIndex {
             70 => Some(SelectiveMetagraphIndex::TaoDividendsPerHotkey),
             71 => Some(SelectiveMetagraphIndex::AlphaDividendsPerHotkey),
             72 => Some(SelectiveMetagraphIndex::Validators),
+            73 => Some(SelectiveMetagraphIndex::AxonEndpoints),
+            74 => Some(SelectiveMetagraphIndex::IdentityAttestations),
+            75 => Some(SelectiveMetagraphIndex::SubnetIdentityAttestations),
+            76 => Some(SelectiveMetagraphIndex::ValidatorWeightMetrics),
             _ => None,
         }
     }
@@ -623,6 +668,9 @@ impl<T: Config> Pallet<T> {
         let mut block_at_registration: Vec<Compact<u64>> = vec![];
         let mut identities: Vec<Option<ChainIdentityOfV2>> = vec![];
         let mut axons: Vec<AxonInfo> = vec![];
+        let mut axon_endpoints: Vec<Vec<AxonEndpoint>> = vec![];
+        let mut identity_attestations: Vec<Vec<IdentityAttestation<T::AccountId>>> = vec![];
+        let mut validator_weight_metrics: Vec<Vec<ValidatorWeightMetrics>> = vec![];
         for uid in 0..n {
             let hotkey = Keys::<T>::get(netuid, uid);
             let coldkey = Owner::<T>::get(hotkey.clone());
@@ -631,6 +679,11 @@ impl<T: Config> Pallet<T> {
             block_at_registration.push(BlockAtRegistration::<T>::get(netuid, uid).into());
             identities.push(IdentitiesV2::<T>::get(coldkey.clone()));
             axons.push(Self::get_axon_info(netuid, &hotkey));
+            axon_endpoints.push(Self::get_axon_endpoints(netuid, &hotkey));
+            identity_attestations.push(Self::get_valid_identity_attestations(
+                &IdentityAttestationTarget::Coldkey(coldkey),
+            ));
+            validator_weight_metrics.push(Self::get_validator_weight_metrics(netuid, uid));
         }
         let mut tao_dividends_per_hotkey: Vec<(T::AccountId, Compact<u64>)> = vec![];
         let mut alpha_dividends_per_hotkey: Vec<(T::AccountId, Compact<AlphaCurrency>)> = vec![];
@@ -785,6 +838,18 @@ impl<T: Config> Pallet<T> {
             // Dividend break down.
             tao_dividends_per_hotkey,
             alpha_dividends_per_hotkey,
+
+            // Endpoints
+            axon_endpoints,
+
+            // Identity attestations
+            identity_attestations,
+            subnet_identity_attestations: Self::get_valid_identity_attestations(
+                &IdentityAttestationTarget::Subnet(netuid),
+            ),
+
+            // Weight-setting quality
+            validator_weight_metrics,
         })
     }
     pub fn get_all_metagraphs() -> Vec<Option<Metagraph<T::AccountId>>> {
@@ -1173,6 +1238,54 @@ impl<T: Config> Pallet<T> {
                     ..Default::default()
                 }
             }
+            Some(SelectiveMetagraphIndex::AxonEndpoints) => {
+                let n: u16 = Self::get_subnetwork_n(netuid);
+                let mut axon_endpoints: Vec<Vec<AxonEndpoint>> = vec![];
+                for uid in 0..n {
+                    let hotkey = Keys::<T>::get(netuid, uid);
+                    axon_endpoints.push(Self::get_axon_endpoints(netuid, &hotkey));
+                }
+                SelectiveMetagraph {
+                    netuid: netuid.into(),
+                    axon_endpoints: Some(axon_endpoints),
+                    ..Default::default()
+                }
+            }
+            Some(SelectiveMetagraphIndex::IdentityAttestations) => {
+                let n: u16 = Self::get_subnetwork_n(netuid);
+                let mut identity_attestations: Vec<Vec<IdentityAttestation<T::AccountId>>> = vec![];
+                for uid in 0..n {
+                    let hotkey = Keys::<T>::get(netuid, uid);
+                    let coldkey = Owner::<T>::get(hotkey.clone());
+                    identity_attestations.push(Self::get_valid_identity_attestations(
+                        &IdentityAttestationTarget::Coldkey(coldkey),
+                    ));
+                }
+                SelectiveMetagraph {
+                    netuid: netuid.into(),
+                    identity_attestations: Some(identity_attestations),
+                    ..Default::default()
+                }
+            }
+            Some(SelectiveMetagraphIndex::SubnetIdentityAttestations) => SelectiveMetagraph {
+                netuid: netuid.into(),
+                subnet_identity_attestations: Some(Self::get_valid_identity_attestations(
+                    &IdentityAttestationTarget::Subnet(netuid),
+                )),
+                ..Default::default()
+            },
+            Some(SelectiveMetagraphIndex::ValidatorWeightMetrics) => {
+                let n: u16 = Self::get_subnetwork_n(netuid);
+                SelectiveMetagraph {
+                    netuid: netuid.into(),
+                    validator_weight_metrics: Some(
+                        (0..n)
+                            .map(|uid| Self::get_validator_weight_metrics(netuid, uid))
+                            .collect(),
+                    ),
+                    ..Default::default()
+                }
+            }
             Some(SelectiveMetagraphIndex::Active) => SelectiveMetagraph {
                 netuid: netuid.into(),
                 active: Some(Active::<T>::get(netuid)),
@@ -1491,6 +1604,10 @@ fn test_selective_metagraph() {
         tao_dividends_per_hotkey: None,
         alpha_dividends_per_hotkey: None,
         validators: None,
+        axon_endpoints: None,
+        identity_attestations: None,
+        subnet_identity_attestations: None,
+        validator_weight_metrics: None,
     };
 
     // test init value
@@ -4,53 +4,53 @@ extern crate alloc;
 use codec::Compact;
 use subtensor_runtime_common::{AlphaCurrency, NetUid};
 
-#[freeze_struct("9e5a291e7e71482d")]
+#[freeze_struct("2932cae7a9e64e0e")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct NeuronInfo<AccountId: TypeInfo + Encode + Decode> {
-    hotkey: AccountId,
-    coldkey: AccountId,
-    uid: Compact<u16>,
-    netuid: Compact<NetUid>,
-    active: bool,
-    axon_info: AxonInfo,
-    prometheus_info: PrometheusInfo,
-    stake: Vec<(AccountId, Compact<AlphaCurrency>)>, // map of coldkey to stake on this neuron/hotkey (includes delegations)
-    rank: Compact<u16>,
-    emission: Compact<AlphaCurrency>,
-    incentive: Compact<u16>,
-    consensus: Compact<u16>,
-    trust: Compact<u16>,
-    validator_trust: Compact<u16>,
-    dividends: Compact<u16>,
-    last_update: Compact<u64>,
-    validator_permit: bool,
-    weights: Vec<(Compact<u16>, Compact<u16>)>, // Vec of (uid, weight)
-    bonds: Vec<(Compact<u16>, Compact<u16>)>,   // Vec of (uid, bond)
-    pruning_score: Compact<u16>,
+    pub hotkey: AccountId,
+    pub coldkey: AccountId,
+    pub uid: Compact<u16>,
+    pub netuid: Compact<NetUid>,
+    pub active: bool,
+    pub axon_info: AxonInfo,
+    pub prometheus_info: PrometheusInfo,
+    pub stake: Vec<(AccountId, Compact<AlphaCurrency>)>, // map of coldkey to stake on this neuron/hotkey (includes delegations)
+    pub rank: Compact<u16>,
+    pub emission: Compact<AlphaCurrency>,
+    pub incentive: Compact<u16>,
+    pub consensus: Compact<u16>,
+    pub trust: Compact<u16>,
+    pub validator_trust: Compact<u16>,
+    pub dividends: Compact<u16>,
+    pub last_update: Compact<u64>,
+    pub validator_permit: bool,
+    pub weights: Vec<(Compact<u16>, Compact<u16>)>, // Vec of (uid, weight)
+    pub bonds: Vec<(Compact<u16>, Compact<u16>)>,   // Vec of (uid, bond)
+    pub pruning_score: Compact<u16>,
 }
 
-#[freeze_struct("b9fdff7fc6e023c7")]
+#[freeze_struct("a32f17414752bd43")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct NeuronInfoLite<AccountId: TypeInfo + Encode + Decode> {
-    hotkey: AccountId,
-    coldkey: AccountId,
-    uid: Compact<u16>,
-    netuid: Compact<NetUid>,
-    active: bool,
-    axon_info: AxonInfo,
-    prometheus_info: PrometheusInfo,
-    stake: Vec<(AccountId, Compact<AlphaCurrency>)>, // map of coldkey to stake on this neuron/hotkey (includes delegations)
-    rank: Compact<u16>,
-    emission: Compact<AlphaCurrency>,
-    incentive: Compact<u16>,
-    consensus: Compact<u16>,
-    trust: Compact<u16>,
-    validator_trust: Compact<u16>,
-    dividends: Compact<u16>,
-    last_update: Compact<u64>,
-    validator_permit: bool,
+    pub hotkey: AccountId,
+    pub coldkey: AccountId,
+    pub uid: Compact<u16>,
+    pub netuid: Compact<NetUid>,
+    pub active: bool,
+    pub axon_info: AxonInfo,
+    pub prometheus_info: PrometheusInfo,
+    pub stake: Vec<(AccountId, Compact<AlphaCurrency>)>, // map of coldkey to stake on this neuron/hotkey (includes delegations)
+    pub rank: Compact<u16>,
+    pub emission: Compact<AlphaCurrency>,
+    pub incentive: Compact<u16>,
+    pub consensus: Compact<u16>,
+    pub trust: Compact<u16>,
+    pub validator_trust: Compact<u16>,
+    pub dividends: Compact<u16>,
+    pub last_update: Compact<u64>,
+    pub validator_permit: bool,
     // has no weights or bonds
-    pruning_score: Compact<u16>,
+    pub pruning_score: Compact<u16>,
 }
 
 impl<T: Config> Pallet<T> {
@@ -236,4 +236,20 @@ impl<T: Config> Pallet<T> {
 
         Self::get_neuron_lite_subnet_exists(netuid, uid)
     }
+
+    pub fn get_neuron_axon_endpoints(netuid: NetUid, uid: u16) -> Vec<AxonEndpoint> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        match Self::get_hotkey_for_net_and_uid(netuid, uid) {
+            Ok(hotkey) => Self::get_axon_endpoints(netuid, &hotkey),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    pub fn get_neuron_weights_key(netuid: NetUid, uid: u16) -> Option<T::AccountId> {
+        let hotkey = Self::get_hotkey_for_net_and_uid(netuid, uid).ok()?;
+        WeightsKeys::<T>::get(netuid, hotkey)
+    }
 }
@@ -6,27 +6,27 @@ use frame_support::pallet_prelude::{Decode, Encode};
 use substrate_fixed::types::I64F64;
 use subtensor_runtime_common::{AlphaCurrency, NetUid};
 
-#[freeze_struct("c990700ae235dee9")]
+#[freeze_struct("6dda2fec13c86d9b")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SubnetState<AccountId: TypeInfo + Encode + Decode> {
-    netuid: Compact<NetUid>,
-    hotkeys: Vec<AccountId>,
-    coldkeys: Vec<AccountId>,
-    active: Vec<bool>,
-    validator_permit: Vec<bool>,
-    pruning_score: Vec<Compact<u16>>,
-    last_update: Vec<Compact<u64>>,
-    emission: Vec<Compact<AlphaCurrency>>,
-    dividends: Vec<Compact<u16>>,
-    incentives: Vec<Compact<u16>>,
-    consensus: Vec<Compact<u16>>,
-    trust: Vec<Compact<u16>>,
-    rank: Vec<Compact<u16>>,
-    block_at_registration: Vec<Compact<u64>>,
-    alpha_stake: Vec<Compact<u64>>,
-    tao_stake: Vec<Compact<u64>>,
-    total_stake: Vec<Compact<u64>>,
-    emission_history: Vec<Vec<Compact<AlphaCurrency>>>,
+    pub netuid: Compact<NetUid>,
+    pub hotkeys: Vec<AccountId>,
+    pub coldkeys: Vec<AccountId>,
+    pub active: Vec<bool>,
+    pub validator_permit: Vec<bool>,
+    pub pruning_score: Vec<Compact<u16>>,
+    pub last_update: Vec<Compact<u64>>,
+    pub emission: Vec<Compact<AlphaCurrency>>,
+    pub dividends: Vec<Compact<u16>>,
+    pub incentives: Vec<Compact<u16>>,
+    pub consensus: Vec<Compact<u16>>,
+    pub trust: Vec<Compact<u16>>,
+    pub rank: Vec<Compact<u16>>,
+    pub block_at_registration: Vec<Compact<u64>>,
+    pub alpha_stake: Vec<Compact<u64>>,
+    pub tao_stake: Vec<Compact<u64>>,
+    pub total_stake: Vec<Compact<u64>>,
+    pub emission_history: Vec<Vec<Compact<AlphaCurrency>>>,
     // identities: Vec<ChainIdentityOf>,
     // tao_stake: Compact<u64>,
     // incentive: Compact<u16>,
@@ -7,18 +7,18 @@ use subtensor_swap_interface::SwapHandler;
 
 use super::*;
 
-#[freeze_struct("cff08b08c64eb867")]
+#[freeze_struct("40e070c7c856c9de")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct StakeInfo<AccountId: TypeInfo + Encode + Decode> {
-    hotkey: AccountId,
-    coldkey: AccountId,
-    netuid: Compact<NetUid>,
-    stake: Compact<AlphaCurrency>,
-    locked: Compact<u64>,
-    emission: Compact<AlphaCurrency>,
-    tao_emission: Compact<u64>,
-    drain: Compact<u64>,
-    is_registered: bool,
+    pub hotkey: AccountId,
+    pub coldkey: AccountId,
+    pub netuid: Compact<NetUid>,
+    pub stake: Compact<AlphaCurrency>,
+    pub locked: Compact<u64>,
+    pub emission: Compact<AlphaCurrency>,
+    pub tao_emission: Compact<u64>,
+    pub drain: Compact<u64>,
+    pub is_registered: bool,
 }
 
 impl<T: Config> Pallet<T> {
@@ -1,4 +1,5 @@
 use super::*;
+use crate::subnets::roles::{HyperparameterBound, HyperparameterGroup};
 use frame_support::pallet_prelude::{Decode, Encode};
 use frame_support::storage::IterableStorageMap;
 extern crate alloc;
@@ -6,121 +7,131 @@ use codec::Compact;
 use substrate_fixed::types::I32F32;
 use subtensor_runtime_common::NetUid;
 
-#[freeze_struct("dd2293544ffd8f2e")]
+#[freeze_struct("f7de58ae37c3c655")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SubnetInfo<AccountId: TypeInfo + Encode + Decode> {
-    netuid: Compact<NetUid>,
-    rho: Compact<u16>,
-    kappa: Compact<u16>,
-    difficulty: Compact<u64>,
-    immunity_period: Compact<u16>,
-    max_allowed_validators: Compact<u16>,
-    min_allowed_weights: Compact<u16>,
-    max_weights_limit: Compact<u16>,
-    scaling_law_power: Compact<u16>,
-    subnetwork_n: Compact<u16>,
-    max_allowed_uids: Compact<u16>,
-    blocks_since_last_step: Compact<u64>,
-    tempo: Compact<u16>,
-    network_modality: Compact<u16>,
-    network_connect: Vec<[u16; 2]>,
-    emission_values: Compact<u64>,
-    burn: Compact<u64>,
-    owner: AccountId,
+    pub netuid: Compact<NetUid>,
+    pub rho: Compact<u16>,
+    pub kappa: Compact<u16>,
+    pub difficulty: Compact<u64>,
+    pub immunity_period: Compact<u16>,
+    pub max_allowed_validators: Compact<u16>,
+    pub min_allowed_weights: Compact<u16>,
+    pub max_weights_limit: Compact<u16>,
+    pub scaling_law_power: Compact<u16>,
+    pub subnetwork_n: Compact<u16>,
+    pub max_allowed_uids: Compact<u16>,
+    pub blocks_since_last_step: Compact<u64>,
+    pub tempo: Compact<u16>,
+    pub network_modality: Compact<u16>,
+    pub network_connect: Vec<[u16; 2]>,
+    pub emission_values: Compact<u64>,
+    pub burn: Compact<u64>,
+    pub owner: AccountId,
 }
 
-#[freeze_struct("4e60a45245fc2ad1")]
+#[freeze_struct("7b81ab737da3d74f")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SubnetInfov2<AccountId: TypeInfo + Encode + Decode> {
-    netuid: Compact<NetUid>,
-    rho: Compact<u16>,
-    kappa: Compact<u16>,
-    difficulty: Compact<u64>,
-    immunity_period: Compact<u16>,
-    max_allowed_validators: Compact<u16>,
-    min_allowed_weights: Compact<u16>,
-    max_weights_limit: Compact<u16>,
-    scaling_law_power: Compact<u16>,
-    subnetwork_n: Compact<u16>,
-    max_allowed_uids: Compact<u16>,
-    blocks_since_last_step: Compact<u64>,
-    tempo: Compact<u16>,
-    network_modality: Compact<u16>,
-    network_connect: Vec<[u16; 2]>,
-    emission_value: Compact<u64>,
-    burn: Compact<u64>,
-    owner: AccountId,
-    identity: Option<SubnetIdentityV3>,
+    pub netuid: Compact<NetUid>,
+    pub rho: Compact<u16>,
+    pub kappa: Compact<u16>,
+    pub difficulty: Compact<u64>,
+    pub immunity_period: Compact<u16>,
+    pub max_allowed_validators: Compact<u16>,
+    pub min_allowed_weights: Compact<u16>,
+    pub max_weights_limit: Compact<u16>,
+    pub scaling_law_power: Compact<u16>,
+    pub subnetwork_n: Compact<u16>,
+    pub max_allowed_uids: Compact<u16>,
+    pub blocks_since_last_step: Compact<u64>,
+    pub tempo: Compact<u16>,
+    pub network_modality: Compact<u16>,
+    pub network_connect: Vec<[u16; 2]>,
+    pub emission_value: Compact<u64>,
+    pub burn: Compact<u64>,
+    pub owner: AccountId,
+    pub identity: Option<SubnetIdentityV3>,
 }
 
-#[freeze_struct("7b506df55bd44646")]
+#[freeze_struct("42a15ee89ca6a876")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SubnetHyperparams {
-    rho: Compact<u16>,
-    kappa: Compact<u16>,
-    immunity_period: Compact<u16>,
-    min_allowed_weights: Compact<u16>,
-    max_weights_limit: Compact<u16>,
-    tempo: Compact<u16>,
-    min_difficulty: Compact<u64>,
-    max_difficulty: Compact<u64>,
-    weights_version: Compact<u64>,
-    weights_rate_limit: Compact<u64>,
-    adjustment_interval: Compact<u16>,
-    activity_cutoff: Compact<u16>,
+    pub rho: Compact<u16>,
+    pub kappa: Compact<u16>,
+    pub immunity_period: Compact<u16>,
+    pub min_allowed_weights: Compact<u16>,
+    pub max_weights_limit: Compact<u16>,
+    pub tempo: Compact<u16>,
+    pub min_difficulty: Compact<u64>,
+    pub max_difficulty: Compact<u64>,
+    pub weights_version: Compact<u64>,
+    pub weights_rate_limit: Compact<u64>,
+    pub adjustment_interval: Compact<u16>,
+    pub activity_cutoff: Compact<u16>,
     pub registration_allowed: bool,
-    target_regs_per_interval: Compact<u16>,
-    min_burn: Compact<u64>,
-    max_burn: Compact<u64>,
-    bonds_moving_avg: Compact<u64>,
-    max_regs_per_block: Compact<u16>,
-    serving_rate_limit: Compact<u64>,
-    max_validators: Compact<u16>,
-    adjustment_alpha: Compact<u64>,
-    difficulty: Compact<u64>,
-    commit_reveal_period: Compact<u64>,
-    commit_reveal_weights_enabled: bool,
-    alpha_high: Compact<u16>,
-    alpha_low: Compact<u16>,
-    liquid_alpha_enabled: bool,
+    pub target_regs_per_interval: Compact<u16>,
+    pub min_burn: Compact<u64>,
+    pub max_burn: Compact<u64>,
+    pub bonds_moving_avg: Compact<u64>,
+    pub max_regs_per_block: Compact<u16>,
+    pub serving_rate_limit: Compact<u64>,
+    pub max_validators: Compact<u16>,
+    pub adjustment_alpha: Compact<u64>,
+    pub difficulty: Compact<u64>,
+    pub commit_reveal_period: Compact<u64>,
+    pub commit_reveal_weights_enabled: bool,
+    pub alpha_high: Compact<u16>,
+    pub alpha_low: Compact<u16>,
+    pub liquid_alpha_enabled: bool,
 }
 
-#[freeze_struct("a13c536303dec16f")]
+#[freeze_struct("d33ace68d5f45668")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SubnetHyperparamsV2 {
-    rho: Compact<u16>,
-    kappa: Compact<u16>,
-    immunity_period: Compact<u16>,
-    min_allowed_weights: Compact<u16>,
-    max_weights_limit: Compact<u16>,
-    tempo: Compact<u16>,
-    min_difficulty: Compact<u64>,
-    max_difficulty: Compact<u64>,
-    weights_version: Compact<u64>,
-    weights_rate_limit: Compact<u64>,
-    adjustment_interval: Compact<u16>,
-    activity_cutoff: Compact<u16>,
+    pub rho: Compact<u16>,
+    pub kappa: Compact<u16>,
+    pub immunity_period: Compact<u16>,
+    pub min_allowed_weights: Compact<u16>,
+    pub max_weights_limit: Compact<u16>,
+    pub tempo: Compact<u16>,
+    pub min_difficulty: Compact<u64>,
+    pub max_difficulty: Compact<u64>,
+    pub weights_version: Compact<u64>,
+    pub weights_rate_limit: Compact<u64>,
+    pub adjustment_interval: Compact<u16>,
+    pub activity_cutoff: Compact<u16>,
     pub registration_allowed: bool,
-    target_regs_per_interval: Compact<u16>,
-    min_burn: Compact<u64>,
-    max_burn: Compact<u64>,
-    bonds_moving_avg: Compact<u64>,
-    max_regs_per_block: Compact<u16>,
-    serving_rate_limit: Compact<u64>,
-    max_validators: Compact<u16>,
-    adjustment_alpha: Compact<u64>,
-    difficulty: Compact<u64>,
-    commit_reveal_period: Compact<u64>,
-    commit_reveal_weights_enabled: bool,
-    alpha_high: Compact<u16>,
-    alpha_low: Compact<u16>,
-    liquid_alpha_enabled: bool,
-    alpha_sigmoid_steepness: I32F32,
-    yuma_version: Compact<u16>,
-    subnet_is_active: bool,
-    transfers_enabled: bool,
-    bonds_reset_enabled: bool,
-    user_liquidity_enabled: bool,
+    pub target_regs_per_interval: Compact<u16>,
+    pub min_burn: Compact<u64>,
+    pub max_burn: Compact<u64>,
+    pub bonds_moving_avg: Compact<u64>,
+    pub max_regs_per_block: Compact<u16>,
+    pub serving_rate_limit: Compact<u64>,
+    pub max_validators: Compact<u16>,
+    pub adjustment_alpha: Compact<u64>,
+    pub difficulty: Compact<u64>,
+    pub commit_reveal_period: Compact<u64>,
+    pub commit_reveal_weights_enabled: bool,
+    pub alpha_high: Compact<u16>,
+    pub alpha_low: Compact<u16>,
+    pub liquid_alpha_enabled: bool,
+    pub alpha_sigmoid_steepness: I32F32,
+    pub yuma_version: Compact<u16>,
+    pub subnet_is_active: bool,
+    pub transfers_enabled: bool,
+    pub bonds_reset_enabled: bool,
+    pub user_liquidity_enabled: bool,
+}
+
+#[freeze_struct("8b565679bca98146")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetRoleInfo<AccountId: TypeInfo + Encode + Decode> {
+    pub delegate: AccountId,
+    pub groups: Vec<HyperparameterGroup>,
+    pub bounds: Vec<HyperparameterBound>,
+    pub expires_at: Option<u64>,
+    pub active: bool,
 }
 
 impl<T: Config> Pallet<T> {
@@ -405,4 +416,18 @@ impl<T: Config> Pallet<T> {
             user_liquidity_enabled,
         })
     }
+
+    pub fn get_subnet_roles(netuid: NetUid) -> Vec<SubnetRoleInfo<T::AccountId>> {
+        let current_block = Self::get_current_block_as_u64();
+
+        SubnetRoles::<T>::iter_prefix(netuid)
+            .map(|(delegate, role)| SubnetRoleInfo {
+                delegate,
+                active: role.is_active(current_block),
+                groups: role.groups.into_inner(),
+                bounds: role.bounds.into_inner(),
+                expires_at: role.expires_at,
+            })
+            .collect()
+    }
 }
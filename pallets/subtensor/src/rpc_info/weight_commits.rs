@@ -0,0 +1,89 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+use sp_core::H256;
+use subtensor_runtime_common::NetUid;
+
+/// How a pending weights commit gets revealed.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub enum WeightCommitKind {
+    /// A hash revealed by the hotkey with `reveal_weights` within the reveal window.
+    Hash(H256),
+    /// A timelocked commit revealed by the chain at the epoch, once the drand round is on chain.
+    Timelocked { reveal_round: u64 },
+}
+
+#[freeze_struct("32b3a2e4ee276ed8")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct WeightCommitInfo<AccountId: TypeInfo + Encode + Decode> {
+    pub hotkey: AccountId,
+    pub netuid: Compact<NetUid>,
+    pub kind: WeightCommitKind,
+    pub commit_block: Compact<u64>,
+    pub reveal_epoch: Compact<u64>,
+    pub first_reveal_block: Compact<u64>,
+    pub last_reveal_block: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the weights commits of a subnet that are still waiting to be revealed,
+    /// optionally only those of one hotkey.
+    pub fn get_weight_commits(
+        netuid: NetUid,
+        hotkey: Option<T::AccountId>,
+    ) -> Vec<WeightCommitInfo<T::AccountId>> {
+        let current_block = Self::get_current_block_as_u64();
+        let reveal_period = Self::get_reveal_period(netuid);
+
+        let hash_commits = WeightCommits::<T>::iter_prefix(netuid).flat_map(|(who, commits)| {
+            commits.into_iter().map(
+                move |(hash, commit_block, first_reveal_block, last_reveal_block)| {
+                    WeightCommitInfo {
+                        hotkey: who.clone(),
+                        netuid: netuid.into(),
+                        kind: WeightCommitKind::Hash(hash),
+                        commit_block: commit_block.into(),
+                        reveal_epoch: Self::get_epoch_index(netuid, first_reveal_block).into(),
+                        first_reveal_block: first_reveal_block.into(),
+                        last_reveal_block: last_reveal_block.into(),
+                    }
+                },
+            )
+        });
+
+        // Commits of epoch `e` are revealed by the epoch run on the last block of epoch
+        // `e + reveal_period - 1`, see `reveal_crv3_commits`.
+        let timelocked_commits =
+            CRV3WeightCommitsV2::<T>::iter_prefix(netuid).flat_map(|(commit_epoch, commits)| {
+                let reveal_epoch = commit_epoch.saturating_add(reveal_period).saturating_sub(1);
+                let reveal_block =
+                    Self::get_first_block_of_epoch(netuid, reveal_epoch.saturating_add(1))
+                        .saturating_sub(1);
+
+                commits
+                    .into_iter()
+                    .map(
+                        move |(who, commit_block, _, reveal_round)| WeightCommitInfo {
+                            hotkey: who,
+                            netuid: netuid.into(),
+                            kind: WeightCommitKind::Timelocked { reveal_round },
+                            commit_block: commit_block.into(),
+                            reveal_epoch: reveal_epoch.into(),
+                            first_reveal_block: reveal_block.into(),
+                            last_reveal_block: reveal_block.into(),
+                        },
+                    )
+            });
+
+        hash_commits
+            .chain(timelocked_commits)
+            .filter(|commit| commit.last_reveal_block.0 >= current_block)
+            .filter(|commit| {
+                hotkey
+                    .as_ref()
+                    .is_none_or(|hotkey| *hotkey == commit.hotkey)
+            })
+            .collect()
+    }
+}
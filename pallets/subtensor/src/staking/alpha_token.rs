@@ -0,0 +1,166 @@
+use super::*;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+impl<T: Config> Pallet<T> {
+    /// Sets the hotkey whose stake backs the caller's alpha token balance on the subnet.
+    pub fn do_set_alpha_token_hotkey(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        AlphaTokenHotkey::<T>::insert(netuid, &coldkey, &hotkey);
+
+        Self::deposit_event(Event::AlphaTokenHotkeySet {
+            netuid,
+            coldkey,
+            hotkey,
+        });
+
+        Ok(())
+    }
+
+    /// Allows `spender` to transfer up to `amount` of the caller's alpha tokens on the subnet,
+    /// replacing the previous allowance. An allowance of `AlphaCurrency::MAX` is infinite.
+    pub fn do_approve_alpha_token(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        spender: T::AccountId,
+        amount: AlphaCurrency,
+    ) -> DispatchResult {
+        let owner = ensure_signed(origin)?;
+
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+
+        AlphaTokenAllowance::<T>::insert((netuid, &owner, &spender), amount);
+
+        Self::deposit_event(Event::AlphaTokenApproved {
+            netuid,
+            owner,
+            spender,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Transfers `amount` of the caller's alpha tokens on the subnet to `destination_coldkey`.
+    pub fn do_transfer_alpha_token(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        destination_coldkey: T::AccountId,
+        amount: AlphaCurrency,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        Self::move_alpha_tokens(netuid, &coldkey, &destination_coldkey, amount)
+    }
+
+    /// Transfers `amount` of `owner`'s alpha tokens on the subnet to `destination_coldkey`,
+    /// spending the allowance `owner` gave the caller.
+    pub fn do_transfer_alpha_token_from(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        owner: T::AccountId,
+        destination_coldkey: T::AccountId,
+        amount: AlphaCurrency,
+    ) -> DispatchResult {
+        let spender = ensure_signed(origin)?;
+
+        let allowance = AlphaTokenAllowance::<T>::get((netuid, &owner, &spender));
+        ensure!(
+            allowance >= amount,
+            Error::<T>::InsufficientAlphaTokenAllowance
+        );
+
+        Self::move_alpha_tokens(netuid, &owner, &destination_coldkey, amount)?;
+
+        if allowance != AlphaCurrency::MAX {
+            AlphaTokenAllowance::<T>::insert(
+                (netuid, &owner, &spender),
+                allowance.saturating_sub(amount),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns the hotkey backing the alpha tokens of a coldkey on the subnet: the one it chose,
+    /// otherwise the first hotkey it holds stake on there, such as the hotkey of a transfer it
+    /// received.
+    pub fn get_alpha_token_hotkey(netuid: NetUid, coldkey: &T::AccountId) -> Option<T::AccountId> {
+        AlphaTokenHotkey::<T>::get(netuid, coldkey).or_else(|| {
+            StakingHotkeys::<T>::get(coldkey)
+                .into_iter()
+                .find(|hotkey| {
+                    !Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid)
+                        .is_zero()
+                })
+        })
+    }
+
+    /// Returns the alpha token balance of a coldkey on the subnet: its stake on the hotkey backing
+    /// its tokens, zero when it has none.
+    pub fn get_alpha_token_balance(netuid: NetUid, coldkey: &T::AccountId) -> AlphaCurrency {
+        Self::get_alpha_token_hotkey(netuid, coldkey)
+            .map(|hotkey| {
+                Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, coldkey, netuid)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Transfers alpha stake on the hotkey backing the owner's tokens to the destination coldkey,
+    /// keeping the hotkey as `transfer_stake` does. A destination backing its tokens with another
+    /// hotkey holds the received stake outside of its token balance. Zero amounts move nothing
+    /// but are still reported, as ERC-20 requires.
+    fn move_alpha_tokens(
+        netuid: NetUid,
+        owner: &T::AccountId,
+        destination_coldkey: &T::AccountId,
+        amount: AlphaCurrency,
+    ) -> DispatchResult {
+        if amount.is_zero() {
+            Self::deposit_event(Event::AlphaTokenTransferred {
+                netuid,
+                from: owner.clone(),
+                to: destination_coldkey.clone(),
+                amount,
+            });
+
+            return Ok(());
+        }
+
+        let hotkey = Self::get_alpha_token_hotkey(netuid, owner)
+            .ok_or(Error::<T>::AlphaTokenHotkeyNotSet)?;
+
+        Self::transition_stake_internal(
+            owner,
+            destination_coldkey,
+            &hotkey,
+            &hotkey,
+            netuid,
+            netuid,
+            amount,
+            None,
+            None,
+            true,
+            false,
+        )?;
+
+        Self::deposit_event(Event::AlphaTokenTransferred {
+            netuid,
+            from: owner.clone(),
+            to: destination_coldkey.clone(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use super::*;
 pub mod account;
 pub mod add_stake;
+pub mod alpha_token;
 pub mod decrease_take;
 pub mod helpers;
 pub mod increase_take;
@@ -315,7 +315,7 @@ impl<T: Config> Pallet<T> {
 
     // If limit_price is None, this is a regular operation, otherwise, it is slippage-protected
     // by setting limit price between origin_netuid and destination_netuid token
-    fn transition_stake_internal(
+    pub(crate) fn transition_stake_internal(
         origin_coldkey: &T::AccountId,
         destination_coldkey: &T::AccountId,
         origin_hotkey: &T::AccountId,
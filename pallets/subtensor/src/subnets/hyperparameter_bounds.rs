@@ -0,0 +1,32 @@
+use super::*;
+use crate::subnets::roles::{Hyperparameter, HyperparameterBound};
+
+impl<T: Config> Pallet<T> {
+    /// Ensures `value` is within the range root allows subnet owners to set for `param`.
+    pub fn ensure_hyperparameter_within_bounds(
+        param: Hyperparameter,
+        value: u64,
+    ) -> Result<(), Error<T>> {
+        if let Some((min, max)) = HyperparameterBounds::<T>::get(param) {
+            ensure!(
+                value >= min && value <= max,
+                Error::<T>::ValueOutOfBounds { param }
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Sets the inclusive range subnet owners may use for `param`. `None` removes the range.
+    pub fn set_hyperparameter_bounds(param: Hyperparameter, bounds: Option<(u64, u64)>) {
+        HyperparameterBounds::<T>::set(param, bounds);
+        Self::deposit_event(Event::HyperparameterBoundsSet { param, bounds });
+    }
+
+    /// Returns the ranges root allows subnet owners to set.
+    pub fn get_hyperparameter_bounds() -> Vec<HyperparameterBound> {
+        HyperparameterBounds::<T>::iter()
+            .map(|(param, (min, max))| HyperparameterBound { param, min, max })
+            .collect()
+    }
+}
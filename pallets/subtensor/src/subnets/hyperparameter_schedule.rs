@@ -0,0 +1,431 @@
+use super::*;
+use crate::subnets::roles::Hyperparameter;
+use frame_support::pallet_prelude::BoundedVec;
+use sp_core::ConstU32;
+use subtensor_runtime_common::NetUid;
+
+/// Maximum number of pending hyperparameter changes per subnet.
+pub const MAX_SCHEDULED_HYPERPARAMETER_CHANGES: u32 = 32;
+
+/// A hyperparameter change queued by a subnet owner, applied at `effective_block`.
+#[freeze_struct("80b19b18de5f9a0f")]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ScheduledHyperparameterChange {
+    /// The hyperparameter being changed.
+    pub param: Hyperparameter,
+    /// The new value of the hyperparameter.
+    pub value: u64,
+    /// The block at which the change was queued.
+    pub scheduled_at: u64,
+    /// The block at which the change takes effect.
+    pub effective_block: u64,
+    /// Whether root queued the change. Other changes are checked against the bounds and rate
+    /// limits of subnet owners when they take effect.
+    pub by_root: bool,
+}
+
+pub type ScheduledHyperparameterChanges =
+    BoundedVec<ScheduledHyperparameterChange, ConstU32<MAX_SCHEDULED_HYPERPARAMETER_CHANGES>>;
+
+impl<T: Config> Pallet<T> {
+    /// Queues a change of `param` to `value` on the subnet, taking effect at `effective_block`.
+    ///
+    /// The effective block must be in the future and respect the minimum notice set by root
+    /// for the hyperparameter. A pending change for the same hyperparameter is replaced.
+    pub fn do_schedule_hyperparameter_change(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        param: Hyperparameter,
+        value: u64,
+        effective_block: u64,
+    ) -> DispatchResult {
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        let is_root = Self::ensure_hyperparameter_rights(origin, netuid, param, value)?.is_none();
+        Self::ensure_valid_hyperparameter_value(param, value, is_root)?;
+        if !is_root {
+            Self::ensure_hyperparameter_within_bounds(param, value)?;
+        }
+
+        let current_block = Self::get_current_block_as_u64();
+        let min_notice = HyperparameterMinNotice::<T>::get(param).max(1);
+        ensure!(
+            effective_block >= current_block.saturating_add(min_notice),
+            Error::<T>::HyperparameterNoticeTooShort
+        );
+
+        let change = ScheduledHyperparameterChange {
+            param,
+            value,
+            scheduled_at: current_block,
+            effective_block,
+            by_root: is_root,
+        };
+        PendingHyperparameterChanges::<T>::try_mutate(netuid, |changes| -> DispatchResult {
+            if let Some(index) = changes.iter().position(|pending| pending.param == param) {
+                let replaced = changes.remove(index);
+                Self::unindex_hyperparameter_change(netuid, changes, replaced.effective_block);
+            }
+            changes
+                .try_push(change)
+                .map_err(|_| Error::<T>::TooManyScheduledHyperparameterChanges)?;
+            Self::ensure_consistent_alpha_values(netuid, changes, param, effective_block)?;
+            HyperparameterChangesDue::<T>::insert(effective_block, netuid, true);
+            Ok(())
+        })?;
+
+        Self::deposit_event(Event::HyperparameterChangeScheduled {
+            netuid,
+            param,
+            value,
+            effective_block,
+        });
+
+        Ok(())
+    }
+
+    /// Cancels the pending change of `param` on the subnet.
+    pub fn do_cancel_hyperparameter_change(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        param: Hyperparameter,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+
+        PendingHyperparameterChanges::<T>::try_mutate_exists(netuid, |maybe_changes| {
+            let changes = maybe_changes
+                .as_mut()
+                .ok_or(Error::<T>::ScheduledHyperparameterChangeNotFound)?;
+            let index = changes
+                .iter()
+                .position(|pending| pending.param == param)
+                .ok_or(Error::<T>::ScheduledHyperparameterChangeNotFound)?;
+            let cancelled = changes.remove(index);
+            Self::unindex_hyperparameter_change(netuid, changes, cancelled.effective_block);
+            if changes.is_empty() {
+                *maybe_changes = None;
+            }
+            Ok::<(), Error<T>>(())
+        })?;
+
+        Self::deposit_event(Event::HyperparameterChangeCancelled { netuid, param });
+
+        Ok(())
+    }
+
+    /// Sets the minimum number of blocks of notice required to change `param`.
+    ///
+    /// A non-zero notice prevents subnet owners from changing the hyperparameter immediately.
+    pub fn set_hyperparameter_min_notice(param: Hyperparameter, blocks: u64) {
+        if blocks == 0 {
+            HyperparameterMinNotice::<T>::remove(param);
+        } else {
+            HyperparameterMinNotice::<T>::insert(param, blocks);
+        }
+        Self::deposit_event(Event::HyperparameterMinNoticeSet { param, blocks });
+    }
+
+    /// Returns the pending hyperparameter changes of the subnet.
+    pub fn get_scheduled_hyperparameter_changes(
+        netuid: NetUid,
+    ) -> Vec<ScheduledHyperparameterChange> {
+        PendingHyperparameterChanges::<T>::get(netuid).into_inner()
+    }
+
+    /// Cancels the pending changes queued by the subnet owner and its delegates. Called when
+    /// the subnet changes hands, so the new owner doesn't inherit them.
+    pub fn cancel_owner_hyperparameter_changes(netuid: NetUid) {
+        let changes = PendingHyperparameterChanges::<T>::take(netuid);
+        let (cancelled, kept): (Vec<_>, Vec<_>) =
+            changes.into_iter().partition(|change| !change.by_root);
+
+        for change in cancelled {
+            Self::unindex_hyperparameter_change(netuid, &kept, change.effective_block);
+            Self::deposit_event(Event::HyperparameterChangeCancelled {
+                netuid,
+                param: change.param,
+            });
+        }
+
+        if !kept.is_empty() {
+            PendingHyperparameterChanges::<T>::insert(
+                netuid,
+                ScheduledHyperparameterChanges::truncate_from(kept),
+            );
+        }
+    }
+
+    /// Drops every pending change of the subnet without applying it.
+    pub fn clear_scheduled_hyperparameter_changes(netuid: NetUid) {
+        for change in PendingHyperparameterChanges::<T>::take(netuid) {
+            HyperparameterChangesDue::<T>::remove(change.effective_block, netuid);
+        }
+    }
+
+    /// Applies every pending hyperparameter change that is due at the current block.
+    ///
+    /// Changes are checked again before they are applied, since other hyperparameters, the
+    /// bounds and the rate limits may have changed since they were queued. A change that no
+    /// longer passes is dropped with a `HyperparameterChangeFailed` event.
+    pub fn apply_scheduled_hyperparameter_changes(current_block: u64) -> Weight {
+        let mut weight = Weight::from_parts(0, 0);
+
+        let netuids: Vec<NetUid> = HyperparameterChangesDue::<T>::drain_prefix(current_block)
+            .map(|(netuid, _)| netuid)
+            .collect();
+        weight.saturating_accrue(
+            T::DbWeight::get().reads_writes(netuids.len() as u64, netuids.len() as u64),
+        );
+
+        for netuid in netuids {
+            let (due, remaining): (Vec<_>, Vec<_>) =
+                PendingHyperparameterChanges::<T>::take(netuid)
+                    .into_iter()
+                    .partition(|change| change.effective_block <= current_block);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+
+            if Self::if_subnet_exist(netuid) {
+                for change in due.iter() {
+                    let result = Self::ensure_scheduled_change_still_valid(netuid, change, &due)
+                        .and_then(|()| {
+                            Self::apply_hyperparameter(netuid, change.param, change.value)
+                        });
+
+                    match result {
+                        Ok(()) => {
+                            if change.param == Hyperparameter::WeightsVersionKey && !change.by_root
+                            {
+                                Self::set_last_transaction_block_on_subnet(
+                                    &SubnetOwner::<T>::get(netuid),
+                                    netuid,
+                                    &TransactionType::SetWeightsVersionKey,
+                                    current_block,
+                                );
+                            }
+                            Self::deposit_event(Event::HyperparameterChangeApplied {
+                                netuid,
+                                param: change.param,
+                                value: change.value,
+                            });
+                        }
+                        Err(error) => {
+                            Self::deposit_event(Event::HyperparameterChangeFailed {
+                                netuid,
+                                param: change.param,
+                                value: change.value,
+                                error,
+                            });
+                        }
+                    }
+                    weight.saturating_accrue(T::DbWeight::get().reads_writes(6, 2));
+                }
+            }
+
+            if !remaining.is_empty() {
+                PendingHyperparameterChanges::<T>::insert(
+                    netuid,
+                    ScheduledHyperparameterChanges::truncate_from(remaining),
+                );
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+        }
+
+        weight
+    }
+
+    /// Removes the due-block index entry of a change, unless another pending change of the
+    /// subnet takes effect at the same block.
+    fn unindex_hyperparameter_change(
+        netuid: NetUid,
+        remaining: &[ScheduledHyperparameterChange],
+        effective_block: u64,
+    ) {
+        if !remaining
+            .iter()
+            .any(|change| change.effective_block == effective_block)
+        {
+            HyperparameterChangesDue::<T>::remove(effective_block, netuid);
+        }
+    }
+
+    /// Repeats the checks of [`Self::do_schedule_hyperparameter_change`] against the current
+    /// state, and applies the rate limits subnet owners are subject to.
+    fn ensure_scheduled_change_still_valid(
+        netuid: NetUid,
+        change: &ScheduledHyperparameterChange,
+        due: &[ScheduledHyperparameterChange],
+    ) -> DispatchResult {
+        Self::ensure_valid_hyperparameter_value(change.param, change.value, change.by_root)?;
+        Self::ensure_consistent_alpha_values(netuid, due, change.param, change.effective_block)?;
+
+        if !change.by_root {
+            Self::ensure_hyperparameter_within_bounds(change.param, change.value)?;
+
+            if change.param == Hyperparameter::WeightsVersionKey {
+                ensure!(
+                    Self::passes_rate_limit_on_subnet(
+                        &TransactionType::SetWeightsVersionKey,
+                        &SubnetOwner::<T>::get(netuid),
+                        netuid,
+                    ),
+                    Error::<T>::TxRateLimitExceeded
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks a change of the liquid alpha values the way `do_set_alpha_values` does, against the
+    /// values the subnet will have at `block` given the `changes` due by then.
+    fn ensure_consistent_alpha_values(
+        netuid: NetUid,
+        changes: &[ScheduledHyperparameterChange],
+        param: Hyperparameter,
+        block: u64,
+    ) -> DispatchResult {
+        if !matches!(param, Hyperparameter::AlphaLow | Hyperparameter::AlphaHigh) {
+            return Ok(());
+        }
+
+        let value_at = |param: Hyperparameter| {
+            changes
+                .iter()
+                .find(|change| change.param == param && change.effective_block <= block)
+                .map(|change| change.value)
+                .unwrap_or_else(|| Self::hyperparameter_value(netuid, param))
+        };
+        let as_u16 = |value: u64| u16::try_from(value).unwrap_or(u16::MAX);
+
+        Self::ensure_valid_alpha_values(
+            value_at(Hyperparameter::LiquidAlphaEnabled) != 0,
+            as_u16(value_at(Hyperparameter::AlphaLow)),
+            as_u16(value_at(Hyperparameter::AlphaHigh)),
+        )
+    }
+
+    /// Checks that `value` is a valid setting for `param`, using the same limits as the
+    /// immediate setters.
+    pub(crate) fn ensure_valid_hyperparameter_value(
+        param: Hyperparameter,
+        value: u64,
+        is_root: bool,
+    ) -> DispatchResult {
+        let valid = match param {
+            Hyperparameter::ServingRateLimit
+            | Hyperparameter::MaxDifficulty
+            | Hyperparameter::WeightsVersionKey
+            | Hyperparameter::AdjustmentAlpha => true,
+            Hyperparameter::BondsMovingAverage => {
+                is_root || value <= MAX_OWNER_BONDS_MOVING_AVERAGE
+            }
+            Hyperparameter::CommitRevealWeightsInterval => value <= MAX_COMMIT_REVEAL_PERIODS,
+            Hyperparameter::ActivityCutoff => {
+                u16::try_from(value).is_ok_and(|cutoff| cutoff >= MinActivityCutoff::<T>::get())
+            }
+            Hyperparameter::AlphaSigmoidSteepness => {
+                i16::try_from(value as i64).is_ok_and(|steepness| is_root || steepness >= 0)
+            }
+            Hyperparameter::NetworkPowRegistrationAllowed
+            | Hyperparameter::CommitRevealWeightsEnabled
+            | Hyperparameter::LiquidAlphaEnabled
+            | Hyperparameter::TransferEnabled
+            | Hyperparameter::Yuma3Enabled
+            | Hyperparameter::BondsResetEnabled => value <= 1,
+            Hyperparameter::MaxWeightLimit
+            | Hyperparameter::ImmunityPeriod
+            | Hyperparameter::MinAllowedWeights
+            | Hyperparameter::Kappa
+            | Hyperparameter::Rho
+            | Hyperparameter::BondsPenalty => u16::try_from(value).is_ok(),
+            Hyperparameter::AlphaLow | Hyperparameter::AlphaHigh => {
+                u16::try_from(value).is_ok_and(|alpha| alpha >= MIN_LIQUID_ALPHA)
+            }
+        };
+        ensure!(valid, Error::<T>::InvalidHyperparameterValue);
+
+        Ok(())
+    }
+
+    /// Writes a validated hyperparameter value to storage.
+    pub(crate) fn apply_hyperparameter(
+        netuid: NetUid,
+        param: Hyperparameter,
+        value: u64,
+    ) -> DispatchResult {
+        let as_u16 = || u16::try_from(value).unwrap_or(u16::MAX);
+        let as_bool = value != 0;
+
+        match param {
+            Hyperparameter::ServingRateLimit => Self::set_serving_rate_limit(netuid, value),
+            Hyperparameter::MaxDifficulty => Self::set_max_difficulty(netuid, value),
+            Hyperparameter::WeightsVersionKey => Self::set_weights_version_key(netuid, value),
+            Hyperparameter::AdjustmentAlpha => Self::set_adjustment_alpha(netuid, value),
+            Hyperparameter::MaxWeightLimit => Self::set_max_weight_limit(netuid, as_u16()),
+            Hyperparameter::ImmunityPeriod => Self::set_immunity_period(netuid, as_u16()),
+            Hyperparameter::MinAllowedWeights => Self::set_min_allowed_weights(netuid, as_u16()),
+            Hyperparameter::Kappa => Self::set_kappa(netuid, as_u16()),
+            Hyperparameter::Rho => Self::set_rho(netuid, as_u16()),
+            Hyperparameter::ActivityCutoff => Self::set_activity_cutoff(netuid, as_u16()),
+            Hyperparameter::NetworkPowRegistrationAllowed => {
+                Self::set_network_pow_registration_allowed(netuid, as_bool)
+            }
+            Hyperparameter::BondsMovingAverage => Self::set_bonds_moving_average(netuid, value),
+            Hyperparameter::BondsPenalty => Self::set_bonds_penalty(netuid, as_u16()),
+            Hyperparameter::CommitRevealWeightsEnabled => {
+                Self::set_commit_reveal_weights_enabled(netuid, as_bool)
+            }
+            Hyperparameter::LiquidAlphaEnabled => Self::set_liquid_alpha_enabled(netuid, as_bool),
+            Hyperparameter::AlphaLow => {
+                AlphaValues::<T>::mutate(netuid, |(low, _)| *low = as_u16())
+            }
+            Hyperparameter::AlphaHigh => {
+                AlphaValues::<T>::mutate(netuid, |(_, high)| *high = as_u16())
+            }
+            Hyperparameter::CommitRevealWeightsInterval => Self::set_reveal_period(netuid, value),
+            Hyperparameter::TransferEnabled => Self::toggle_transfer(netuid, as_bool)?,
+            Hyperparameter::AlphaSigmoidSteepness => {
+                let steepness = i16::try_from(value as i64)
+                    .map_err(|_| Error::<T>::InvalidHyperparameterValue)?;
+                Self::set_alpha_sigmoid_steepness(netuid, steepness)
+            }
+            Hyperparameter::Yuma3Enabled => Self::set_yuma3_enabled(netuid, as_bool),
+            Hyperparameter::BondsResetEnabled => Self::set_bonds_reset(netuid, as_bool),
+        }
+
+        Ok(())
+    }
+
+    /// Reads a hyperparameter in the encoding taken by [`Self::apply_hyperparameter`].
+    pub(crate) fn hyperparameter_value(netuid: NetUid, param: Hyperparameter) -> u64 {
+        match param {
+            Hyperparameter::ServingRateLimit => Self::get_serving_rate_limit(netuid),
+            Hyperparameter::MaxDifficulty => Self::get_max_difficulty(netuid),
+            Hyperparameter::WeightsVersionKey => Self::get_weights_version_key(netuid),
+            Hyperparameter::AdjustmentAlpha => Self::get_adjustment_alpha(netuid),
+            Hyperparameter::MaxWeightLimit => Self::get_max_weight_limit(netuid).into(),
+            Hyperparameter::ImmunityPeriod => Self::get_immunity_period(netuid).into(),
+            Hyperparameter::MinAllowedWeights => Self::get_min_allowed_weights(netuid).into(),
+            Hyperparameter::Kappa => Self::get_kappa(netuid).into(),
+            Hyperparameter::Rho => Self::get_rho(netuid).into(),
+            Hyperparameter::ActivityCutoff => Self::get_activity_cutoff(netuid).into(),
+            Hyperparameter::NetworkPowRegistrationAllowed => {
+                Self::get_network_pow_registration_allowed(netuid).into()
+            }
+            Hyperparameter::BondsMovingAverage => Self::get_bonds_moving_average(netuid),
+            Hyperparameter::BondsPenalty => Self::get_bonds_penalty(netuid).into(),
+            Hyperparameter::CommitRevealWeightsEnabled => {
+                Self::get_commit_reveal_weights_enabled(netuid).into()
+            }
+            Hyperparameter::LiquidAlphaEnabled => Self::get_liquid_alpha_enabled(netuid).into(),
+            Hyperparameter::AlphaLow => AlphaValues::<T>::get(netuid).0.into(),
+            Hyperparameter::AlphaHigh => AlphaValues::<T>::get(netuid).1.into(),
+            Hyperparameter::CommitRevealWeightsInterval => Self::get_reveal_period(netuid),
+            Hyperparameter::TransferEnabled => TransferToggle::<T>::get(netuid).into(),
+            Hyperparameter::AlphaSigmoidSteepness => {
+                i64::from(AlphaSigmoidSteepness::<T>::get(netuid)) as u64
+            }
+            Hyperparameter::Yuma3Enabled => Self::get_yuma3_enabled(netuid).into(),
+            Hyperparameter::BondsResetEnabled => Self::get_bonds_reset(netuid).into(),
+        }
+    }
+}
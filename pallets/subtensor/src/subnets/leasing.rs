@@ -199,6 +199,8 @@ impl<T: Config> Pallet<T> {
         );
         SubnetOwner::<T>::insert(lease.netuid, lease.beneficiary.clone());
         Self::set_subnet_owner_hotkey(lease.netuid, &hotkey);
+        Self::cancel_owner_hyperparameter_changes(lease.netuid);
+        let _ = SubnetRoles::<T>::clear_prefix(lease.netuid, u32::MAX, None);
 
         // Stop tracking the lease coldkey and hotkey
         let _ = frame_system::Pallet::<T>::dec_providers(&lease.coldkey).defensive();
@@ -1,8 +1,12 @@
 use super::*;
+pub mod hyperparameter_bounds;
+pub mod hyperparameter_schedule;
 pub mod leasing;
 pub mod registration;
+pub mod roles;
 pub mod serving;
 pub mod subnet;
 pub mod symbols;
 pub mod uids;
 pub mod weights;
+pub mod weights_key;
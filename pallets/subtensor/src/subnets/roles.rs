@@ -0,0 +1,296 @@
+use super::*;
+use frame_support::{PalletError, pallet_prelude::BoundedVec};
+use frame_system::ensure_signed_or_root;
+use sp_core::ConstU32;
+use subtensor_runtime_common::NetUid;
+
+/// Maximum number of hyperparameter groups a single role can cover.
+pub const MAX_SUBNET_ROLE_GROUPS: u32 = 8;
+
+/// Maximum number of per-hyperparameter bounds attached to a single role.
+pub const MAX_SUBNET_ROLE_BOUNDS: u32 = 32;
+
+/// A subnet hyperparameter that the subnet owner is allowed to change.
+///
+/// Settings only root may change, such as the tempo, are not listed: they cannot be delegated
+/// or scheduled, and root changes them directly through the admin pallet.
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    PalletError,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum Hyperparameter {
+    ServingRateLimit,
+    MaxDifficulty,
+    WeightsVersionKey,
+    AdjustmentAlpha,
+    MaxWeightLimit,
+    ImmunityPeriod,
+    MinAllowedWeights,
+    Kappa,
+    Rho,
+    ActivityCutoff,
+    NetworkPowRegistrationAllowed,
+    BondsMovingAverage,
+    BondsPenalty,
+    CommitRevealWeightsEnabled,
+    LiquidAlphaEnabled,
+    AlphaLow,
+    AlphaHigh,
+    CommitRevealWeightsInterval,
+    TransferEnabled,
+    /// Carried as the two's complement of the signed steepness, so `u64::MAX` is `-1`. Only
+    /// root may use negative values.
+    AlphaSigmoidSteepness,
+    Yuma3Enabled,
+    BondsResetEnabled,
+}
+
+/// A named set of hyperparameters that can be delegated as a whole.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum HyperparameterGroup {
+    /// Registration terms: difficulty, immunity period and PoW registration.
+    Registration,
+    /// Weights settings: version key, limits and commit-reveal.
+    Weights,
+    /// Consensus settings: kappa, rho, bonds and liquid alpha.
+    Consensus,
+    /// Network settings: serving rate limit and alpha transfers.
+    Network,
+}
+
+impl Hyperparameter {
+    /// Every hyperparameter, in declaration order.
+    pub const ALL: [Self; 22] = [
+        Self::ServingRateLimit,
+        Self::MaxDifficulty,
+        Self::WeightsVersionKey,
+        Self::AdjustmentAlpha,
+        Self::MaxWeightLimit,
+        Self::ImmunityPeriod,
+        Self::MinAllowedWeights,
+        Self::Kappa,
+        Self::Rho,
+        Self::ActivityCutoff,
+        Self::NetworkPowRegistrationAllowed,
+        Self::BondsMovingAverage,
+        Self::BondsPenalty,
+        Self::CommitRevealWeightsEnabled,
+        Self::LiquidAlphaEnabled,
+        Self::AlphaLow,
+        Self::AlphaHigh,
+        Self::CommitRevealWeightsInterval,
+        Self::TransferEnabled,
+        Self::AlphaSigmoidSteepness,
+        Self::Yuma3Enabled,
+        Self::BondsResetEnabled,
+    ];
+
+    /// Returns the group this hyperparameter belongs to.
+    pub fn group(&self) -> HyperparameterGroup {
+        match self {
+            Self::MaxDifficulty | Self::ImmunityPeriod | Self::NetworkPowRegistrationAllowed => {
+                HyperparameterGroup::Registration
+            }
+            Self::WeightsVersionKey
+            | Self::MaxWeightLimit
+            | Self::MinAllowedWeights
+            | Self::CommitRevealWeightsEnabled
+            | Self::CommitRevealWeightsInterval => HyperparameterGroup::Weights,
+            Self::AdjustmentAlpha
+            | Self::Kappa
+            | Self::Rho
+            | Self::ActivityCutoff
+            | Self::BondsMovingAverage
+            | Self::BondsPenalty
+            | Self::LiquidAlphaEnabled
+            | Self::AlphaLow
+            | Self::AlphaHigh
+            | Self::AlphaSigmoidSteepness
+            | Self::Yuma3Enabled
+            | Self::BondsResetEnabled => HyperparameterGroup::Consensus,
+            Self::ServingRateLimit | Self::TransferEnabled => HyperparameterGroup::Network,
+        }
+    }
+}
+
+/// Inclusive bounds a delegate must respect when changing a hyperparameter.
+#[freeze_struct("33532333d5a9818d")]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HyperparameterBound {
+    /// The bounded hyperparameter.
+    pub param: Hyperparameter,
+    /// The lowest value the delegate may set.
+    pub min: u64,
+    /// The highest value the delegate may set.
+    pub max: u64,
+}
+
+/// Hyperparameter rights granted by a subnet owner to another account.
+#[freeze_struct("fd570d8aec3fdf")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SubnetRole {
+    /// The hyperparameter groups the delegate may change.
+    pub groups: BoundedVec<HyperparameterGroup, ConstU32<MAX_SUBNET_ROLE_GROUPS>>,
+    /// Optional bounds on the values the delegate may set.
+    pub bounds: BoundedVec<HyperparameterBound, ConstU32<MAX_SUBNET_ROLE_BOUNDS>>,
+    /// The block at which the role expires. If not defined, the role is perpetual.
+    pub expires_at: Option<u64>,
+}
+
+impl SubnetRole {
+    /// Returns true if the role is still active at the given block.
+    pub fn is_active(&self, current_block: u64) -> bool {
+        self.expires_at
+            .is_none_or(|expires_at| current_block < expires_at)
+    }
+
+    /// Returns true if the role covers the given hyperparameter.
+    pub fn covers(&self, param: Hyperparameter) -> bool {
+        self.groups.contains(&param.group())
+    }
+
+    /// Returns true if the value respects the bound configured for the hyperparameter, if any.
+    pub fn allows_value(&self, param: Hyperparameter, value: u64) -> bool {
+        self.bounds
+            .iter()
+            .filter(|bound| bound.param == param)
+            .all(|bound| value >= bound.min && value <= bound.max)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Ensures the origin may immediately set `param` to `value` on the subnet.
+    ///
+    /// Root is always allowed. The subnet owner and its delegates must have the rights
+    /// checked by [`Self::ensure_hyperparameter_rights`], must stay within the bounds root
+    /// set for `param`, and must go through the hyperparameter schedule if root requires a
+    /// minimum notice for `param`.
+    pub fn ensure_subnet_owner_or_root_or_role(
+        o: T::RuntimeOrigin,
+        netuid: NetUid,
+        param: Hyperparameter,
+        value: u64,
+    ) -> Result<(), DispatchError> {
+        if Self::ensure_hyperparameter_rights(o, netuid, param, value)?.is_some() {
+            Self::ensure_hyperparameter_within_bounds(param, value)?;
+            ensure!(
+                HyperparameterMinNotice::<T>::get(param) == 0,
+                Error::<T>::HyperparameterNoticeRequired
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Ensures the origin has the right to set `param` to `value` on the subnet.
+    ///
+    /// Root and the subnet owner are always allowed. Any other signed origin must hold an
+    /// active role on the subnet covering the hyperparameter, and the value must be within
+    /// the bounds of the role. Returns the signer, or `None` for root.
+    pub fn ensure_hyperparameter_rights(
+        o: T::RuntimeOrigin,
+        netuid: NetUid,
+        param: Hyperparameter,
+        value: u64,
+    ) -> Result<Option<T::AccountId>, DispatchError> {
+        let Some(who) = ensure_signed_or_root(o)? else {
+            return Ok(None);
+        };
+
+        if SubnetOwner::<T>::get(netuid) == who {
+            return Ok(Some(who));
+        }
+
+        let role = SubnetRoles::<T>::get(netuid, &who).ok_or(DispatchError::BadOrigin)?;
+        ensure!(
+            role.is_active(Self::get_current_block_as_u64()),
+            Error::<T>::SubnetRoleExpired
+        );
+        ensure!(role.covers(param), DispatchError::BadOrigin);
+        ensure!(
+            role.allows_value(param, value),
+            Error::<T>::SubnetRoleBoundsExceeded
+        );
+
+        Ok(Some(who))
+    }
+
+    /// Grants `delegate` the right to change the given hyperparameter groups on the subnet.
+    ///
+    /// Replaces any role previously granted to the delegate on this subnet.
+    pub fn do_grant_subnet_role(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        delegate: T::AccountId,
+        groups: Vec<HyperparameterGroup>,
+        bounds: Vec<HyperparameterBound>,
+        expires_at: Option<u64>,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+
+        ensure!(
+            SubnetOwner::<T>::get(netuid) != delegate,
+            Error::<T>::InvalidSubnetRole
+        );
+        ensure!(!groups.is_empty(), Error::<T>::InvalidSubnetRole);
+        ensure!(
+            bounds.iter().all(|bound| bound.min <= bound.max),
+            Error::<T>::InvalidSubnetRole
+        );
+        if let Some(expires_at) = expires_at {
+            ensure!(
+                expires_at > Self::get_current_block_as_u64(),
+                Error::<T>::SubnetRoleExpired
+            );
+        }
+
+        let groups = groups.into_iter().fold(Vec::new(), |mut acc, group| {
+            if !acc.contains(&group) {
+                acc.push(group);
+            }
+            acc
+        });
+        let role = SubnetRole {
+            groups: BoundedVec::try_from(groups).map_err(|_| Error::<T>::InvalidSubnetRole)?,
+            bounds: BoundedVec::try_from(bounds).map_err(|_| Error::<T>::InvalidSubnetRole)?,
+            expires_at,
+        };
+
+        SubnetRoles::<T>::insert(netuid, &delegate, role);
+        Self::deposit_event(Event::SubnetRoleGranted {
+            netuid,
+            delegate,
+            expires_at,
+        });
+
+        Ok(())
+    }
+
+    /// Revokes the role held by `delegate` on the subnet.
+    pub fn do_revoke_subnet_role(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        delegate: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+
+        ensure!(
+            SubnetRoles::<T>::contains_key(netuid, &delegate),
+            Error::<T>::SubnetRoleNotFound
+        );
+
+        SubnetRoles::<T>::remove(netuid, &delegate);
+        Self::deposit_event(Event::SubnetRoleRevoked { netuid, delegate });
+
+        Ok(())
+    }
+}
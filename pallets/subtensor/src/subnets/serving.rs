@@ -1,4 +1,6 @@
 use super::*;
+use frame_support::BoundedVec;
+use sp_core::ConstU32;
 use subtensor_runtime_common::NetUid;
 
 impl<T: Config> Pallet<T> {
@@ -110,6 +112,9 @@ impl<T: Config> Pallet<T> {
 
         Axons::<T>::insert(netuid, hotkey_id.clone(), prev_axon);
 
+        // A single served axon replaces any previously served endpoint list.
+        AxonEndpoints::<T>::remove(netuid, hotkey_id.clone());
+
         // We deposit axon served event.
         log::debug!("AxonServed( hotkey:{:?} ) ", hotkey_id.clone());
         Self::deposit_event(Event::AxonServed(netuid, hotkey_id));
@@ -118,6 +123,102 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic serve_axons which sets several endpoints for a uid on a network.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the caller.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'version' (u32):
+    ///     - The bittensor version identifier.
+    ///
+    /// * 'endpoints' (Vec<AxonEndpoint>):
+    ///     - The endpoints served by the neuron.
+    ///
+    /// # Event:
+    /// * AxonEndpointsServed;
+    ///     - On successfully serving the axon endpoints.
+    ///
+    /// # Raises:
+    /// * 'NotRegistered':
+    ///     - Attempting to serve from a non registered account.
+    ///
+    /// * 'NoAxonEndpoints':
+    ///     - The endpoint list is empty.
+    ///
+    /// * 'TooManyAxonEndpoints':
+    ///     - The endpoint list is longer than `MAX_AXON_ENDPOINTS`.
+    ///
+    /// * 'DuplicateAxonEndpoint':
+    ///     - The same protocol, ip and port is listed twice.
+    ///
+    /// * 'InvalidIpType':
+    ///     - An ip type is not 4 or 6.
+    ///
+    /// * 'InvalidIpAddress':
+    ///     - A numerically encoded ip address does not resolve to a proper ip.
+    ///
+    /// * 'InvalidPort':
+    ///     - A port is zero.
+    ///
+    /// * 'ServingRateLimitExceeded':
+    ///     - Attempting to serve axon information within the rate limit min.
+    ///
+    pub fn do_serve_axons(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        version: u32,
+        mut endpoints: Vec<AxonEndpointOf>,
+    ) -> dispatch::DispatchResult {
+        // We check the callers (hotkey) signature.
+        let hotkey_id = ensure_signed(origin)?;
+
+        // Validate user input
+        Self::validate_serve_axons(&hotkey_id, netuid, &endpoints)?;
+
+        // Keep the preferred endpoint first.
+        endpoints.sort_by_key(|endpoint| endpoint.priority);
+        let count = endpoints.len() as u32;
+        let endpoints: BoundedVec<AxonEndpointOf, ConstU32<MAX_AXON_ENDPOINTS>> =
+            BoundedVec::try_from(endpoints).map_err(|_| Error::<T>::TooManyAxonEndpoints)?;
+        let primary = endpoints
+            .first()
+            .cloned()
+            .ok_or(Error::<T>::NoAxonEndpoints)?;
+
+        // We mirror the preferred endpoint into the single axon view.
+        let mut prev_axon = Self::get_axon_info(netuid, &hotkey_id);
+        prev_axon.block = Self::get_current_block_as_u64();
+        prev_axon.version = version;
+        prev_axon.ip = primary.ip;
+        prev_axon.port = primary.port;
+        prev_axon.ip_type = primary.ip_type;
+        prev_axon.protocol = primary.protocol;
+        prev_axon.placeholder1 = 0;
+        prev_axon.placeholder2 = 0;
+
+        Axons::<T>::insert(netuid, hotkey_id.clone(), prev_axon);
+        AxonEndpoints::<T>::insert(netuid, hotkey_id.clone(), endpoints);
+
+        // We deposit axon endpoints served event.
+        log::debug!(
+            "AxonEndpointsServed( hotkey:{:?}, count:{:?} ) ",
+            hotkey_id.clone(),
+            count
+        );
+        Self::deposit_event(Event::AxonEndpointsServed {
+            netuid,
+            hotkey: hotkey_id,
+            count,
+        });
+
+        // Return is successful dispatch.
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic serve_prometheus.
     ///
     /// # Args:
@@ -257,6 +358,26 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Returns the endpoints served by the hotkey on the subnet, preferred endpoint first.
+    ///
+    /// Neurons that only served a single axon are reported with that axon as their one endpoint.
+    pub fn get_axon_endpoints(netuid: NetUid, hotkey: &T::AccountId) -> Vec<AxonEndpointOf> {
+        if let Some(endpoints) = AxonEndpoints::<T>::get(netuid, hotkey) {
+            return endpoints.into_inner();
+        }
+
+        match Axons::<T>::get(netuid, hotkey) {
+            Some(axon) => vec![AxonEndpoint {
+                protocol: axon.protocol,
+                ip: axon.ip,
+                ip_type: axon.ip_type,
+                port: axon.port,
+                priority: 0,
+            }],
+            None => Vec::new(),
+        }
+    }
+
     pub fn get_prometheus_info(netuid: NetUid, hotkey: &T::AccountId) -> PrometheusInfoOf {
         if let Some(prometheus) = Prometheus::<T>::get(netuid, hotkey) {
             prometheus
@@ -369,4 +490,55 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    pub fn validate_serve_axons(
+        hotkey_id: &T::AccountId,
+        netuid: NetUid,
+        endpoints: &[AxonEndpointOf],
+    ) -> Result<(), Error<T>> {
+        // Ensure the hotkey is registered somewhere.
+        ensure!(
+            Self::is_hotkey_registered_on_any_network(hotkey_id),
+            Error::<T>::HotKeyNotRegisteredInNetwork
+        );
+
+        // Check the endpoint list bounds.
+        ensure!(!endpoints.is_empty(), Error::<T>::NoAxonEndpoints);
+        ensure!(
+            endpoints.len() <= MAX_AXON_ENDPOINTS as usize,
+            Error::<T>::TooManyAxonEndpoints
+        );
+
+        for (index, endpoint) in endpoints.iter().enumerate() {
+            // Check the ip signature validity.
+            ensure!(
+                Self::is_valid_ip_type(endpoint.ip_type),
+                Error::<T>::InvalidIpType
+            );
+            ensure!(
+                // allow axon to be served with a zero ip address for testing purposes
+                Self::is_valid_ip_address(endpoint.ip_type, endpoint.ip, true),
+                Error::<T>::InvalidIpAddress
+            );
+            ensure!(endpoint.port != 0, Error::<T>::InvalidPort);
+
+            // Each (protocol, ip, port) can only be listed once.
+            let duplicated = endpoints.iter().skip(index.saturating_add(1)).any(|other| {
+                other.protocol == endpoint.protocol
+                    && other.ip == endpoint.ip
+                    && other.port == endpoint.port
+            });
+            ensure!(!duplicated, Error::<T>::DuplicateAxonEndpoint);
+        }
+
+        // Endpoints share the rate limit of the single axon.
+        let prev_axon = Self::get_axon_info(netuid, hotkey_id);
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            Self::axon_passes_rate_limit(netuid, &prev_axon, current_block),
+            Error::<T>::ServingRateLimitExceeded
+        );
+
+        Ok(())
+    }
 }
@@ -160,7 +160,7 @@ impl<T: Config> Pallet<T> {
         Self::set_network_last_lock(actual_tao_lock_amount);
 
         // --- 9. Set initial and custom parameters for the network.
-        let default_tempo = DefaultTempo::<T>::get();
+        let default_tempo = InitialSubnetTempo::<T>::get();
         Self::init_new_network(netuid_to_register, default_tempo);
         log::debug!("init_new_network: {:?}", netuid_to_register);
 
@@ -349,7 +349,7 @@ impl<T: Config> Pallet<T> {
 
         ensure!(
             current_block_number
-                >= registration_block_number.saturating_add(T::DurationOfStartCall::get()),
+                >= registration_block_number.saturating_add(StartCallDelay::<T>::get()),
             Error::<T>::NeedWaitingMoreBlocksToStarCall
         );
         let next_block_number = current_block_number.saturating_add(1);
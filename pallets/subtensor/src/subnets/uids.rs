@@ -25,6 +25,7 @@ impl<T: Config> Pallet<T> {
         Incentive::<T>::mutate(netuid, |v| Self::set_element_at(v, neuron_index, 0));
         Dividends::<T>::mutate(netuid, |v| Self::set_element_at(v, neuron_index, 0));
         Bonds::<T>::remove(netuid, neuron_uid); // Remove bonds for Validator.
+        ValidatorWeightMetricsHistory::<T>::remove(netuid, neuron_uid); // Remove weight metrics for Validator.
     }
 
     /// Replace the neuron under this uid.
@@ -73,13 +74,16 @@ impl<T: Config> Pallet<T> {
         BlockAtRegistration::<T>::insert(netuid, uid_to_replace, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
 
-        // 4. Clear neuron certificates
+        // 4. Clear neuron certificates and weights keys
         NeuronCertificates::<T>::remove(netuid, old_hotkey.clone());
+        Self::remove_weights_key(netuid, old_hotkey);
+        Self::revoke_registered_weights_key(netuid, new_hotkey);
 
         // 5. Reset new neuron's values.
         Self::clear_neuron(netuid, uid_to_replace);
 
         // 5a. reset axon info for the new uid.
+        AxonEndpoints::<T>::remove(netuid, old_hotkey.clone());
         Axons::<T>::remove(netuid, old_hotkey);
     }
 
@@ -115,6 +119,9 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), next_uid); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, next_uid, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+
+        // 5. Revoke the new hotkey as weights key of another hotkey.
+        Self::revoke_registered_weights_key(netuid, new_hotkey);
     }
 
     /// Returns true if the uid is set on the network.
@@ -7,7 +7,10 @@ use sp_runtime::{
     BoundedVec,
     traits::{BlakeTwo256, Hash},
 };
-use sp_std::{collections::vec_deque::VecDeque, vec};
+use sp_std::{
+    collections::{btree_map::BTreeMap, vec_deque::VecDeque},
+    vec,
+};
 use subtensor_runtime_common::NetUid;
 
 impl<T: Config> Pallet<T> {
@@ -15,7 +18,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Args:
     /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
-    ///   - The signature of the committing hotkey.
+    ///   - The signature of the committing hotkey or of its weights key.
     ///
     /// * `netuid` (`u16`):
     ///   - The u16 network identifier.
@@ -44,8 +47,8 @@ impl<T: Config> Pallet<T> {
         netuid: NetUid,
         commit_hash: H256,
     ) -> DispatchResult {
-        // 1. Verify the caller's signature (hotkey).
-        let who = ensure_signed(origin)?;
+        // 1. Verify the caller's signature (hotkey or its weights key).
+        let who = Self::ensure_weights_signer(origin, netuid)?;
 
         log::debug!("do_commit_weights(hotkey: {:?}, netuid: {:?})", who, netuid);
 
@@ -193,7 +196,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Args:
     /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
-    ///   - The signature of the committing hotkey.
+    ///   - The signature of the committing hotkey or of its weights key.
     ///
     /// * `netuid` (`u16`):
     ///   - The u16 network identifier.
@@ -233,8 +236,8 @@ impl<T: Config> Pallet<T> {
         commit: BoundedVec<u8, ConstU32<MAX_CRV3_COMMIT_SIZE_BYTES>>,
         reveal_round: u64,
     ) -> DispatchResult {
-        // 1. Verify the caller's signature (hotkey).
-        let who = ensure_signed(origin)?;
+        // 1. Verify the caller's signature (hotkey or its weights key).
+        let who = Self::ensure_weights_signer(origin, netuid)?;
 
         log::debug!(
             "do_commit_v3_weights(hotkey: {:?}, netuid: {:?})",
@@ -305,7 +308,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Args:
     /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
-    ///   - The signature of the revealing hotkey.
+    ///   - The signature of the revealing hotkey or of its weights key.
     ///
     /// * `netuid` (`u16`):
     ///   - The u16 network identifier.
@@ -345,8 +348,42 @@ impl<T: Config> Pallet<T> {
         salt: Vec<u16>,
         version_key: u64,
     ) -> DispatchResult {
-        // --- 1. Check the caller's signature (hotkey).
-        let who = ensure_signed(origin.clone())?;
+        Self::reveal_weights_with(origin, netuid, uids, values, salt, version_key, false)
+    }
+
+    /// ---- The implementation for revealing committed weight changes.
+    ///
+    /// The commit hash is the hash of `(b"delta", hotkey, netuid, uids, values, salt,
+    /// version_key)`, so that a commit of full weights can't be revealed as changes or the other
+    /// way around. The changes are merged into the weights stored when revealing, see
+    /// `do_set_weights_delta`.
+    ///
+    /// # Raises:
+    /// * The errors of `do_reveal_weights` and `do_set_weights_delta`.
+    pub fn do_reveal_weights_delta(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        salt: Vec<u16>,
+        version_key: u64,
+    ) -> DispatchResult {
+        Self::reveal_weights_with(origin, netuid, uids, values, salt, version_key, true)
+    }
+
+    /// Checks a reveal against the commits of the hotkey and sets the revealed weights, or
+    /// merges the revealed weight changes if `delta` is set.
+    fn reveal_weights_with(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        salt: Vec<u16>,
+        version_key: u64,
+        delta: bool,
+    ) -> DispatchResult {
+        // --- 1. Check the caller's signature (hotkey or its weights key).
+        let who = Self::ensure_weights_signer(origin.clone(), netuid)?;
 
         log::debug!("do_reveal_weights( hotkey:{:?} netuid:{:?})", who, netuid);
 
@@ -374,15 +411,27 @@ impl<T: Config> Pallet<T> {
                 }
             }
 
-            // --- 5. Hash the provided data.
-            let provided_hash: H256 = BlakeTwo256::hash_of(&(
-                who.clone(),
-                netuid,
-                uids.clone(),
-                values.clone(),
-                salt.clone(),
-                version_key,
-            ));
+            // --- 5. Hash the provided data, weight changes being tagged.
+            let provided_hash: H256 = if delta {
+                BlakeTwo256::hash_of(&(
+                    b"delta",
+                    who.clone(),
+                    netuid,
+                    uids.clone(),
+                    values.clone(),
+                    salt.clone(),
+                    version_key,
+                ))
+            } else {
+                BlakeTwo256::hash_of(&(
+                    who.clone(),
+                    netuid,
+                    uids.clone(),
+                    values.clone(),
+                    salt.clone(),
+                    version_key,
+                ))
+            };
 
             // --- 6. After removing expired commits, check if any commits are left.
             if commits.is_empty() {
@@ -421,7 +470,23 @@ impl<T: Config> Pallet<T> {
                 }
 
                 // --- 12. Proceed to set the revealed weights.
-                Self::do_set_weights(origin, netuid, uids.clone(), values.clone(), version_key)?;
+                if delta {
+                    Self::do_set_weights_delta(
+                        origin,
+                        netuid,
+                        uids.clone(),
+                        values.clone(),
+                        version_key,
+                    )?;
+                } else {
+                    Self::do_set_weights(
+                        origin,
+                        netuid,
+                        uids.clone(),
+                        values.clone(),
+                        version_key,
+                    )?;
+                }
 
                 // --- 13. Emit the WeightsRevealed event.
                 Self::deposit_event(Event::WeightsRevealed(who.clone(), netuid, provided_hash));
@@ -443,7 +508,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Args:
     /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
-    ///   - The signature of the revealing hotkey.
+    ///   - The signature of the revealing hotkey or of its weights key.
     ///
     /// * `netuid` (`u16`):
     ///   - The u16 network identifier.
@@ -495,8 +560,8 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InputLengthsUnequal
         );
 
-        // --- 2. Check the caller's signature (hotkey).
-        let who = ensure_signed(origin.clone())?;
+        // --- 2. Check the caller's signature (hotkey or its weights key).
+        let who = Self::ensure_weights_signer(origin.clone(), netuid)?;
 
         log::debug!(
             "do_batch_reveal_weights( hotkey:{:?} netuid:{:?})",
@@ -622,7 +687,7 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Args:
     ///  * 'origin': (<T as frame_system::Config>RuntimeOrigin):
-    ///    - The signature of the calling hotkey.
+    ///    - The signature of the calling hotkey or of its weights key.
     ///
     ///  * 'netuid' (u16):
     ///    - The u16 network identifier.
@@ -681,8 +746,8 @@ impl<T: Config> Pallet<T> {
         values: Vec<u16>,
         version_key: u64,
     ) -> dispatch::DispatchResult {
-        // --- 1. Check the caller's signature. This is the hotkey of a registered account.
-        let hotkey = ensure_signed(origin)?;
+        // --- 1. Check the caller's signature: a registered hotkey or its weights key.
+        let hotkey = Self::ensure_weights_signer(origin, netuid)?;
         log::debug!(
             "do_set_weights( origin:{:?} netuid:{:?}, uids:{:?}, values:{:?})",
             hotkey,
@@ -893,6 +958,74 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for setting weights as changes to the stored weights.
+    ///
+    /// # Args:
+    /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+    ///   - The signature of the calling hotkey or of its weights key.
+    ///
+    /// * `netuid` (`u16`):
+    ///   - The u16 network identifier.
+    ///
+    /// * `dests` (`Vec<u16>`):
+    ///   - The uids whose weight changes.
+    ///
+    /// * `weights` (`Vec<u16>`):
+    ///   - The new weights of `dests`, a zero weight removing the uid. Stored weights are
+    ///     normalized so that the largest is `u16::MAX`, and changes use the same scale.
+    ///
+    /// * `version_key` (`u64`):
+    ///   - The network version key.
+    ///
+    /// # Raises:
+    ///  * 'WeightVecNotEqualSize':
+    ///    - Attempting to set weights with uids not of same length.
+    ///
+    ///  * 'DuplicateUids':
+    ///    - Attempting to change the weight of a uid twice.
+    ///
+    ///  * The errors of `do_set_weights` for the merged weights.
+    pub fn do_set_weights_delta(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        dests: Vec<u16>,
+        weights: Vec<u16>,
+        version_key: u64,
+    ) -> dispatch::DispatchResult {
+        // --- 1. Check the caller's signature: a registered hotkey or its weights key.
+        let hotkey = Self::ensure_weights_signer(origin.clone(), netuid)?;
+        log::debug!(
+            "do_set_weights_delta( origin:{:?} netuid:{:?}, dests:{:?}, weights:{:?})",
+            hotkey,
+            netuid,
+            dests,
+            weights
+        );
+
+        // --- 2. Check the changes are well formed.
+        ensure!(
+            Self::uids_match_values(&dests, &weights),
+            Error::<T>::WeightVecNotEqualSize
+        );
+        ensure!(!Self::has_duplicate_uids(&dests), Error::<T>::DuplicateUids);
+
+        // --- 3. Apply the changes to the stored weights of the hotkey.
+        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+        let mut row: BTreeMap<u16, u16> =
+            Weights::<T>::get(netuid, neuron_uid).into_iter().collect();
+        for (dest, weight) in dests.into_iter().zip(weights) {
+            if weight == 0 {
+                row.remove(&dest);
+            } else {
+                row.insert(dest, weight);
+            }
+        }
+        let (uids, values): (Vec<u16>, Vec<u16>) = row.into_iter().unzip();
+
+        // --- 4. Set the merged weights, running the usual checks on them.
+        Self::do_set_weights(origin, netuid, uids, values, version_key)
+    }
+
     // ==========================
     // ==== Helper functions ====
     // ==========================
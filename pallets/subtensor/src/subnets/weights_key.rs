@@ -0,0 +1,94 @@
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    /// Authorizes `weights_key` to set, commit and reveal weights for the calling hotkey on the
+    /// subnet, replacing the key it previously authorized there.
+    pub fn do_set_weights_key(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        weights_key: T::AccountId,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        // A registered hotkey always signs for itself, so it cannot act for another one.
+        ensure!(
+            !Self::is_hotkey_registered_on_network(netuid, &weights_key),
+            Error::<T>::InvalidWeightsKey
+        );
+        ensure!(
+            WeightsKeyHotkey::<T>::get(netuid, &weights_key).is_none_or(|owner| owner == hotkey),
+            Error::<T>::WeightsKeyAlreadyInUse
+        );
+
+        if let Some(previous) = WeightsKeys::<T>::get(netuid, &hotkey) {
+            WeightsKeyHotkey::<T>::remove(netuid, previous);
+        }
+        WeightsKeys::<T>::insert(netuid, &hotkey, &weights_key);
+        WeightsKeyHotkey::<T>::insert(netuid, &weights_key, &hotkey);
+
+        Self::deposit_event(Event::WeightsKeySet {
+            netuid,
+            hotkey,
+            weights_key,
+        });
+
+        Ok(())
+    }
+
+    /// Revokes the weights key of the calling hotkey on the subnet.
+    pub fn do_remove_weights_key(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+
+        ensure!(
+            Self::remove_weights_key(netuid, &hotkey),
+            Error::<T>::WeightsKeyNotFound
+        );
+
+        Ok(())
+    }
+
+    /// Removes the weights key of a hotkey on a subnet, if any. Returns whether one was removed.
+    pub fn remove_weights_key(netuid: NetUid, hotkey: &T::AccountId) -> bool {
+        let Some(weights_key) = WeightsKeys::<T>::take(netuid, hotkey) else {
+            return false;
+        };
+        WeightsKeyHotkey::<T>::remove(netuid, weights_key);
+
+        Self::deposit_event(Event::WeightsKeyRemoved {
+            netuid,
+            hotkey: hotkey.clone(),
+        });
+        true
+    }
+
+    /// Revokes `key` as weights key on the subnet when it becomes a registered hotkey there, as a
+    /// registered hotkey always signs for itself.
+    pub fn revoke_registered_weights_key(netuid: NetUid, key: &T::AccountId) {
+        if let Some(hotkey) = WeightsKeyHotkey::<T>::get(netuid, key) {
+            Self::remove_weights_key(netuid, &hotkey);
+        }
+    }
+
+    /// Returns the hotkey the signer of a weights call acts for on the subnet: the signer itself
+    /// when it is registered there, otherwise the hotkey that authorized it as weights key.
+    pub fn get_weights_signer_hotkey(netuid: NetUid, who: &T::AccountId) -> T::AccountId {
+        if Self::is_hotkey_registered_on_network(netuid, who) {
+            return who.clone();
+        }
+        WeightsKeyHotkey::<T>::get(netuid, who).unwrap_or_else(|| who.clone())
+    }
+
+    /// Ensures the origin is signed and returns the hotkey it sets weights for on the subnet.
+    pub fn ensure_weights_signer(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+    ) -> Result<T::AccountId, DispatchError> {
+        let who = ensure_signed(origin)?;
+        Ok(Self::get_weights_signer_hotkey(netuid, &who))
+    }
+}
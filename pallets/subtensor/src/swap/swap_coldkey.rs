@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::identity_attestation::IdentityAttestationTarget;
 use frame_support::weights::Weight;
 use sp_core::Get;
 use substrate_fixed::types::U64F64;
@@ -55,6 +56,15 @@ impl<T: Config> Pallet<T> {
         if let Some(identity) = IdentitiesV2::<T>::take(old_coldkey) {
             IdentitiesV2::<T>::insert(new_coldkey, identity);
         }
+        let attestations = IdentityAttestations::<T>::take(IdentityAttestationTarget::Coldkey(
+            old_coldkey.clone(),
+        ));
+        if !attestations.is_empty() {
+            IdentityAttestations::<T>::insert(
+                IdentityAttestationTarget::Coldkey(new_coldkey.clone()),
+                attestations,
+            );
+        }
 
         // 6. Ensure sufficient balance for the swap cost
         ensure!(
@@ -146,12 +156,14 @@ impl<T: Config> Pallet<T> {
         //     weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
         // }  (DEPRECATED)
 
-        // 2. Swap subnet owner.
+        // 2. Swap subnet owner and remove the roles delegated by the old owner.
         // SubnetOwner: MAP ( netuid ) --> (coldkey) | Owner of the subnet.
         for netuid in Self::get_all_subnet_netuids() {
             let subnet_owner = SubnetOwner::<T>::get(netuid);
             if subnet_owner == *old_coldkey {
                 SubnetOwner::<T>::insert(netuid, new_coldkey.clone());
+                let removed = SubnetRoles::<T>::clear_prefix(netuid, u32::MAX, None);
+                weight.saturating_accrue(T::DbWeight::get().writes(u64::from(removed.unique)));
             }
             weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
         }
@@ -387,6 +387,10 @@ impl<T: Config> Pallet<T> {
                 // 3.2.2 Swap the keys.
                 Keys::<T>::insert(netuid, old_uid, new_hotkey.clone());
                 weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
+
+                // 3.2.3 Revoke the new hotkey as weights key of another hotkey.
+                Self::revoke_registered_weights_key(netuid, new_hotkey);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
             }
         }
 
@@ -408,6 +412,11 @@ impl<T: Config> Pallet<T> {
                 Axons::<T>::insert(netuid, new_hotkey, old_axon_info);
                 weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
             }
+            if let Ok(old_axon_endpoints) = AxonEndpoints::<T>::try_get(netuid, old_hotkey) {
+                AxonEndpoints::<T>::remove(netuid, old_hotkey);
+                AxonEndpoints::<T>::insert(netuid, new_hotkey, old_axon_endpoints);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
         }
 
         // 3.5 Swap WeightCommits
@@ -446,6 +455,14 @@ impl<T: Config> Pallet<T> {
                 weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
             }
         }
+
+        // 3.8. Swap the weights key.
+        // WeightsKeys( netuid, hotkey ) -> weights_key -- the key setting weights for the hotkey.
+        if let Some(weights_key) = WeightsKeys::<T>::take(netuid, old_hotkey) {
+            WeightsKeyHotkey::<T>::insert(netuid, &weights_key, new_hotkey);
+            WeightsKeys::<T>::insert(netuid, new_hotkey, weights_key);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+        }
         // 4. Swap ChildKeys.
         // ChildKeys( parent, netuid ) --> Vec<(proportion,child)> -- the child keys of the parent.
         let my_children: Vec<(u64, T::AccountId)> = ChildKeys::<T>::get(old_hotkey, netuid);
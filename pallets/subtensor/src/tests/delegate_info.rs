@@ -1,5 +1,7 @@
+#![allow(clippy::unwrap_used)]
 use super::mock::*;
 
+use crate::utils::identity_attestation::{IdentityAttestationTarget, IdentityField};
 use codec::Compact;
 use frame_support::assert_ok;
 use scale_info::prelude::collections::HashMap;
@@ -166,3 +168,56 @@ fn test_get_delegated() {
         }
     });
 }
+
+#[test]
+fn test_get_delegate_v2_includes_owner_identity() {
+    new_test_ext(1).execute_with(|| {
+        let owner = U256::from(1);
+        let delegate = U256::from(2);
+        let attester = U256::from(3);
+        let netuid = NetUid::from(1);
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, delegate, owner, 0);
+        SubtensorModule::delegate_hotkey(&delegate, 0);
+        assert_ok!(SubtensorModule::do_set_identity(
+            RuntimeOrigin::signed(owner),
+            b"Alice".to_vec(),
+            vec![],
+            b"https://github.com/alice".to_vec(),
+            vec![],
+            vec![],
+            vec![],
+            vec![]
+        ));
+        assert_ok!(SubtensorModule::add_identity_attester(
+            RuntimeOrigin::root(),
+            attester
+        ));
+        assert_ok!(SubtensorModule::attest_identity(
+            RuntimeOrigin::signed(attester),
+            IdentityAttestationTarget::Coldkey(owner),
+            IdentityField::GithubRepo,
+            100
+        ));
+
+        let delegate_info = SubtensorModule::get_delegate_v2(delegate).unwrap();
+        assert_eq!(delegate_info.owner_ss58, owner);
+        assert_eq!(
+            delegate_info.identity.map(|identity| identity.name),
+            Some(b"Alice".to_vec())
+        );
+        assert_eq!(delegate_info.identity_attestations.len(), 1);
+
+        // The first version keeps its layout.
+        assert_eq!(
+            SubtensorModule::get_delegate(delegate).map(|info| info.delegate_ss58),
+            Some(delegate)
+        );
+        assert!(
+            SubtensorModule::get_delegates_v2()
+                .iter()
+                .any(|info| info.delegate_ss58 == delegate)
+        );
+    });
+}
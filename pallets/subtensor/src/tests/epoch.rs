@@ -2520,6 +2520,60 @@ fn test_can_set_self_weight_as_subnet_owner() {
     });
 }
 
+// Test that the epoch records the weight metrics of validators only and keeps a bounded history.
+#[test]
+fn test_epoch_records_validator_weight_metrics() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey: U256 = U256::from(1);
+        let subnet_owner_hotkey: U256 = U256::from(1 + 456);
+        let other_hotkey: U256 = U256::from(2);
+
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, other_hotkey, subnet_owner_coldkey, 0);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &subnet_owner_hotkey,
+            &subnet_owner_coldkey,
+            netuid,
+            5_000_000_000_000.into(),
+        );
+        ValidatorPermit::<Test>::insert(netuid, vec![true, false]);
+
+        let fifty_percent: u16 = u16::MAX / 2;
+        Weights::<Test>::insert(netuid, 0, vec![(0, fifty_percent), (1, fifty_percent)]);
+        step_block(1);
+        LastUpdate::<Test>::insert(netuid, vec![2, 0]);
+
+        SubtensorModule::epoch(netuid, 1_000_000_000.into());
+
+        // The only validator defines consensus, so its weights match it and nothing is clipped.
+        let metrics = SubtensorModule::get_validator_weight_metrics(netuid, 0);
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(
+            metrics[0].block,
+            SubtensorModule::get_current_block_as_u64()
+        );
+        assert!(metrics[0].consensus_similarity > u16::MAX - 100);
+        assert!(metrics[0].clipped_share < 100);
+        assert_eq!(
+            metrics[0].blocks_since_update,
+            SubtensorModule::get_current_block_as_u64() - 2
+        );
+        assert!(SubtensorModule::get_validator_weight_metrics(netuid, 1).is_empty());
+
+        let history = crate::epoch::weight_metrics::VALIDATOR_WEIGHT_METRICS_HISTORY;
+        for _ in 0..history + 4 {
+            step_block(1);
+            SubtensorModule::epoch(netuid, 1_000_000_000.into());
+        }
+        let metrics = SubtensorModule::get_validator_weight_metrics(netuid, 0);
+        assert_eq!(metrics.len(), history);
+        assert_eq!(
+            metrics.last().unwrap().block,
+            SubtensorModule::get_current_block_as_u64()
+        );
+    });
+}
+
 #[test]
 fn test_epoch_outputs_single_staker_registered_no_weights() {
     new_test_ext(1).execute_with(|| {
@@ -4,7 +4,10 @@
     clippy::indexing_slicing
 )]
 use super::mock::*;
-use crate::{subnets::leasing::SubnetLeaseOf, *};
+use crate::{
+    subnets::{leasing::SubnetLeaseOf, roles::HyperparameterGroup},
+    *,
+};
 use frame_support::{StorageDoubleMap, assert_err, assert_ok};
 use sp_core::U256;
 use sp_runtime::Percent;
@@ -263,6 +266,17 @@ fn test_terminate_lease_works() {
         let hotkey = U256::from(3);
         SubtensorModule::create_account_if_non_existent(&beneficiary, &hotkey);
 
+        // Delegate a role while the subnet is leased
+        let delegate = U256::from(4);
+        assert_ok!(SubtensorModule::grant_subnet_role(
+            RuntimeOrigin::signed(lease.coldkey),
+            lease.netuid,
+            delegate,
+            vec![HyperparameterGroup::Weights],
+            vec![],
+            None,
+        ));
+
         // Terminate the lease
         assert_ok!(SubtensorModule::terminate_lease(
             RuntimeOrigin::signed(beneficiary),
@@ -278,6 +292,7 @@ fn test_terminate_lease_works() {
         assert_eq!(SubnetLeases::<Test>::get(lease_id), None);
         assert!(!SubnetLeaseShares::<Test>::contains_prefix(lease_id));
         assert!(!AccumulatedLeaseDividends::<Test>::contains_key(lease_id));
+        assert!(!SubnetRoles::<Test>::contains_key(lease.netuid, delegate));
 
         // Ensure the beneficiary has been removed as a proxy
         assert!(PROXIES.with_borrow(|proxies| proxies.0.is_empty()));
@@ -1343,6 +1343,24 @@ fn test_math_row_sum_sparse() {
     assert_vec_compare(&result, &target, I32F32::from_num(0));
 }
 
+#[test]
+fn test_math_row_cosine_similarity_sparse() {
+    let matrix: Vec<f32> = vec![0.5, 0.5, 0., 0., 0., 1., 0.25, 0.25, 0.5, 0., 0., 0.];
+    let matrix = vec_to_sparse_mat_fixed(&matrix, 4, false);
+    let vector: Vec<I32F32> = vec_to_fixed(&[0.5, 0.5, 0.]);
+    let result = row_cosine_similarity_sparse(&matrix, &vector);
+    // Identical direction, orthogonal, partially aligned (1/sqrt(3)), empty row.
+    assert_eq!(result[0], u16::MAX);
+    assert_eq!(result[1], 0);
+    assert!(result[2].abs_diff(37837) <= 1);
+    assert_eq!(result[3], 0);
+
+    // A vector without mass is similar to nothing.
+    let vector: Vec<I32F32> = vec_to_fixed(&[0., 0., 0.]);
+    let result = row_cosine_similarity_sparse(&matrix, &vector);
+    assert_eq!(result, vec![0, 0, 0, 0]);
+}
+
 #[test]
 fn test_math_col_sum() {
     let matrix: Vec<f32> = vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.];
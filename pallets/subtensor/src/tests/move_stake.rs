@@ -2072,3 +2072,310 @@ fn test_swap_stake_limits_destination_netuid() {
         )));
     });
 }
+
+#[test]
+fn test_transfer_alpha_token() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+
+        let origin_coldkey = U256::from(1);
+        let destination_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10;
+
+        SubtensorModule::create_account_if_non_existent(&origin_coldkey, &hotkey);
+        SubtensorModule::stake_into_subnet(
+            &hotkey,
+            &origin_coldkey,
+            netuid,
+            stake_amount,
+            <Test as Config>::SwapInterface::max_price(),
+            false,
+        )
+        .unwrap();
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey,
+            &origin_coldkey,
+            netuid,
+        );
+
+        // Without a chosen hotkey the tokens are backed by the hotkey the coldkey stakes on.
+        assert_eq!(
+            SubtensorModule::get_alpha_token_hotkey(netuid, &origin_coldkey),
+            Some(hotkey)
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_token_balance(netuid, &origin_coldkey),
+            alpha
+        );
+
+        // A coldkey without stake holds no tokens.
+        assert_eq!(
+            SubtensorModule::get_alpha_token_balance(netuid, &destination_coldkey),
+            AlphaCurrency::ZERO
+        );
+        assert_noop!(
+            SubtensorModule::transfer_alpha_token(
+                RuntimeOrigin::signed(destination_coldkey),
+                netuid,
+                origin_coldkey,
+                alpha
+            ),
+            Error::<Test>::AlphaTokenHotkeyNotSet
+        );
+
+        let amount = alpha / 2.into();
+        assert_ok!(SubtensorModule::transfer_alpha_token(
+            RuntimeOrigin::signed(origin_coldkey),
+            netuid,
+            destination_coldkey,
+            amount
+        ));
+        System::assert_last_event(
+            Event::AlphaTokenTransferred {
+                netuid,
+                from: origin_coldkey,
+                to: destination_coldkey,
+                amount,
+            }
+            .into(),
+        );
+
+        // The destination is credited on the sender's hotkey without choosing it.
+        assert_eq!(
+            AlphaTokenHotkey::<Test>::get(netuid, destination_coldkey),
+            None
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_token_hotkey(netuid, &destination_coldkey),
+            Some(hotkey)
+        );
+        assert_abs_diff_eq!(
+            SubtensorModule::get_alpha_token_balance(netuid, &destination_coldkey),
+            amount,
+            epsilon = amount / 1000.into()
+        );
+        assert_abs_diff_eq!(
+            SubtensorModule::get_alpha_token_balance(netuid, &origin_coldkey),
+            alpha - amount,
+            epsilon = amount / 1000.into()
+        );
+    });
+}
+
+#[test]
+fn test_transfer_alpha_token_keeps_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+
+        let origin_coldkey = U256::from(1);
+        let destination_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let destination_hotkey = U256::from(4);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10;
+
+        SubtensorModule::create_account_if_non_existent(&origin_coldkey, &hotkey);
+        SubtensorModule::create_account_if_non_existent(&destination_coldkey, &destination_hotkey);
+        SubtensorModule::stake_into_subnet(
+            &hotkey,
+            &origin_coldkey,
+            netuid,
+            stake_amount,
+            <Test as Config>::SwapInterface::max_price(),
+            false,
+        )
+        .unwrap();
+        assert_ok!(SubtensorModule::set_alpha_token_hotkey(
+            RuntimeOrigin::signed(destination_coldkey),
+            netuid,
+            destination_hotkey
+        ));
+
+        let amount = SubtensorModule::get_alpha_token_balance(netuid, &origin_coldkey) / 2.into();
+        assert_ok!(SubtensorModule::transfer_alpha_token(
+            RuntimeOrigin::signed(origin_coldkey),
+            netuid,
+            destination_coldkey,
+            amount
+        ));
+
+        // The stake stays on the sender's hotkey and the destination keeps its choice.
+        assert_abs_diff_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &destination_coldkey,
+                netuid
+            ),
+            amount,
+            epsilon = amount / 1000.into()
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &destination_hotkey,
+                &destination_coldkey,
+                netuid
+            ),
+            AlphaCurrency::ZERO
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_token_hotkey(netuid, &destination_coldkey),
+            Some(destination_hotkey)
+        );
+    });
+}
+
+#[test]
+fn test_transfer_alpha_token_from_spends_allowance() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+
+        let owner = U256::from(1);
+        let spender = U256::from(2);
+        let destination_coldkey = U256::from(3);
+        let hotkey = U256::from(4);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10;
+
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        SubtensorModule::stake_into_subnet(
+            &hotkey,
+            &owner,
+            netuid,
+            stake_amount,
+            <Test as Config>::SwapInterface::max_price(),
+            false,
+        )
+        .unwrap();
+        assert_ok!(SubtensorModule::set_alpha_token_hotkey(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            hotkey
+        ));
+        let alpha = SubtensorModule::get_alpha_token_balance(netuid, &owner);
+        let allowance = alpha / 2.into();
+
+        assert_ok!(SubtensorModule::approve_alpha_token(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            spender,
+            allowance
+        ));
+
+        assert_noop!(
+            SubtensorModule::transfer_alpha_token_from(
+                RuntimeOrigin::signed(spender),
+                netuid,
+                owner,
+                destination_coldkey,
+                alpha
+            ),
+            Error::<Test>::InsufficientAlphaTokenAllowance
+        );
+
+        let amount = allowance / 2.into();
+        assert_ok!(SubtensorModule::transfer_alpha_token_from(
+            RuntimeOrigin::signed(spender),
+            netuid,
+            owner,
+            destination_coldkey,
+            amount
+        ));
+        assert_eq!(
+            AlphaTokenAllowance::<Test>::get((netuid, owner, spender)),
+            allowance - amount
+        );
+        assert_abs_diff_eq!(
+            SubtensorModule::get_alpha_token_balance(netuid, &destination_coldkey),
+            amount,
+            epsilon = amount / 1000.into()
+        );
+    });
+}
+
+#[test]
+fn test_transfer_alpha_token_from_keeps_infinite_allowance() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+
+        let owner = U256::from(1);
+        let spender = U256::from(2);
+        let destination_coldkey = U256::from(3);
+        let hotkey = U256::from(4);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10;
+
+        SubtensorModule::create_account_if_non_existent(&owner, &hotkey);
+        SubtensorModule::stake_into_subnet(
+            &hotkey,
+            &owner,
+            netuid,
+            stake_amount,
+            <Test as Config>::SwapInterface::max_price(),
+            false,
+        )
+        .unwrap();
+        assert_ok!(SubtensorModule::set_alpha_token_hotkey(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            hotkey
+        ));
+        assert_ok!(SubtensorModule::approve_alpha_token(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            spender,
+            AlphaCurrency::MAX
+        ));
+
+        let amount = SubtensorModule::get_alpha_token_balance(netuid, &owner) / 2.into();
+        assert_ok!(SubtensorModule::transfer_alpha_token_from(
+            RuntimeOrigin::signed(spender),
+            netuid,
+            owner,
+            destination_coldkey,
+            amount
+        ));
+        assert_eq!(
+            AlphaTokenAllowance::<Test>::get((netuid, owner, spender)),
+            AlphaCurrency::MAX
+        );
+    });
+}
+
+#[test]
+fn test_transfer_alpha_token_zero_amount() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+
+        let origin_coldkey = U256::from(1);
+        let destination_coldkey = U256::from(2);
+
+        // Zero transfers succeed even without a token hotkey or any stake.
+        assert_ok!(SubtensorModule::transfer_alpha_token(
+            RuntimeOrigin::signed(origin_coldkey),
+            netuid,
+            destination_coldkey,
+            AlphaCurrency::ZERO
+        ));
+        System::assert_last_event(
+            Event::AlphaTokenTransferred {
+                netuid,
+                from: origin_coldkey,
+                to: destination_coldkey,
+                amount: AlphaCurrency::ZERO,
+            }
+            .into(),
+        );
+        assert_eq!(
+            AlphaTokenHotkey::<Test>::get(netuid, destination_coldkey),
+            None
+        );
+    });
+}
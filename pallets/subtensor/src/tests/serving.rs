@@ -2,6 +2,7 @@
 use super::mock::*;
 
 use crate::Error;
+use crate::utils::identity_attestation::{IdentityAttestationTarget, IdentityField};
 use crate::*;
 use frame_support::assert_noop;
 use frame_support::pallet_prelude::Weight;
@@ -1453,3 +1454,390 @@ fn test_serve_axon_validate() {
         assert_ok!(result_ok);
     });
 }
+
+#[test]
+fn test_serve_axons_ok() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid = NetUid::from(1);
+        let version: u32 = 2;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+
+        let quic = AxonEndpoint {
+            protocol: 2,
+            ip: test::ipv4(8, 8, 8, 8),
+            ip_type: 4,
+            port: 443,
+            priority: 1,
+        };
+        let grpc = AxonEndpoint {
+            protocol: 0,
+            ip: test::ipv6(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+            ip_type: 6,
+            port: 8091,
+            priority: 0,
+        };
+        assert_ok!(SubtensorModule::serve_axons(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            version,
+            vec![quic.clone(), grpc.clone()]
+        ));
+
+        // Endpoints are stored with the preferred one first.
+        assert_eq!(
+            SubtensorModule::get_axon_endpoints(netuid, &hotkey_account_id),
+            vec![grpc.clone(), quic]
+        );
+
+        // The single axon view mirrors the preferred endpoint.
+        let axon = SubtensorModule::get_axon_info(netuid, &hotkey_account_id);
+        assert_eq!(axon.version, version);
+        assert_eq!(axon.ip, grpc.ip);
+        assert_eq!(axon.port, grpc.port);
+        assert_eq!(axon.ip_type, grpc.ip_type);
+        assert_eq!(axon.protocol, grpc.protocol);
+
+        System::assert_last_event(
+            Event::AxonEndpointsServed {
+                netuid,
+                hotkey: hotkey_account_id,
+                count: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_serve_axons_invalid_endpoints() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid = NetUid::from(1);
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id);
+        let endpoint = AxonEndpoint {
+            protocol: 0,
+            ip: test::ipv4(8, 8, 8, 8),
+            ip_type: 4,
+            port: 8091,
+            priority: 0,
+        };
+
+        // Not registered.
+        assert_noop!(
+            SubtensorModule::serve_axons(origin.clone(), netuid, 2, vec![endpoint.clone()]),
+            Error::<Test>::HotKeyNotRegisteredInNetwork
+        );
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+
+        assert_noop!(
+            SubtensorModule::serve_axons(origin.clone(), netuid, 2, vec![]),
+            Error::<Test>::NoAxonEndpoints
+        );
+        assert_noop!(
+            SubtensorModule::serve_axons(
+                origin.clone(),
+                netuid,
+                2,
+                vec![endpoint.clone(); MAX_AXON_ENDPOINTS as usize + 1]
+            ),
+            Error::<Test>::TooManyAxonEndpoints
+        );
+        assert_noop!(
+            SubtensorModule::serve_axons(
+                origin.clone(),
+                netuid,
+                2,
+                vec![
+                    endpoint.clone(),
+                    AxonEndpoint {
+                        priority: 1,
+                        ..endpoint.clone()
+                    }
+                ]
+            ),
+            Error::<Test>::DuplicateAxonEndpoint
+        );
+        assert_noop!(
+            SubtensorModule::serve_axons(
+                origin.clone(),
+                netuid,
+                2,
+                vec![AxonEndpoint {
+                    port: 0,
+                    ..endpoint.clone()
+                }]
+            ),
+            Error::<Test>::InvalidPort
+        );
+        assert_noop!(
+            SubtensorModule::serve_axons(
+                origin.clone(),
+                netuid,
+                2,
+                vec![AxonEndpoint {
+                    ip_type: 5,
+                    ..endpoint.clone()
+                }]
+            ),
+            Error::<Test>::InvalidIpType
+        );
+        assert_noop!(
+            SubtensorModule::serve_axons(
+                origin,
+                netuid,
+                2,
+                vec![AxonEndpoint {
+                    ip: u32::MAX as u128,
+                    ..endpoint
+                }]
+            ),
+            Error::<Test>::InvalidIpAddress
+        );
+    });
+}
+
+#[test]
+fn test_serve_axon_replaces_axon_endpoints() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid = NetUid::from(1);
+        let origin = <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+
+        // Neurons without a served axon have no endpoints.
+        assert!(SubtensorModule::get_axon_endpoints(netuid, &hotkey_account_id).is_empty());
+
+        let endpoints = vec![
+            AxonEndpoint {
+                protocol: 0,
+                ip: test::ipv4(8, 8, 8, 8),
+                ip_type: 4,
+                port: 8091,
+                priority: 0,
+            },
+            AxonEndpoint {
+                protocol: 1,
+                ip: test::ipv4(8, 8, 4, 4),
+                ip_type: 4,
+                port: 8092,
+                priority: 1,
+            },
+        ];
+        assert_ok!(SubtensorModule::serve_axons(
+            origin.clone(),
+            netuid,
+            2,
+            endpoints
+        ));
+        assert_eq!(
+            SubtensorModule::get_neuron_axon_endpoints(netuid, 0).len(),
+            2
+        );
+
+        // Serving a single axon replaces the endpoint list.
+        let ip = test::ipv4(1, 1, 1, 1);
+        assert_ok!(SubtensorModule::serve_axon(
+            origin, netuid, 3, ip, 9000, 4, 0, 0, 0
+        ));
+        assert!(AxonEndpoints::<Test>::get(netuid, hotkey_account_id).is_none());
+        assert_eq!(
+            SubtensorModule::get_neuron_axon_endpoints(netuid, 0),
+            vec![AxonEndpoint {
+                protocol: 0,
+                ip,
+                ip_type: 4,
+                port: 9000,
+                priority: 0,
+            }]
+        );
+    });
+}
+
+#[test]
+fn test_identity_attestation_lifecycle() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(2);
+        let attester = U256::from(3);
+        let netuid = NetUid::from(1);
+        let target = IdentityAttestationTarget::Coldkey(coldkey);
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        assert_ok!(SubtensorModule::do_set_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            b"Alice".to_vec(),
+            b"https://alice.com".to_vec(),
+            b"https://github.com/alice".to_vec(),
+            vec![],
+            b"alice#1234".to_vec(),
+            vec![],
+            vec![]
+        ));
+
+        // Only appointed attesters can attest.
+        assert_noop!(
+            SubtensorModule::attest_identity(
+                <<Test as Config>::RuntimeOrigin>::signed(attester),
+                target.clone(),
+                IdentityField::GithubRepo,
+                100
+            ),
+            Error::<Test>::NotIdentityAttester
+        );
+        assert_noop!(
+            SubtensorModule::add_identity_attester(
+                <<Test as Config>::RuntimeOrigin>::signed(attester),
+                attester
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::add_identity_attester(
+            RawOrigin::Root.into(),
+            attester
+        ));
+
+        // Empty fields and past expiries are rejected.
+        assert_noop!(
+            SubtensorModule::attest_identity(
+                <<Test as Config>::RuntimeOrigin>::signed(attester),
+                target.clone(),
+                IdentityField::Image,
+                100
+            ),
+            Error::<Test>::IdentityFieldNotSet
+        );
+        assert_noop!(
+            SubtensorModule::attest_identity(
+                <<Test as Config>::RuntimeOrigin>::signed(attester),
+                target.clone(),
+                IdentityField::GithubRepo,
+                SubtensorModule::get_current_block_as_u64()
+            ),
+            Error::<Test>::IdentityAttestationExpired
+        );
+
+        let expires_at = SubtensorModule::get_current_block_as_u64() + 10;
+        assert_ok!(SubtensorModule::attest_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(attester),
+            target.clone(),
+            IdentityField::GithubRepo,
+            expires_at
+        ));
+        System::assert_last_event(
+            Event::IdentityAttested {
+                attester,
+                target: target.clone(),
+                field: IdentityField::GithubRepo,
+                expires_at,
+            }
+            .into(),
+        );
+        assert_ok!(SubtensorModule::attest_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(attester),
+            target.clone(),
+            IdentityField::Discord,
+            expires_at
+        ));
+        assert_eq!(
+            SubtensorModule::get_valid_identity_attestations(&target).len(),
+            2
+        );
+
+        // Changing the attested field invalidates its attestation only.
+        assert_ok!(SubtensorModule::do_set_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            b"Alice".to_vec(),
+            b"https://alice.com".to_vec(),
+            b"https://github.com/mallory".to_vec(),
+            vec![],
+            b"alice#1234".to_vec(),
+            vec![],
+            vec![]
+        ));
+        let valid = SubtensorModule::get_valid_identity_attestations(&target);
+        assert_eq!(valid.len(), 1);
+        assert_eq!(valid[0].field, IdentityField::Discord);
+
+        // Revoked attestations are removed.
+        assert_ok!(SubtensorModule::revoke_identity_attestation(
+            <<Test as Config>::RuntimeOrigin>::signed(attester),
+            target.clone(),
+            IdentityField::Discord
+        ));
+        assert!(SubtensorModule::get_valid_identity_attestations(&target).is_empty());
+        assert_noop!(
+            SubtensorModule::revoke_identity_attestation(
+                <<Test as Config>::RuntimeOrigin>::signed(attester),
+                target.clone(),
+                IdentityField::Discord
+            ),
+            Error::<Test>::IdentityAttestationNotFound
+        );
+    });
+}
+
+#[test]
+fn test_identity_attestation_expiry_and_attester_removal() {
+    new_test_ext(1).execute_with(|| {
+        let owner_coldkey = U256::from(1);
+        let attester = U256::from(3);
+        let netuid = NetUid::from(1);
+        let target = IdentityAttestationTarget::Subnet(netuid);
+
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner_coldkey);
+        assert_ok!(SubtensorModule::do_set_subnet_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(owner_coldkey),
+            netuid,
+            b"Subnet".to_vec(),
+            b"https://github.com/subnet".to_vec(),
+            b"contact@subnet.com".to_vec(),
+            b"https://subnet.com".to_vec(),
+            b"subnet#1234".to_vec(),
+            vec![],
+            vec![],
+            vec![]
+        ));
+        assert_ok!(SubtensorModule::add_identity_attester(
+            RawOrigin::Root.into(),
+            attester
+        ));
+
+        let expires_at = SubtensorModule::get_current_block_as_u64() + 5;
+        assert_ok!(SubtensorModule::attest_identity(
+            <<Test as Config>::RuntimeOrigin>::signed(attester),
+            target.clone(),
+            IdentityField::Contact,
+            expires_at
+        ));
+        assert_eq!(
+            SubtensorModule::get_valid_identity_attestations(&target).len(),
+            1
+        );
+
+        // Attestations of a removed attester are no longer valid.
+        assert_ok!(SubtensorModule::remove_identity_attester(
+            RawOrigin::Root.into(),
+            attester
+        ));
+        assert!(SubtensorModule::get_valid_identity_attestations(&target).is_empty());
+        assert_ok!(SubtensorModule::add_identity_attester(
+            RawOrigin::Root.into(),
+            attester
+        ));
+        assert_eq!(
+            SubtensorModule::get_valid_identity_attestations(&target).len(),
+            1
+        );
+
+        // Attestations expire.
+        step_block(5);
+        assert!(SubtensorModule::get_valid_identity_attestations(&target).is_empty());
+    });
+}
@@ -1,10 +1,14 @@
 #![allow(clippy::unwrap_used)]
 use super::mock::*;
+use crate::subnets::hyperparameter_schedule::ScheduledHyperparameterChange;
+use crate::subnets::roles::{Hyperparameter, HyperparameterBound, HyperparameterGroup};
 use crate::subnets::symbols::{DEFAULT_SYMBOL, SYMBOLS};
+use crate::utils::rate_limiting::TransactionType;
 use crate::*;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
 use sp_core::U256;
+use sp_runtime::BuildStorage;
 use subtensor_runtime_common::AlphaCurrency;
 
 use super::mock;
@@ -197,6 +201,142 @@ fn test_do_start_call_ok_with_same_block_number_after_coinbase() {
     });
 }
 
+#[test]
+fn test_genesis_timing_overrides() {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    crate::GenesisConfig::<Test> {
+        start_call_delay: Some(5),
+        pending_childkey_cooldown: Some(15),
+        set_children_rate_limit: Some(15),
+        childkey_take_rate_limit: Some(5),
+        initial_subnet_tempo: Some(10),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        assert_eq!(PendingChildKeyCooldown::<Test>::get(), 15);
+        assert_eq!(
+            SubtensorModule::get_rate_limit(&TransactionType::SetChildren),
+            15
+        );
+        assert_eq!(TxChildkeyTakeRateLimit::<Test>::get(), 5);
+        assert_eq!(InitialSubnetTempo::<Test>::get(), 10);
+
+        let netuid = NetUid::from(2);
+        add_network_without_emission_block(netuid, 13, 0);
+        mock::setup_reserves(netuid, 1_000_000_000, 1_000_000_000.into());
+        let owner = SubnetOwner::<Test>::get(netuid);
+
+        System::set_block_number(4);
+        assert_noop!(
+            SubtensorModule::start_call(<<Test as Config>::RuntimeOrigin>::signed(owner), netuid),
+            Error::<Test>::NeedWaitingMoreBlocksToStarCall
+        );
+
+        System::set_block_number(5);
+        assert_ok!(SubtensorModule::start_call(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid
+        ));
+    });
+}
+
+#[test]
+fn test_genesis_snapshot_round_trip() {
+    let snapshot = new_test_ext(1).execute_with(|| {
+        let owner_hotkey = U256::from(1);
+        let owner_coldkey = U256::from(2);
+        let staker = U256::from(3);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+
+        assert_ok!(SubtensorModule::start_call(
+            <<Test as Config>::RuntimeOrigin>::signed(owner_coldkey),
+            netuid
+        ));
+        SubtensorModule::set_kappa(netuid, 1_234);
+        SubtensorModule::set_burn(netuid, 42);
+        IdentitiesV2::<Test>::insert(
+            owner_coldkey,
+            ChainIdentityV2 {
+                name: b"owner".to_vec(),
+                ..Default::default()
+            },
+        );
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &staker,
+            netuid,
+            AlphaCurrency::from(500_000),
+        );
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &staker,
+            NetUid::ROOT,
+            AlphaCurrency::from(700_000),
+        );
+
+        SubtensorModule::take_snapshot()
+    });
+    assert_eq!(snapshot.subnets.len(), 1);
+    assert_eq!(snapshot.stakes.len(), 2);
+
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    crate::GenesisConfig::<Test> {
+        snapshot: Some(snapshot.clone()),
+        balances_issuance: 1_000,
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        assert_eq!(SubtensorModule::take_snapshot(), snapshot);
+
+        let subnet = &snapshot.subnets[0];
+        assert_eq!(SubtensorModule::get_kappa(subnet.netuid), 1_234);
+        assert!(SubtokenEnabled::<Test>::get(subnet.netuid));
+        assert_eq!(SubnetTAO::<Test>::get(NetUid::ROOT), 700_000);
+        assert_eq!(
+            TotalStake::<Test>::get(),
+            subnet.tao_reserve.saturating_add(700_000)
+        );
+        assert_eq!(
+            TotalIssuance::<Test>::get(),
+            TotalStake::<Test>::get().saturating_add(1_000)
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Invalid hyperparameter Kappa")]
+fn test_genesis_snapshot_rejects_invalid_hyperparameter() {
+    let snapshot = crate::utils::snapshot::SubtensorSnapshot {
+        subnets: vec![crate::utils::snapshot::SubnetSnapshot {
+            netuid: NetUid::from(1),
+            owner_coldkey: U256::from(2),
+            owner_hotkey: U256::from(1),
+            hyperparameters: vec![(Hyperparameter::Kappa, u64::MAX)],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let _ = crate::GenesisConfig::<Test> {
+        snapshot: Some(snapshot),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage);
+}
+
 #[test]
 fn test_register_network_min_burn_at_default() {
     new_test_ext(1).execute_with(|| {
@@ -890,3 +1030,392 @@ fn test_update_symbol_fails_if_symbol_already_in_use() {
         );
     });
 }
+
+#[test]
+fn test_grant_subnet_role_validation() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        let delegate = U256::from(2);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        // Only the subnet owner can grant a role.
+        assert_noop!(
+            SubtensorModule::grant_subnet_role(
+                RuntimeOrigin::signed(delegate),
+                netuid,
+                delegate,
+                vec![HyperparameterGroup::Weights],
+                vec![],
+                None,
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // A role needs at least one group.
+        assert_noop!(
+            SubtensorModule::grant_subnet_role(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                delegate,
+                vec![],
+                vec![],
+                None,
+            ),
+            Error::<Test>::InvalidSubnetRole
+        );
+
+        // Bounds cannot be inverted.
+        assert_noop!(
+            SubtensorModule::grant_subnet_role(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                delegate,
+                vec![HyperparameterGroup::Weights],
+                vec![HyperparameterBound {
+                    param: Hyperparameter::MaxWeightLimit,
+                    min: 10,
+                    max: 5,
+                }],
+                None,
+            ),
+            Error::<Test>::InvalidSubnetRole
+        );
+
+        // The expiry must be in the future.
+        assert_noop!(
+            SubtensorModule::grant_subnet_role(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                delegate,
+                vec![HyperparameterGroup::Weights],
+                vec![],
+                Some(1),
+            ),
+            Error::<Test>::SubnetRoleExpired
+        );
+
+        assert_ok!(SubtensorModule::grant_subnet_role(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            delegate,
+            vec![HyperparameterGroup::Weights, HyperparameterGroup::Weights],
+            vec![],
+            Some(100),
+        ));
+        System::assert_last_event(
+            Event::SubnetRoleGranted {
+                netuid,
+                delegate,
+                expires_at: Some(100),
+            }
+            .into(),
+        );
+
+        let roles = SubtensorModule::get_subnet_roles(netuid);
+        assert_eq!(roles.len(), 1);
+        let role = SubnetRoles::<Test>::get(netuid, delegate).unwrap();
+        assert_eq!(role.groups.into_inner(), vec![HyperparameterGroup::Weights]);
+
+        // Root can revoke a role, but only once.
+        assert_ok!(SubtensorModule::revoke_subnet_role(
+            RuntimeOrigin::root(),
+            netuid,
+            delegate,
+        ));
+        System::assert_last_event(Event::SubnetRoleRevoked { netuid, delegate }.into());
+        assert_noop!(
+            SubtensorModule::revoke_subnet_role(RuntimeOrigin::signed(owner), netuid, delegate),
+            Error::<Test>::SubnetRoleNotFound
+        );
+    });
+}
+
+#[test]
+fn test_schedule_hyperparameter_change_applies_at_effective_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        let initial_kappa = SubtensorModule::get_kappa(netuid);
+
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            Hyperparameter::Kappa,
+            1_000,
+            5,
+        ));
+        System::assert_last_event(
+            Event::HyperparameterChangeScheduled {
+                netuid,
+                param: Hyperparameter::Kappa,
+                value: 1_000,
+                effective_block: 5,
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_scheduled_hyperparameter_changes(netuid),
+            vec![ScheduledHyperparameterChange {
+                param: Hyperparameter::Kappa,
+                value: 1_000,
+                scheduled_at: 1,
+                effective_block: 5,
+                by_root: false,
+            }]
+        );
+        assert!(HyperparameterChangesDue::<Test>::get(5, netuid));
+
+        step_block(3);
+        assert_eq!(SubtensorModule::get_kappa(netuid), initial_kappa);
+
+        step_block(1);
+        assert_eq!(SubtensorModule::get_kappa(netuid), 1_000);
+        assert!(SubtensorModule::get_scheduled_hyperparameter_changes(netuid).is_empty());
+        assert!(!HyperparameterChangesDue::<Test>::contains_key(5, netuid));
+        System::assert_has_event(
+            Event::HyperparameterChangeApplied {
+                netuid,
+                param: Hyperparameter::Kappa,
+                value: 1_000,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_schedule_hyperparameter_change_validation() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        // Only the owner, root or a delegate can schedule a change.
+        assert_noop!(
+            SubtensorModule::schedule_hyperparameter_change(
+                RuntimeOrigin::signed(U256::from(2)),
+                netuid,
+                Hyperparameter::Kappa,
+                1_000,
+                5,
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // The value must fit the hyperparameter.
+        assert_noop!(
+            SubtensorModule::schedule_hyperparameter_change(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                Hyperparameter::Kappa,
+                u64::from(u16::MAX) + 1,
+                5,
+            ),
+            Error::<Test>::InvalidHyperparameterValue
+        );
+
+        // The change must take effect in a future block.
+        assert_noop!(
+            SubtensorModule::schedule_hyperparameter_change(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                Hyperparameter::Kappa,
+                1_000,
+                1,
+            ),
+            Error::<Test>::HyperparameterNoticeTooShort
+        );
+
+        // Root can require a minimum notice.
+        SubtensorModule::set_hyperparameter_min_notice(Hyperparameter::Kappa, 100);
+        assert_noop!(
+            SubtensorModule::schedule_hyperparameter_change(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                Hyperparameter::Kappa,
+                1_000,
+                100,
+            ),
+            Error::<Test>::HyperparameterNoticeTooShort
+        );
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            Hyperparameter::Kappa,
+            1_000,
+            101,
+        ));
+
+        // Scheduling the same hyperparameter again replaces the pending change.
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            Hyperparameter::Kappa,
+            2_000,
+            150,
+        ));
+        let pending = SubtensorModule::get_scheduled_hyperparameter_changes(netuid);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].value, 2_000);
+        assert!(!HyperparameterChangesDue::<Test>::contains_key(101, netuid));
+        assert!(HyperparameterChangesDue::<Test>::get(150, netuid));
+
+        assert_ok!(SubtensorModule::cancel_hyperparameter_change(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            Hyperparameter::Kappa,
+        ));
+        assert!(SubtensorModule::get_scheduled_hyperparameter_changes(netuid).is_empty());
+        assert_noop!(
+            SubtensorModule::cancel_hyperparameter_change(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                Hyperparameter::Kappa,
+            ),
+            Error::<Test>::ScheduledHyperparameterChangeNotFound
+        );
+    });
+}
+
+#[test]
+fn test_schedule_negative_alpha_sigmoid_steepness() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        let negative = i64::from(-300i16) as u64;
+
+        // Only root may schedule a negative steepness.
+        assert_noop!(
+            SubtensorModule::schedule_hyperparameter_change(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                Hyperparameter::AlphaSigmoidSteepness,
+                negative,
+                5,
+            ),
+            Error::<Test>::InvalidHyperparameterValue
+        );
+
+        // The value must fit a signed 16-bit steepness.
+        assert_noop!(
+            SubtensorModule::schedule_hyperparameter_change(
+                RuntimeOrigin::root(),
+                netuid,
+                Hyperparameter::AlphaSigmoidSteepness,
+                i64::from(i16::MIN).saturating_sub(1) as u64,
+                5,
+            ),
+            Error::<Test>::InvalidHyperparameterValue
+        );
+
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            RuntimeOrigin::root(),
+            netuid,
+            Hyperparameter::AlphaSigmoidSteepness,
+            negative,
+            5,
+        ));
+        step_block(4);
+        assert_eq!(AlphaSigmoidSteepness::<Test>::get(netuid), -300);
+        assert_eq!(
+            SubtensorModule::hyperparameter_value(netuid, Hyperparameter::AlphaSigmoidSteepness),
+            negative
+        );
+    });
+}
+
+#[test]
+fn test_scheduled_hyperparameter_change_checked_when_applied() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        let initial_kappa = SubtensorModule::get_kappa(netuid);
+
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            Hyperparameter::Kappa,
+            1_000,
+            5,
+        ));
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            RuntimeOrigin::root(),
+            netuid,
+            Hyperparameter::Rho,
+            2_000,
+            5,
+        ));
+
+        // Root narrows the bounds after the owner's change was queued.
+        SubtensorModule::set_hyperparameter_bounds(Hyperparameter::Kappa, Some((0, 500)));
+        SubtensorModule::set_hyperparameter_bounds(Hyperparameter::Rho, Some((0, 500)));
+
+        step_block(4);
+        assert_eq!(SubtensorModule::get_kappa(netuid), initial_kappa);
+        System::assert_has_event(
+            Event::HyperparameterChangeFailed {
+                netuid,
+                param: Hyperparameter::Kappa,
+                value: 1_000,
+                error: Error::<Test>::ValueOutOfBounds {
+                    param: Hyperparameter::Kappa,
+                }
+                .into(),
+            }
+            .into(),
+        );
+
+        // Changes queued by root are not subject to the bounds.
+        assert_eq!(SubtensorModule::get_rho(netuid), 2_000);
+        assert!(SubtensorModule::get_scheduled_hyperparameter_changes(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_owner_hyperparameter_changes_cancelled_on_transfer() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            Hyperparameter::Kappa,
+            1_000,
+            5,
+        ));
+        assert_ok!(SubtensorModule::schedule_hyperparameter_change(
+            RuntimeOrigin::root(),
+            netuid,
+            Hyperparameter::Rho,
+            2_000,
+            10,
+        ));
+
+        SubtensorModule::cancel_owner_hyperparameter_changes(netuid);
+        System::assert_last_event(
+            Event::HyperparameterChangeCancelled {
+                netuid,
+                param: Hyperparameter::Kappa,
+            }
+            .into(),
+        );
+
+        let pending = SubtensorModule::get_scheduled_hyperparameter_changes(netuid);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].param, Hyperparameter::Rho);
+        assert!(!HyperparameterChangesDue::<Test>::contains_key(5, netuid));
+        assert!(HyperparameterChangesDue::<Test>::get(10, netuid));
+    });
+}
@@ -17,6 +17,7 @@ use subtensor_swap_interface::{OrderType, SwapHandler};
 
 use super::mock;
 use super::mock::*;
+use crate::subnets::roles::HyperparameterGroup;
 use crate::*;
 use crate::{Call, ColdkeySwapScheduleDuration, Error};
 
@@ -1228,6 +1229,17 @@ fn test_swap_subnet_owner_for_coldkey() {
         SubnetOwner::<Test>::insert(netuid1, old_coldkey);
         SubnetOwner::<Test>::insert(netuid2, old_coldkey);
 
+        // Delegate a role on the first subnet
+        let delegate = U256::from(3);
+        assert_ok!(SubtensorModule::grant_subnet_role(
+            RuntimeOrigin::signed(old_coldkey),
+            netuid1,
+            delegate,
+            vec![HyperparameterGroup::Weights],
+            vec![],
+            None,
+        ));
+
         // Set up TotalNetworks
         TotalNetworks::<Test>::put(3);
 
@@ -1237,6 +1249,9 @@ fn test_swap_subnet_owner_for_coldkey() {
         // Verify the swap
         assert_eq!(SubnetOwner::<Test>::get(netuid1), new_coldkey);
         assert_eq!(SubnetOwner::<Test>::get(netuid2), new_coldkey);
+
+        // Verify the roles delegated by the old coldkey were removed
+        assert!(!SubnetRoles::<Test>::contains_key(netuid1, delegate));
     });
 }
 
@@ -29,7 +29,8 @@ use w3f_bls::EngineBLS;
 
 use super::mock;
 use super::mock::*;
-use crate::coinbase::reveal_commits::WeightsTlockPayload;
+use crate::coinbase::reveal_commits::{RevealFailureReason, WeightsTlockPayload};
+use crate::rpc_info::weight_commits::WeightCommitKind;
 use crate::*;
 
 /***************************
@@ -5329,6 +5330,96 @@ fn test_reveal_crv3_commits_decryption_failure() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_reveal_crv3_commits_failure_emits_reason --exact --show-output --nocapture
+#[test]
+fn test_reveal_crv3_commits_failure_emits_reason() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey: AccountId = U256::from(1);
+        let reveal_round: u64 = 1000;
+
+        add_network(netuid, 5, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        let bounded_commit_bytes = vec![0xff; 100]
+            .try_into()
+            .expect("Failed to convert commit bytes into bounded vector");
+
+        assert_ok!(SubtensorModule::do_commit_crv3_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            bounded_commit_bytes,
+            reveal_round
+        ));
+
+        let commits = SubtensorModule::get_weight_commits(netuid, Some(hotkey));
+        assert_eq!(commits.len(), 1);
+        assert_eq!(
+            commits[0].kind,
+            WeightCommitKind::Timelocked { reveal_round }
+        );
+
+        step_epochs(1, netuid);
+        assert_ok!(SubtensorModule::reveal_crv3_commits(netuid));
+
+        // The commit bytes are not a timelock ciphertext.
+        assert!(System::events().iter().any(|record| {
+            record.event
+                == RuntimeEvent::SubtensorModule(Event::CRV3WeightsRevealFailed {
+                    netuid,
+                    hotkey,
+                    reason: RevealFailureReason::CommitDeserialization,
+                })
+        }));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_get_weight_commits_hash_commit_reveal_window --exact --show-output --nocapture
+#[test]
+fn test_get_weight_commits_hash_commit_reveal_window() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey: U256 = U256::from(1);
+        let commit_hash: H256 = H256::repeat_byte(7);
+
+        System::set_block_number(0);
+        add_network(netuid, 5, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+
+        let commits = SubtensorModule::get_weight_commits(netuid, None);
+        assert_eq!(commits.len(), 1);
+        let commit = &commits[0];
+        let (_, commit_block, first_reveal_block, last_reveal_block) =
+            WeightCommits::<Test>::get(netuid, hotkey).unwrap()[0];
+        assert_eq!(commit.hotkey, hotkey);
+        assert_eq!(commit.kind, WeightCommitKind::Hash(commit_hash));
+        assert_eq!(commit.commit_block.0, commit_block);
+        assert_eq!(commit.first_reveal_block.0, first_reveal_block);
+        assert_eq!(commit.last_reveal_block.0, last_reveal_block);
+        assert_eq!(
+            commit.reveal_epoch.0,
+            SubtensorModule::get_epoch_index(netuid, first_reveal_block)
+        );
+
+        // Other hotkeys have no commits.
+        assert!(SubtensorModule::get_weight_commits(netuid, Some(U256::from(3))).is_empty());
+
+        // Expired commits are no longer listed.
+        System::set_block_number(last_reveal_block + 1);
+        assert!(SubtensorModule::get_weight_commits(netuid, None).is_empty());
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_reveal_crv3_commits_multiple_commits_some_fail_some_succeed --exact --show-output --nocapture
 #[test]
 fn test_reveal_crv3_commits_multiple_commits_some_fail_some_succeed() {
@@ -6437,3 +6528,398 @@ fn test_get_first_block_of_epoch_step_blocks_and_assert_with_until_next() {
         }
     });
 }
+
+fn setup_weights_validator(netuid: NetUid, hotkey: U256, coldkey: U256) {
+    add_network(netuid, 13, 0);
+    SubtensorModule::set_min_allowed_weights(netuid, 0);
+    SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+    SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+    register_ok_neuron(netuid, hotkey, coldkey, 0);
+    register_ok_neuron(netuid, U256::from(100), U256::from(101), 65555);
+
+    let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+    SubtensorModule::set_validator_permit_for_uid(netuid, uid, true);
+    SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+        &hotkey,
+        &coldkey,
+        netuid,
+        1.into(),
+    );
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_weights_key_sets_weights_for_hotkey --exact --show-output --nocapture
+#[test]
+fn test_weights_key_sets_weights_for_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let weights_key = U256::from(3);
+        setup_weights_validator(netuid, hotkey, U256::from(2));
+
+        // Without authorization the key is not a neuron.
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(weights_key),
+                netuid,
+                vec![1],
+                vec![1],
+                0
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        assert_ok!(SubtensorModule::set_weights_key(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            weights_key
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(Event::WeightsKeySet {
+            netuid,
+            hotkey,
+            weights_key,
+        }));
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(weights_key),
+            netuid,
+            vec![1],
+            vec![1],
+            0
+        ));
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        assert_eq!(Weights::<Test>::get(netuid, uid), vec![(1, u16::MAX)]);
+
+        assert_eq!(
+            SubtensorModule::get_neuron_weights_key(netuid, uid),
+            Some(weights_key)
+        );
+
+        // Once revoked, the key can no longer set weights.
+        assert_ok!(SubtensorModule::remove_weights_key(
+            RuntimeOrigin::signed(hotkey),
+            netuid
+        ));
+        assert_err!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(weights_key),
+                netuid,
+                vec![1],
+                vec![1],
+                0
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+        assert_err!(
+            SubtensorModule::remove_weights_key(RuntimeOrigin::signed(hotkey), netuid),
+            Error::<Test>::WeightsKeyNotFound
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_weights_key_commits_and_reveals_for_hotkey --exact --show-output --nocapture
+#[test]
+fn test_weights_key_commits_and_reveals_for_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let weights_key = U256::from(3);
+        setup_weights_validator(netuid, hotkey, U256::from(2));
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        assert_ok!(SubtensorModule::set_weights_key(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            weights_key
+        ));
+
+        // The commit is made for the hotkey, so its hash covers the hotkey.
+        let uids: Vec<u16> = vec![1];
+        let values: Vec<u16> = vec![1];
+        let salt: Vec<u16> = vec![1, 2, 3];
+        let commit_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            uids.clone(),
+            values.clone(),
+            salt.clone(),
+            0u64,
+        ));
+
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(weights_key),
+            netuid,
+            commit_hash
+        ));
+        assert!(WeightCommits::<Test>::contains_key(netuid, hotkey));
+        assert!(!WeightCommits::<Test>::contains_key(netuid, weights_key));
+
+        step_epochs(1, netuid);
+
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(weights_key),
+            netuid,
+            uids,
+            values,
+            salt,
+            0
+        ));
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        assert_eq!(Weights::<Test>::get(netuid, uid), vec![(1, u16::MAX)]);
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_set_weights_key_validation --exact --show-output --nocapture
+#[test]
+fn test_set_weights_key_validation() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let other_hotkey = U256::from(100);
+        let weights_key = U256::from(3);
+        setup_weights_validator(netuid, hotkey, U256::from(2));
+
+        // Only registered hotkeys can authorize a weights key.
+        assert_err!(
+            SubtensorModule::set_weights_key(
+                RuntimeOrigin::signed(U256::from(4)),
+                netuid,
+                weights_key
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        // A registered hotkey cannot act for another one.
+        assert_err!(
+            SubtensorModule::set_weights_key(RuntimeOrigin::signed(hotkey), netuid, other_hotkey),
+            Error::<Test>::InvalidWeightsKey
+        );
+
+        // A weights key acts for a single hotkey.
+        assert_ok!(SubtensorModule::set_weights_key(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            weights_key
+        ));
+        assert_err!(
+            SubtensorModule::set_weights_key(
+                RuntimeOrigin::signed(other_hotkey),
+                netuid,
+                weights_key
+            ),
+            Error::<Test>::WeightsKeyAlreadyInUse
+        );
+
+        // Replacing the key frees the previous one.
+        assert_ok!(SubtensorModule::set_weights_key(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            U256::from(5)
+        ));
+        assert_eq!(WeightsKeyHotkey::<Test>::get(netuid, weights_key), None);
+        assert_ok!(SubtensorModule::set_weights_key(
+            RuntimeOrigin::signed(other_hotkey),
+            netuid,
+            weights_key
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_weights_key_revoked_when_registered --exact --show-output --nocapture
+#[test]
+fn test_weights_key_revoked_when_registered() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let weights_key = U256::from(3);
+        setup_weights_validator(netuid, hotkey, U256::from(2));
+
+        assert_ok!(SubtensorModule::set_weights_key(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            weights_key
+        ));
+
+        // Once registered, the key signs for itself and no longer for the hotkey.
+        register_ok_neuron(netuid, weights_key, U256::from(4), 75555);
+        assert_eq!(WeightsKeys::<Test>::get(netuid, hotkey), None);
+        assert_eq!(WeightsKeyHotkey::<Test>::get(netuid, weights_key), None);
+        System::assert_has_event(RuntimeEvent::SubtensorModule(Event::WeightsKeyRemoved {
+            netuid,
+            hotkey,
+        }));
+        assert_eq!(
+            SubtensorModule::get_weights_signer_hotkey(netuid, &weights_key),
+            weights_key
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_set_weights_delta_merges_stored_weights --exact --show-output --nocapture
+#[test]
+fn test_set_weights_delta_merges_stored_weights() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        setup_weights_validator(netuid, hotkey, U256::from(2));
+        register_ok_neuron(netuid, U256::from(102), U256::from(103), 75555);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![1, 2],
+            vec![u16::MAX, u16::MAX / 2],
+            0
+        ));
+
+        // Only uid 2 changes, uid 1 keeps its stored weight.
+        assert_ok!(SubtensorModule::set_weights_delta(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![2],
+            vec![u16::MAX],
+            0
+        ));
+        assert_eq!(
+            Weights::<Test>::get(netuid, uid),
+            vec![(1, u16::MAX), (2, u16::MAX)]
+        );
+
+        // A zero weight removes the uid.
+        assert_ok!(SubtensorModule::set_weights_delta(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![1],
+            vec![0],
+            0
+        ));
+        assert_eq!(Weights::<Test>::get(netuid, uid), vec![(2, u16::MAX)]);
+
+        assert_err!(
+            SubtensorModule::set_weights_delta(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![1, 1],
+                vec![1, 2],
+                0
+            ),
+            Error::<Test>::DuplicateUids
+        );
+        assert_err!(
+            SubtensorModule::set_weights_delta(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![1],
+                vec![1, 2],
+                0
+            ),
+            Error::<Test>::WeightVecNotEqualSize
+        );
+
+        // The merged weights are checked like any other.
+        assert_err!(
+            SubtensorModule::set_weights_delta(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![42],
+                vec![1],
+                0
+            ),
+            Error::<Test>::UidVecContainInvalidOne
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_reveal_weights_delta --exact --show-output --nocapture
+#[test]
+fn test_reveal_weights_delta() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        setup_weights_validator(netuid, hotkey, U256::from(2));
+        register_ok_neuron(netuid, U256::from(102), U256::from(103), 75555);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![1],
+            vec![u16::MAX],
+            0
+        ));
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        assert_err!(
+            SubtensorModule::set_weights_delta(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![2],
+                vec![u16::MAX],
+                0
+            ),
+            Error::<Test>::CommitRevealEnabled
+        );
+
+        let uids: Vec<u16> = vec![2];
+        let values: Vec<u16> = vec![u16::MAX];
+        let salt: Vec<u16> = vec![9, 8, 7];
+        let weights_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            uids.clone(),
+            values.clone(),
+            salt.clone(),
+            0u64,
+        ));
+        let delta_hash: H256 = BlakeTwo256::hash_of(&(
+            b"delta",
+            hotkey,
+            netuid,
+            uids.clone(),
+            values.clone(),
+            salt.clone(),
+            0u64,
+        ));
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            weights_hash
+        ));
+
+        step_epochs(1, netuid);
+
+        // a commit of full weights can't be revealed as changes
+        assert_err!(
+            SubtensorModule::reveal_weights_delta(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                values.clone(),
+                salt.clone(),
+                0
+            ),
+            Error::<Test>::InvalidRevealCommitHashNotMatch
+        );
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            delta_hash
+        ));
+
+        step_epochs(1, netuid);
+
+        assert_ok!(SubtensorModule::reveal_weights_delta(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids,
+            values,
+            salt,
+            0
+        ));
+        assert_eq!(
+            Weights::<Test>::get(netuid, uid),
+            vec![(1, u16::MAX), (2, u16::MAX)]
+        );
+        assert!(!WeightCommits::<Test>::contains_key(netuid, hotkey));
+    });
+}
@@ -0,0 +1,244 @@
+use super::*;
+use frame_support::{ensure, pallet_prelude::BoundedVec};
+use frame_system::{ensure_root, ensure_signed};
+use sp_core::{ConstU32, H256, blake2_256};
+use sp_std::vec::Vec;
+use subtensor_runtime_common::NetUid;
+
+/// Maximum number of attestations stored for a single identity.
+pub const MAX_IDENTITY_ATTESTATIONS: u32 = 32;
+
+/// A field of a coldkey or subnet identity that can be attested.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum IdentityField {
+    /// `name` of a coldkey identity, `subnet_name` of a subnet identity.
+    Name,
+    /// `url` of a coldkey identity, `subnet_url` of a subnet identity.
+    Url,
+    /// `github_repo`.
+    GithubRepo,
+    /// `image`, coldkey identities only.
+    Image,
+    /// `discord`.
+    Discord,
+    /// `description`.
+    Description,
+    /// `additional`.
+    Additional,
+    /// `subnet_contact`, subnet identities only.
+    Contact,
+    /// `logo_url`, subnet identities only.
+    LogoUrl,
+}
+
+/// The identity an attestation refers to.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum IdentityAttestationTarget<AccountId> {
+    /// The chain identity of a coldkey.
+    Coldkey(AccountId),
+    /// The identity of a subnet.
+    Subnet(NetUid),
+}
+
+/// A statement by an attester that an identity field was verified.
+#[freeze_struct("378e7dd6828905ea")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct IdentityAttestation<AccountId> {
+    /// The attester who verified the field.
+    pub attester: AccountId,
+    /// The verified field.
+    pub field: IdentityField,
+    /// The hash of the field value at the time of the attestation.
+    pub value_hash: H256,
+    /// The block at which the attestation was made.
+    pub attested_at: u64,
+    /// The block at which the attestation expires.
+    pub expires_at: u64,
+}
+
+pub type IdentityAttestationsOf<T> = BoundedVec<
+    IdentityAttestation<<T as frame_system::Config>::AccountId>,
+    ConstU32<MAX_IDENTITY_ATTESTATIONS>,
+>;
+
+impl<T: Config> Pallet<T> {
+    /// Appoints an account as identity attester. Only callable by root (governance).
+    pub fn do_add_identity_attester(
+        origin: T::RuntimeOrigin,
+        attester: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        ensure_root(origin)?;
+
+        IdentityAttesters::<T>::insert(&attester, true);
+        Self::deposit_event(Event::IdentityAttesterAdded(attester));
+
+        Ok(())
+    }
+
+    /// Removes an identity attester. Its attestations are no longer returned.
+    pub fn do_remove_identity_attester(
+        origin: T::RuntimeOrigin,
+        attester: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        ensure_root(origin)?;
+
+        ensure!(
+            IdentityAttesters::<T>::get(&attester),
+            Error::<T>::NotIdentityAttester
+        );
+
+        IdentityAttesters::<T>::remove(&attester);
+        Self::deposit_event(Event::IdentityAttesterRemoved(attester));
+
+        Ok(())
+    }
+
+    /// Attests that `field` of the target identity was verified, until `expires_at`.
+    ///
+    /// The attestation is bound to the current value of the field and stops being valid
+    /// if the identity owner changes it. A previous attestation of the same field by the
+    /// same attester is replaced.
+    pub fn do_attest_identity(
+        origin: T::RuntimeOrigin,
+        target: IdentityAttestationTarget<T::AccountId>,
+        field: IdentityField,
+        expires_at: u64,
+    ) -> dispatch::DispatchResult {
+        let attester = ensure_signed(origin)?;
+
+        ensure!(
+            IdentityAttesters::<T>::get(&attester),
+            Error::<T>::NotIdentityAttester
+        );
+
+        let current_block = Self::get_current_block_as_u64();
+        ensure!(
+            expires_at > current_block,
+            Error::<T>::IdentityAttestationExpired
+        );
+
+        let value = Self::get_identity_field(&target, field)
+            .filter(|value| !value.is_empty())
+            .ok_or(Error::<T>::IdentityFieldNotSet)?;
+
+        let attestation = IdentityAttestation {
+            attester: attester.clone(),
+            field,
+            value_hash: blake2_256(&value).into(),
+            attested_at: current_block,
+            expires_at,
+        };
+
+        IdentityAttestations::<T>::try_mutate(
+            &target,
+            |attestations| -> dispatch::DispatchResult {
+                attestations.retain(|existing| {
+                    !(existing.attester == attester && existing.field == field)
+                        && existing.expires_at > current_block
+                });
+                attestations
+                    .try_push(attestation)
+                    .map_err(|_| Error::<T>::TooManyIdentityAttestations)?;
+                Ok(())
+            },
+        )?;
+
+        Self::deposit_event(Event::IdentityAttested {
+            attester,
+            target,
+            field,
+            expires_at,
+        });
+
+        Ok(())
+    }
+
+    /// Revokes the attestation made by the caller for `field` of the target identity.
+    pub fn do_revoke_identity_attestation(
+        origin: T::RuntimeOrigin,
+        target: IdentityAttestationTarget<T::AccountId>,
+        field: IdentityField,
+    ) -> dispatch::DispatchResult {
+        let attester = ensure_signed(origin)?;
+
+        IdentityAttestations::<T>::try_mutate_exists(&target, |maybe_attestations| {
+            let attestations = maybe_attestations
+                .as_mut()
+                .ok_or(Error::<T>::IdentityAttestationNotFound)?;
+            let len_before = attestations.len();
+            attestations
+                .retain(|existing| !(existing.attester == attester && existing.field == field));
+            ensure!(
+                attestations.len() < len_before,
+                Error::<T>::IdentityAttestationNotFound
+            );
+            if attestations.is_empty() {
+                *maybe_attestations = None;
+            }
+            Ok::<(), Error<T>>(())
+        })?;
+
+        Self::deposit_event(Event::IdentityAttestationRevoked {
+            attester,
+            target,
+            field,
+        });
+
+        Ok(())
+    }
+
+    /// Returns the attestations of the target identity that are still valid: made by a
+    /// current attester, not expired, and matching the current value of the field.
+    pub fn get_valid_identity_attestations(
+        target: &IdentityAttestationTarget<T::AccountId>,
+    ) -> Vec<IdentityAttestation<T::AccountId>> {
+        let current_block = Self::get_current_block_as_u64();
+
+        IdentityAttestations::<T>::get(target)
+            .into_iter()
+            .filter(|attestation| {
+                attestation.expires_at > current_block
+                    && IdentityAttesters::<T>::get(&attestation.attester)
+                    && Self::get_identity_field(target, attestation.field).is_some_and(|value| {
+                        H256::from(blake2_256(&value)) == attestation.value_hash
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the current value of `field` in the target identity, if the identity has it.
+    fn get_identity_field(
+        target: &IdentityAttestationTarget<T::AccountId>,
+        field: IdentityField,
+    ) -> Option<Vec<u8>> {
+        match target {
+            IdentityAttestationTarget::Coldkey(coldkey) => {
+                let identity = IdentitiesV2::<T>::get(coldkey)?;
+                match field {
+                    IdentityField::Name => Some(identity.name),
+                    IdentityField::Url => Some(identity.url),
+                    IdentityField::GithubRepo => Some(identity.github_repo),
+                    IdentityField::Image => Some(identity.image),
+                    IdentityField::Discord => Some(identity.discord),
+                    IdentityField::Description => Some(identity.description),
+                    IdentityField::Additional => Some(identity.additional),
+                    IdentityField::Contact | IdentityField::LogoUrl => None,
+                }
+            }
+            IdentityAttestationTarget::Subnet(netuid) => {
+                let identity = SubnetIdentitiesV3::<T>::get(netuid)?;
+                match field {
+                    IdentityField::Name => Some(identity.subnet_name),
+                    IdentityField::Url => Some(identity.subnet_url),
+                    IdentityField::GithubRepo => Some(identity.github_repo),
+                    IdentityField::Discord => Some(identity.discord),
+                    IdentityField::Description => Some(identity.description),
+                    IdentityField::Additional => Some(identity.additional),
+                    IdentityField::Contact => Some(identity.subnet_contact),
+                    IdentityField::LogoUrl => Some(identity.logo_url),
+                    IdentityField::Image => None,
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use super::*;
 pub mod evm;
 pub mod identity;
+pub mod identity_attestation;
 pub mod misc;
 pub mod rate_limiting;
+pub mod snapshot;
 #[cfg(feature = "try-runtime")]
 pub mod try_state;
@@ -47,7 +47,7 @@ impl<T: Config> Pallet<T> {
     /// Get the rate limit for a specific transaction type
     pub fn get_rate_limit(tx_type: &TransactionType) -> u64 {
         match tx_type {
-            TransactionType::SetChildren => 150, // 30 minutes
+            TransactionType::SetChildren => SetChildrenRateLimit::<T>::get(),
             TransactionType::SetChildkeyTake => TxChildkeyTakeRateLimit::<T>::get(),
             TransactionType::RegisterNetwork => NetworkRateLimit::<T>::get(),
 
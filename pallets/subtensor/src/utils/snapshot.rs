@@ -0,0 +1,227 @@
+use super::*;
+use crate::subnets::roles::Hyperparameter;
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+/// The subtensor part of a chain state snapshot, from which a dynamic-TAO genesis can be
+/// rebuilt.
+///
+/// Swap pallet state is not included: concentrated-liquidity positions, ticks and accrued fees
+/// are lost, and each pool is rebuilt from its TAO and alpha reserves only.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubtensorSnapshot<AccountId> {
+    /// Hotkeys and the coldkeys owning them.
+    pub hotkeys: Vec<(AccountId, AccountId)>,
+    /// Coldkey identities.
+    pub identities: Vec<(AccountId, ChainIdentityV2)>,
+    /// Every subnet but root.
+    pub subnets: Vec<SubnetSnapshot<AccountId>>,
+    /// Non-zero stakes, root included.
+    pub stakes: Vec<StakeSnapshot<AccountId>>,
+}
+
+/// The state of a subnet in a snapshot.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubnetSnapshot<AccountId> {
+    pub netuid: NetUid,
+    pub owner_coldkey: AccountId,
+    pub owner_hotkey: AccountId,
+    pub mechanism: u16,
+    pub symbol: Vec<u8>,
+    pub identity: Option<SubnetIdentityV3>,
+    /// Whether the subnet has been started and emits.
+    pub started: bool,
+    pub tempo: u16,
+    pub max_allowed_uids: u16,
+    pub max_allowed_validators: u16,
+    pub burn: u64,
+    pub min_burn: u64,
+    pub max_burn: u64,
+    pub difficulty: u64,
+    pub min_difficulty: u64,
+    pub registration_allowed: bool,
+    pub target_registrations_per_interval: u16,
+    pub adjustment_interval: u16,
+    pub max_registrations_per_block: u16,
+    pub weights_set_rate_limit: u64,
+    /// Owner-settable hyperparameters.
+    pub hyperparameters: Vec<(Hyperparameter, u64)>,
+    /// TAO reserve of the subnet pool.
+    pub tao_reserve: u64,
+    /// Alpha reserve of the subnet pool.
+    pub alpha_in: AlphaCurrency,
+    /// Alpha outstanding, staked or not.
+    pub alpha_out: AlphaCurrency,
+    pub locked: u64,
+}
+
+/// The alpha a coldkey stakes to a hotkey on a subnet.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeSnapshot<AccountId> {
+    pub hotkey: AccountId,
+    pub coldkey: AccountId,
+    pub netuid: NetUid,
+    pub alpha: AlphaCurrency,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Captures the subtensor state needed to rebuild it in a new genesis.
+    pub fn take_snapshot() -> SubtensorSnapshot<T::AccountId> {
+        let hotkeys = Owner::<T>::iter().collect();
+        let identities = IdentitiesV2::<T>::iter().collect();
+
+        let subnets = Self::get_all_subnet_netuids()
+            .into_iter()
+            .filter(|netuid| !netuid.is_root())
+            .map(|netuid| SubnetSnapshot {
+                netuid,
+                owner_coldkey: SubnetOwner::<T>::get(netuid),
+                owner_hotkey: SubnetOwnerHotkey::<T>::get(netuid),
+                mechanism: SubnetMechanism::<T>::get(netuid),
+                symbol: TokenSymbol::<T>::get(netuid),
+                identity: SubnetIdentitiesV3::<T>::get(netuid),
+                started: FirstEmissionBlockNumber::<T>::get(netuid).is_some(),
+                tempo: Self::get_tempo(netuid),
+                max_allowed_uids: Self::get_max_allowed_uids(netuid),
+                max_allowed_validators: Self::get_max_allowed_validators(netuid),
+                burn: Self::get_burn_as_u64(netuid),
+                min_burn: Self::get_min_burn_as_u64(netuid),
+                max_burn: Self::get_max_burn_as_u64(netuid),
+                difficulty: Self::get_difficulty_as_u64(netuid),
+                min_difficulty: Self::get_min_difficulty(netuid),
+                registration_allowed: Self::get_network_registration_allowed(netuid),
+                target_registrations_per_interval: Self::get_target_registrations_per_interval(
+                    netuid,
+                ),
+                adjustment_interval: Self::get_adjustment_interval(netuid),
+                max_registrations_per_block: Self::get_max_registrations_per_block(netuid),
+                weights_set_rate_limit: Self::get_weights_set_rate_limit(netuid),
+                hyperparameters: Hyperparameter::ALL
+                    .iter()
+                    .map(|param| (*param, Self::hyperparameter_value(netuid, *param)))
+                    .collect(),
+                tao_reserve: SubnetTAO::<T>::get(netuid),
+                alpha_in: SubnetAlphaIn::<T>::get(netuid),
+                alpha_out: SubnetAlphaOut::<T>::get(netuid),
+                locked: SubnetLocked::<T>::get(netuid),
+            })
+            .collect();
+
+        let stakes = Alpha::<T>::iter_keys()
+            .filter_map(|(hotkey, coldkey, netuid)| {
+                let alpha =
+                    Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+                (!alpha.is_zero()).then_some(StakeSnapshot {
+                    hotkey,
+                    coldkey,
+                    netuid,
+                    alpha,
+                })
+            })
+            .collect();
+
+        SubtensorSnapshot {
+            hotkeys,
+            identities,
+            subnets,
+            stakes,
+        }
+    }
+
+    /// Rebuilds the state captured by [`Self::take_snapshot`] on top of a genesis holding only
+    /// the root network.
+    ///
+    /// Each subnet gets its owner hotkey as only neuron. Stakes are converted into fresh shares,
+    /// so the pools keep their reserves but accrued share prices are not carried over.
+    pub fn apply_snapshot(snapshot: &SubtensorSnapshot<T::AccountId>) {
+        let block_number = Self::get_current_block_as_u64();
+
+        for (hotkey, coldkey) in snapshot.hotkeys.iter() {
+            Self::create_account_if_non_existent(coldkey, hotkey);
+        }
+        for (coldkey, identity) in snapshot.identities.iter() {
+            IdentitiesV2::<T>::insert(coldkey, identity.clone());
+        }
+
+        for subnet in snapshot.subnets.iter() {
+            let netuid = subnet.netuid;
+
+            Self::init_new_network(netuid, subnet.tempo);
+            Self::create_account_if_non_existent(&subnet.owner_coldkey, &subnet.owner_hotkey);
+            Self::set_max_allowed_uids(netuid, subnet.max_allowed_uids);
+            Self::set_max_allowed_validators(netuid, subnet.max_allowed_validators);
+            Self::append_neuron(netuid, &subnet.owner_hotkey, block_number);
+
+            SubnetMechanism::<T>::insert(netuid, subnet.mechanism);
+            NetworkRegisteredAt::<T>::insert(netuid, block_number);
+            TokenSymbol::<T>::insert(netuid, subnet.symbol.clone());
+            SubnetOwner::<T>::insert(netuid, subnet.owner_coldkey.clone());
+            SubnetOwnerHotkey::<T>::insert(netuid, subnet.owner_hotkey.clone());
+            if let Some(identity) = &subnet.identity {
+                SubnetIdentitiesV3::<T>::insert(netuid, identity.clone());
+            }
+
+            Self::set_burn(netuid, subnet.burn);
+            Self::set_min_burn(netuid, subnet.min_burn);
+            Self::set_max_burn(netuid, subnet.max_burn);
+            Self::set_difficulty(netuid, subnet.difficulty);
+            Self::set_min_difficulty(netuid, subnet.min_difficulty);
+            Self::set_network_registration_allowed(netuid, subnet.registration_allowed);
+            Self::set_target_registrations_per_interval(
+                netuid,
+                subnet.target_registrations_per_interval,
+            );
+            Self::set_adjustment_interval(netuid, subnet.adjustment_interval);
+            Self::set_max_registrations_per_block(netuid, subnet.max_registrations_per_block);
+            Self::set_weights_set_rate_limit(netuid, subnet.weights_set_rate_limit);
+            for (param, value) in subnet.hyperparameters.iter() {
+                if let Err(error) = Self::ensure_valid_hyperparameter_value(*param, *value, true)
+                    .and_then(|()| Self::apply_hyperparameter(netuid, *param, *value))
+                {
+                    panic!(
+                        "Invalid hyperparameter {param:?} = {value} on subnet {netuid}: {error:?}"
+                    );
+                }
+            }
+
+            SubnetTAO::<T>::insert(netuid, subnet.tao_reserve);
+            SubnetAlphaIn::<T>::insert(netuid, subnet.alpha_in);
+            SubnetAlphaOut::<T>::insert(netuid, subnet.alpha_out);
+            SubnetLocked::<T>::insert(netuid, subnet.locked);
+            LargestLocked::<T>::insert(netuid, subnet.locked);
+            Self::increase_total_stake(subnet.tao_reserve);
+
+            if subnet.started {
+                FirstEmissionBlockNumber::<T>::insert(netuid, block_number.saturating_add(1));
+                SubtokenEnabled::<T>::insert(netuid, true);
+            }
+        }
+
+        for stake in snapshot.stakes.iter() {
+            Self::create_account_if_non_existent(&stake.coldkey, &stake.hotkey);
+            Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &stake.hotkey,
+                &stake.coldkey,
+                stake.netuid,
+                stake.alpha,
+            );
+            StakingHotkeys::<T>::mutate(&stake.coldkey, |hotkeys| {
+                if !hotkeys.contains(&stake.hotkey) {
+                    hotkeys.push(stake.hotkey.clone());
+                }
+            });
+
+            // Root stake is TAO, which is not covered by a pool reserve.
+            if stake.netuid.is_root() {
+                SubnetTAO::<T>::mutate(NetUid::ROOT, |tao| {
+                    *tao = tao.saturating_add(u64::from(stake.alpha))
+                });
+                Self::increase_total_stake(u64::from(stake.alpha));
+            }
+        }
+    }
+}
@@ -9,6 +9,7 @@ use frame_support::traits::Get;
 use frame_system::RawOrigin;
 use substrate_fixed::types::{I64F64, U64F64};
 use subtensor_runtime_common::NetUid;
+use subtensor_swap_interface::{OrderType, SwapHandler};
 
 use crate::{
     pallet::{
@@ -143,5 +144,107 @@ mod benchmarks {
         assert!(EnabledUserLiquidity::<T>::get(netuid));
     }
 
+    #[benchmark]
+    fn collect_fees() {
+        let netuid = NetUid::from(1);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, T::MinimumLiquidity::get());
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        let hotkey: T::AccountId = account("hotkey", 0, 0);
+        let id = PositionId::from(1u128);
+
+        Positions::<T>::insert(
+            (netuid, caller.clone(), id),
+            Position {
+                id,
+                netuid,
+                tick_low: TickIndex::new(-10000).unwrap(),
+                tick_high: TickIndex::new(10000).unwrap(),
+                liquidity: 10000,
+                fees_tao: I64F64::from_num(0),
+                fees_alpha: I64F64::from_num(0),
+                _phantom: PhantomData,
+            },
+        );
+
+        #[extrinsic_call]
+        collect_fees(RawOrigin::Signed(caller), hotkey, netuid.into(), id.into());
+    }
+
+    #[benchmark]
+    fn sim_swap() {
+        let netuid = NetUid::from(1);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, T::MinimumLiquidity::get());
+        }
+
+        #[block]
+        {
+            let _ = <Pallet<T> as SwapHandler<T::AccountId>>::sim_swap(
+                netuid,
+                OrderType::Buy,
+                1_000_000_000,
+            );
+        }
+    }
+
+    #[benchmark]
+    fn current_alpha_price() {
+        // Before V3 is initialized the price is read from both reserves.
+        let netuid = NetUid::from(1);
+        SwapV3Initialized::<T>::remove(netuid);
+
+        #[block]
+        {
+            let _ = <Pallet<T> as SwapHandler<T::AccountId>>::current_alpha_price(netuid);
+        }
+    }
+
+    #[benchmark]
+    fn position_info() {
+        let netuid = NetUid::from(1);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, T::MinimumLiquidity::get());
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        let id = PositionId::from(1u128);
+
+        Positions::<T>::insert(
+            (netuid, caller.clone(), id),
+            Position {
+                id,
+                netuid,
+                tick_low: TickIndex::new(-10000).unwrap(),
+                tick_high: TickIndex::new(10000).unwrap(),
+                liquidity: 10000,
+                fees_tao: I64F64::from_num(0),
+                fees_alpha: I64F64::from_num(0),
+                _phantom: PhantomData,
+            },
+        );
+
+        #[block]
+        {
+            let position = Positions::<T>::get((netuid, caller, id)).unwrap();
+            let _ = position.to_token_amounts(AlphaSqrtPrice::<T>::get(netuid));
+            let _ = position.clone().collect_fees();
+        }
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
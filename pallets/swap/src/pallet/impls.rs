@@ -948,6 +948,24 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    /// Collects the fees accrued by a position since they were last collected, leaving its
+    /// liquidity untouched. Collecting is allowed even when user liquidity is disabled.
+    pub fn do_collect_fees(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        position_id: PositionId,
+    ) -> Result<(u64, AlphaCurrency), Error<T>> {
+        let Some(mut position) = Positions::<T>::get((netuid, coldkey_account_id, position_id))
+        else {
+            return Err(Error::<T>::LiquidityNotFound);
+        };
+
+        let (fee_tao, fee_alpha) = position.collect_fees();
+        Positions::<T>::insert(&(netuid, coldkey_account_id, position.id), position);
+
+        Ok((fee_tao, fee_alpha.into()))
+    }
+
     pub fn do_modify_position(
         netuid: NetUid,
         coldkey_account_id: &T::AccountId,
@@ -224,6 +224,22 @@ mod pallet {
             /// the upper tick
             tick_high: TickIndex,
         },
+
+        /// Event emitted when the fees accrued by a liquidity position are collected.
+        FeesCollected {
+            /// The coldkey account that owns the position
+            coldkey: T::AccountId,
+            /// The hotkey account where Alpha fees go to
+            hotkey: T::AccountId,
+            /// The subnet identifier
+            netuid: NetUid,
+            /// Unique identifier for the liquidity position
+            position_id: PositionId,
+            /// The amount of TAO fees collected from the position
+            fee_tao: u64,
+            /// The amount of Alpha fees collected from the position
+            fee_alpha: AlphaCurrency,
+        },
     }
 
     #[pallet::error]
@@ -568,5 +584,52 @@ mod pallet {
 
             Ok(())
         }
+
+        /// Collect the fees accrued by a liquidity position without changing its liquidity.
+        ///
+        /// Parameters:
+        /// - origin: The origin of the transaction
+        /// - hotkey: The hotkey the Alpha fees are staked to
+        /// - netuid: Subnet ID
+        /// - position_id: ID of the position to collect fees from
+        ///
+        /// Emits `Event::FeesCollected` on success
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::collect_fees())]
+        pub fn collect_fees(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::SubNetworkDoesNotExist
+            );
+
+            let (fee_tao, fee_alpha) = Self::do_collect_fees(netuid, &coldkey, position_id)?;
+
+            // Credit the collected fees to the account
+            if fee_tao > 0 {
+                T::BalanceOps::increase_balance(&coldkey, fee_tao);
+            }
+            if !fee_alpha.is_zero() {
+                T::BalanceOps::increase_stake(&coldkey, &hotkey, netuid.into(), fee_alpha)?;
+            }
+
+            Self::deposit_event(Event::FeesCollected {
+                coldkey,
+                hotkey,
+                netuid,
+                position_id,
+                fee_tao,
+                fee_alpha,
+            });
+
+            Ok(())
+        }
     }
 }
@@ -1533,6 +1533,73 @@ fn test_swap_fee_correctness() {
     });
 }
 
+#[test]
+fn test_collect_fees() {
+    new_test_ext().execute_with(|| {
+        let min_price = tick_to_price(TickIndex::MIN);
+        let max_price = tick_to_price(TickIndex::MAX);
+        let netuid = NetUid::from(1);
+        let liquidity = 2_000_000_000_000_u64;
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        let (position_id, _tao, _alpha) = Pallet::<Test>::do_add_liquidity(
+            netuid,
+            &OK_COLDKEY_ACCOUNT_ID,
+            &OK_HOTKEY_ACCOUNT_ID,
+            price_to_tick(min_price),
+            price_to_tick(max_price),
+            liquidity,
+        )
+        .unwrap();
+
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Buy,
+            liquidity / 10,
+            u64::MAX.into(),
+            false,
+            false,
+        )
+        .unwrap();
+
+        // Collecting claims the fees but leaves the liquidity in place
+        assert_ok!(Pallet::<Test>::collect_fees(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            position_id,
+        ));
+        let fee_rate = FeeRate::<Test>::get(netuid) as f64 / u16::MAX as f64;
+        let expected_fee = (fee_rate * (liquidity / 10) as f64 * 0.5) as u64;
+        let fee_tao = match System::events().last().unwrap().event {
+            RuntimeEvent::Swap(Event::FeesCollected { fee_tao, .. }) => fee_tao,
+            _ => panic!("FeesCollected not emitted"),
+        };
+        assert_abs_diff_eq!(fee_tao, expected_fee, epsilon = 1);
+
+        let position =
+            Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID, position_id)).unwrap();
+        assert_eq!(position.liquidity, liquidity);
+
+        // Fees are not collected twice
+        assert_eq!(
+            Pallet::<Test>::do_collect_fees(netuid, &OK_COLDKEY_ACCOUNT_ID, position_id),
+            Ok((0, 0.into()))
+        );
+
+        assert_noop!(
+            Pallet::<Test>::collect_fees(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID_2),
+                OK_HOTKEY_ACCOUNT_ID_2,
+                netuid,
+                position_id,
+            ),
+            Error::<Test>::LiquidityNotFound
+        );
+    });
+}
+
 #[test]
 fn test_current_liquidity_updates() {
     let netuid = NetUid::from(1);
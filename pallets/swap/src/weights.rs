@@ -19,6 +19,10 @@ pub trait WeightInfo {
     fn remove_liquidity() -> Weight;
     fn modify_position() -> Weight;
     fn toggle_user_liquidity() -> Weight;
+    fn collect_fees() -> Weight;
+    fn sim_swap() -> Weight;
+    fn current_alpha_price() -> Weight;
+    fn position_info() -> Weight;
 }
 
 /// Default weights for pallet_subtensor_swap.
@@ -58,6 +62,28 @@ impl<T: frame_system::Config> WeightInfo for DefaultWeight<T> {
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    fn collect_fees() -> Weight {
+        // Conservative weight estimate for collect_fees
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn sim_swap() -> Weight {
+        // Conservative weight estimate for a swap simulated across several ticks
+        Weight::from_parts(150_000_000, 0).saturating_add(T::DbWeight::get().reads(12))
+    }
+
+    fn current_alpha_price() -> Weight {
+        // Conservative weight estimate: the mechanism, the V3 flag and both reserves
+        Weight::from_parts(5_000_000, 0).saturating_add(T::DbWeight::get().reads(4))
+    }
+
+    fn position_info() -> Weight {
+        // Conservative weight estimate: the position, the price and its fees in range
+        Weight::from_parts(20_000_000, 0).saturating_add(T::DbWeight::get().reads(7))
+    }
 }
 
 // For backwards compatibility and tests
@@ -91,4 +117,22 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
+
+    fn collect_fees() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn sim_swap() -> Weight {
+        Weight::from_parts(150_000_000, 0).saturating_add(RocksDbWeight::get().reads(12))
+    }
+
+    fn current_alpha_price() -> Weight {
+        Weight::from_parts(5_000_000, 0).saturating_add(RocksDbWeight::get().reads(4))
+    }
+
+    fn position_info() -> Weight {
+        Weight::from_parts(20_000_000, 0).saturating_add(RocksDbWeight::get().reads(7))
+    }
 }
@@ -2,14 +2,14 @@ use core::marker::PhantomData;
 
 use fp_evm::{ExitError, PrecompileFailure};
 use pallet_evm::{BalanceConverter, PrecompileHandle, SubstrateBalance};
+use pallet_subtensor_swap::weights::WeightInfo;
 use precompile_utils::EvmResult;
 use sp_core::U256;
-use sp_std::vec::Vec;
 use substrate_fixed::types::U96F32;
 use subtensor_runtime_common::{Currency, NetUid};
 use subtensor_swap_interface::{OrderType, SwapHandler};
 
-use crate::PrecompileExt;
+use crate::{PrecompileExt, PrecompileHandleExt};
 
 pub struct AlphaPrecompile<R>(PhantomData<R>);
 
@@ -34,7 +34,11 @@ where
 {
     #[precompile::public("getAlphaPrice(uint16)")]
     #[precompile::view]
-    fn get_alpha_price(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+    fn get_alpha_price(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+        handle.record_weight::<R>(
+            <R as pallet_subtensor_swap::Config>::WeightInfo::current_alpha_price(),
+        )?;
+
         let price =
             <pallet_subtensor_swap::Pallet<R> as SwapHandler<R::AccountId>>::current_alpha_price(
                 netuid.into(),
@@ -49,7 +53,9 @@ where
 
     #[precompile::public("getMovingAlphaPrice(uint16)")]
     #[precompile::view]
-    fn get_moving_alpha_price(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+    fn get_moving_alpha_price(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(2)?;
+
         let price: U96F32 = pallet_subtensor::Pallet::<R>::get_moving_alpha_price(netuid.into());
         let price: SubstrateBalance = price.saturating_to_num::<u64>().into();
         let price_eth = <R as pallet_evm::Config>::BalanceConverter::into_evm_balance(price)
@@ -61,31 +67,41 @@ where
 
     #[precompile::public("getTaoInPool(uint16)")]
     #[precompile::view]
-    fn get_tao_in_pool(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_tao_in_pool(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::SubnetTAO::<R>::get(NetUid::from(netuid)))
     }
 
     #[precompile::public("getAlphaInPool(uint16)")]
     #[precompile::view]
-    fn get_alpha_in_pool(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_alpha_in_pool(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::SubnetAlphaIn::<R>::get(NetUid::from(netuid)).into())
     }
 
     #[precompile::public("getAlphaOutPool(uint16)")]
     #[precompile::view]
-    fn get_alpha_out_pool(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_alpha_out_pool(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::SubnetAlphaOut::<R>::get(NetUid::from(netuid)).into())
     }
 
     #[precompile::public("getAlphaIssuance(uint16)")]
     #[precompile::view]
-    fn get_alpha_issuance(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_alpha_issuance(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(2)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_alpha_issuance(netuid.into()).into())
     }
 
     #[precompile::public("getTaoWeight()")]
     #[precompile::view]
-    fn get_tao_weight(_handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+    fn get_tao_weight(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(1)?;
+
         let tao_weight = pallet_subtensor::TaoWeight::<R>::get();
         Ok(U256::from(tao_weight))
     }
@@ -93,10 +109,12 @@ where
     #[precompile::public("simSwapTaoForAlpha(uint16,uint64)")]
     #[precompile::view]
     fn sim_swap_tao_for_alpha(
-        _handle: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
         tao: u64,
     ) -> EvmResult<U256> {
+        handle.record_weight::<R>(<R as pallet_subtensor_swap::Config>::WeightInfo::sim_swap())?;
+
         let swap_result =
             <pallet_subtensor_swap::Pallet<R> as SwapHandler<R::AccountId>>::sim_swap(
                 netuid.into(),
@@ -112,10 +130,12 @@ where
     #[precompile::public("simSwapAlphaForTao(uint16,uint64)")]
     #[precompile::view]
     fn sim_swap_alpha_for_tao(
-        _handle: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
         alpha: u64,
     ) -> EvmResult<U256> {
+        handle.record_weight::<R>(<R as pallet_subtensor_swap::Config>::WeightInfo::sim_swap())?;
+
         let swap_result =
             <pallet_subtensor_swap::Pallet<R> as SwapHandler<R::AccountId>>::sim_swap(
                 netuid.into(),
@@ -130,7 +150,9 @@ where
 
     #[precompile::public("getSubnetMechanism(uint16)")]
     #[precompile::view]
-    fn get_subnet_mechanism(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_subnet_mechanism(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::SubnetMechanism::<R>::get(NetUid::from(
             netuid,
         )))
@@ -145,9 +167,11 @@ where
     #[precompile::public("getEMAPriceHalvingBlocks(uint16)")]
     #[precompile::view]
     fn get_ema_price_halving_blocks(
-        _handle: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
     ) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::EMAPriceHalvingBlocks::<R>::get(
             NetUid::from(netuid),
         ))
@@ -155,7 +179,9 @@ where
 
     #[precompile::public("getSubnetVolume(uint16)")]
     #[precompile::view]
-    fn get_subnet_volume(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+    fn get_subnet_volume(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(U256::from(pallet_subtensor::SubnetVolume::<R>::get(
             NetUid::from(netuid),
         )))
@@ -163,7 +189,9 @@ where
 
     #[precompile::public("getTaoInEmission(uint16)")]
     #[precompile::view]
-    fn get_tao_in_emission(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+    fn get_tao_in_emission(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(U256::from(pallet_subtensor::SubnetTaoInEmission::<R>::get(
             NetUid::from(netuid),
         )))
@@ -171,7 +199,9 @@ where
 
     #[precompile::public("getAlphaInEmission(uint16)")]
     #[precompile::view]
-    fn get_alpha_in_emission(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+    fn get_alpha_in_emission(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(U256::from(
             pallet_subtensor::SubnetAlphaInEmission::<R>::get(NetUid::from(netuid)).to_u64(),
         ))
@@ -179,7 +209,9 @@ where
 
     #[precompile::public("getAlphaOutEmission(uint16)")]
     #[precompile::view]
-    fn get_alpha_out_emission(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+    fn get_alpha_out_emission(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(U256::from(
             pallet_subtensor::SubnetAlphaOutEmission::<R>::get(NetUid::from(netuid)).to_u64(),
         ))
@@ -187,17 +219,21 @@ where
 
     #[precompile::public("getSumAlphaPrice()")]
     #[precompile::view]
-    fn get_sum_alpha_price(_handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
-        let netuids = pallet_subtensor::NetworksAdded::<R>::iter()
-            .filter(|(netuid, _)| *netuid != NetUid::ROOT)
-            .collect::<Vec<_>>();
-
+    fn get_sum_alpha_price(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
         let mut sum_alpha_price: U96F32 = U96F32::from_num(0);
-        for (netuid, _) in netuids {
+        for (netuid, _) in pallet_subtensor::NetworksAdded::<R>::iter() {
+            handle.record_db_reads::<R>(1)?;
+            if netuid == NetUid::ROOT {
+                continue;
+            }
+
+            handle.record_weight::<R>(
+                <R as pallet_subtensor_swap::Config>::WeightInfo::current_alpha_price(),
+            )?;
             let price =
-            <pallet_subtensor_swap::Pallet<R> as SwapHandler<R::AccountId>>::current_alpha_price(
-                netuid.into(),
-            );
+                <pallet_subtensor_swap::Pallet<R> as SwapHandler<R::AccountId>>::current_alpha_price(
+                    netuid,
+                );
 
             if price < U96F32::from_num(1) {
                 sum_alpha_price = sum_alpha_price.saturating_add(price);
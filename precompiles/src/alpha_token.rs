@@ -0,0 +1,258 @@
+use core::marker::PhantomData;
+
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use precompile_utils::{EvmResult, prelude::Address};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::Dispatchable;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+use crate::logs::DispatchLog;
+use crate::staking::STAKE_READS;
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+/// ERC-20 facade over the alpha stake of a subnet. Every subnet has its own token at
+/// `INDEX + netuid`. A holder's balance is its stake on the hotkey it picked with `setHotkey`, or
+/// else on the first hotkey it holds stake on.
+pub struct AlphaTokenPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for AlphaTokenPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 0x1_0000;
+}
+
+impl<R> AlphaTokenPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    /// The token address of a subnet.
+    pub fn address(netuid: NetUid) -> H160 {
+        H160::from_low_u64_be(Self::INDEX.saturating_add(u16::from(netuid).into()))
+    }
+
+    /// The subnet whose token lives at `address`, if it is a token address.
+    pub fn netuid(address: H160) -> Option<NetUid> {
+        let index = address.to_low_u64_be();
+        let netuid = u16::try_from(index.checked_sub(Self::INDEX)?).ok()?;
+
+        (H160::from_low_u64_be(index) == address).then_some(netuid.into())
+    }
+
+    fn try_netuid(handle: &impl PrecompileHandle) -> EvmResult<NetUid> {
+        Self::netuid(handle.code_address()).ok_or(PrecompileFailure::Error {
+            exit_status: ExitError::Other("not an alpha token address".into()),
+        })
+    }
+
+    fn account_id_of(address: Address) -> R::AccountId {
+        <R as pallet_evm::Config>::AddressMapping::into_account_id(address.0)
+    }
+
+    /// Records the reads of finding the hotkey backing the tokens of `owner`, counting a stake
+    /// lookup per staking hotkey when it has not chosen one.
+    fn record_hotkey_reads(
+        handle: &mut impl PrecompileHandle,
+        netuid: NetUid,
+        owner: &R::AccountId,
+    ) -> EvmResult<()> {
+        handle.record_db_reads::<R>(1)?;
+        if pallet_subtensor::AlphaTokenHotkey::<R>::contains_key(netuid, owner) {
+            return Ok(());
+        }
+
+        let staking_hotkeys =
+            pallet_subtensor::StakingHotkeys::<R>::decode_len(owner).unwrap_or_default() as u64;
+        handle.record_db_reads::<R>(
+            staking_hotkeys
+                .saturating_mul(STAKE_READS)
+                .saturating_add(1),
+        )
+    }
+}
+
+#[precompile_utils::precompile]
+impl<R> AlphaTokenPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    /// The alpha staked on the subnet. Holders only see their stake on the hotkey they picked,
+    /// so the balances sum to at most this supply.
+    #[precompile::public("totalSupply()")]
+    #[precompile::view]
+    fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        let netuid = Self::try_netuid(handle)?;
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(u64::from(pallet_subtensor::SubnetAlphaOut::<R>::get(netuid)).into())
+    }
+
+    #[precompile::public("decimals()")]
+    #[precompile::view]
+    fn decimals(_handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+        Ok(9)
+    }
+
+    #[precompile::public("balanceOf(address)")]
+    #[precompile::view]
+    fn balance_of(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+        let netuid = Self::try_netuid(handle)?;
+        let owner = Self::account_id_of(owner);
+        Self::record_hotkey_reads(handle, netuid, &owner)?;
+        handle.record_db_reads::<R>(STAKE_READS)?;
+
+        let balance = pallet_subtensor::Pallet::<R>::get_alpha_token_balance(netuid, &owner);
+
+        Ok(u64::from(balance).into())
+    }
+
+    #[precompile::public("allowance(address,address)")]
+    #[precompile::view]
+    fn allowance(
+        handle: &mut impl PrecompileHandle,
+        owner: Address,
+        spender: Address,
+    ) -> EvmResult<U256> {
+        let netuid = Self::try_netuid(handle)?;
+        handle.record_db_reads::<R>(1)?;
+
+        let allowance = pallet_subtensor::AlphaTokenAllowance::<R>::get((
+            netuid,
+            Self::account_id_of(owner),
+            Self::account_id_of(spender),
+        ));
+
+        if allowance == AlphaCurrency::MAX {
+            return Ok(U256::MAX);
+        }
+
+        Ok(u64::from(allowance).into())
+    }
+
+    #[precompile::public("hotkeyOf(address)")]
+    #[precompile::view]
+    fn hotkey_of(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<H256> {
+        let netuid = Self::try_netuid(handle)?;
+        let owner = Self::account_id_of(owner);
+        Self::record_hotkey_reads(handle, netuid, &owner)?;
+
+        let hotkey = pallet_subtensor::Pallet::<R>::get_alpha_token_hotkey(netuid, &owner);
+
+        Ok(hotkey.map(|hotkey| H256(hotkey.into())).unwrap_or_default())
+    }
+
+    #[precompile::public("setHotkey(bytes32)")]
+    fn set_hotkey(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<()> {
+        let netuid = Self::try_netuid(handle)?;
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::set_alpha_token_hotkey {
+            netuid,
+            hotkey: R::AccountId::from(hotkey.0),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("transfer(address,uint256)")]
+    fn transfer(handle: &mut impl PrecompileHandle, to: Address, amount: U256) -> EvmResult<bool> {
+        let netuid = Self::try_netuid(handle)?;
+        let caller = handle.context().caller;
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::transfer_alpha_token {
+            netuid,
+            destination_coldkey: Self::account_id_of(to),
+            amount: try_alpha_from_u256(amount)?,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+        handle.record_log(transfer_log(caller, to.0, amount))?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("approve(address,uint256)")]
+    fn approve(
+        handle: &mut impl PrecompileHandle,
+        spender: Address,
+        amount: U256,
+    ) -> EvmResult<bool> {
+        let netuid = Self::try_netuid(handle)?;
+        let caller = handle.context().caller;
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::approve_alpha_token {
+            netuid,
+            spender: Self::account_id_of(spender),
+            amount: saturating_alpha_from_u256(amount),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+        handle.record_log(DispatchLog::new(
+            "Approval(address,address,uint256)",
+            [H256::from(caller), H256::from(spender.0)],
+            amount,
+        ))?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("transferFrom(address,address,uint256)")]
+    fn transfer_from(
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> EvmResult<bool> {
+        let netuid = Self::try_netuid(handle)?;
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::transfer_alpha_token_from {
+            netuid,
+            owner: Self::account_id_of(from),
+            destination_coldkey: Self::account_id_of(to),
+            amount: try_alpha_from_u256(amount)?,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+        handle.record_log(transfer_log(from.0, to.0, amount))?;
+
+        Ok(true)
+    }
+}
+
+fn transfer_log(from: H160, to: H160, amount: U256) -> DispatchLog {
+    DispatchLog::new(
+        "Transfer(address,address,uint256)",
+        [H256::from(from), H256::from(to)],
+        amount,
+    )
+}
+
+/// Allowances above `u64::MAX`, such as the usual `type(uint256).max`, are infinite.
+fn saturating_alpha_from_u256(value: U256) -> AlphaCurrency {
+    u64::try_from(value).unwrap_or(u64::MAX).into()
+}
+
+fn try_alpha_from_u256(value: U256) -> EvmResult<AlphaCurrency> {
+    u64::try_from(value)
+        .map(AlphaCurrency::from)
+        .map_err(|_| PrecompileFailure::Error {
+            exit_status: ExitError::Other("the value is outside of u64 bounds".into()),
+        })
+}
@@ -0,0 +1,206 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use pallet_subtensor::{ChildKeys, ParentKeys, PendingChildKeyCooldown, PendingChildKeys};
+use precompile_utils::EvmResult;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use subtensor_runtime_common::NetUid;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+pub struct ChildkeyPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for ChildkeyPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2063;
+}
+
+// Children and parents travel as `(uint64 proportion, bytes32 hotkey)` tuples.
+#[precompile_utils::precompile]
+impl<R> ChildkeyPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("setChildren(bytes32,uint16,(uint64,bytes32)[])")]
+    fn set_children(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        children: Vec<(u64, H256)>,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::set_children {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid: netuid.into(),
+            children: children
+                .into_iter()
+                .map(|(proportion, child)| (proportion, R::AccountId::from(child.0)))
+                .collect(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getChildren(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_children(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<Vec<(u64, H256)>> {
+        handle.record_db_reads::<R>(1)?;
+
+        let children = ChildKeys::<R>::get(R::AccountId::from(hotkey.0), NetUid::from(netuid));
+
+        Ok(linkage_to_evm::<R>(children))
+    }
+
+    #[precompile::public("getPendingChildren(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_pending_children(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<(Vec<(u64, H256)>, u64)> {
+        handle.record_db_reads::<R>(1)?;
+
+        let (children, cooldown_block) =
+            PendingChildKeys::<R>::get(NetUid::from(netuid), R::AccountId::from(hotkey.0));
+
+        Ok((linkage_to_evm::<R>(children), cooldown_block))
+    }
+
+    #[precompile::public("getParents(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_parents(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<Vec<(u64, H256)>> {
+        handle.record_db_reads::<R>(1)?;
+
+        let parents = ParentKeys::<R>::get(R::AccountId::from(hotkey.0), NetUid::from(netuid));
+
+        Ok(linkage_to_evm::<R>(parents))
+    }
+
+    #[precompile::public("getPendingChildkeyCooldown()")]
+    #[precompile::view]
+    fn get_pending_childkey_cooldown(handle: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(PendingChildKeyCooldown::<R>::get())
+    }
+
+    #[precompile::public("setChildkeyTake(bytes32,uint16,uint16)")]
+    fn set_childkey_take(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        take: u16,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::set_childkey_take {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid: netuid.into(),
+            take,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getChildkeyTake(bytes32,uint16)")]
+    #[precompile::view]
+    fn get_childkey_take(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+    ) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(pallet_subtensor::Pallet::<R>::get_childkey_take(
+            &R::AccountId::from(hotkey.0),
+            netuid.into(),
+        ))
+    }
+
+    #[precompile::public("getChildkeyTakeRange()")]
+    #[precompile::view]
+    fn get_childkey_take_range(handle: &mut impl PrecompileHandle) -> EvmResult<(u16, u16)> {
+        handle.record_db_reads::<R>(2)?;
+
+        Ok((
+            pallet_subtensor::Pallet::<R>::get_min_childkey_take(),
+            pallet_subtensor::Pallet::<R>::get_max_childkey_take(),
+        ))
+    }
+
+    #[precompile::public("increaseTake(bytes32,uint16)")]
+    fn increase_take(handle: &mut impl PrecompileHandle, hotkey: H256, take: u16) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::increase_take {
+            hotkey: R::AccountId::from(hotkey.0),
+            take,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("decreaseTake(bytes32,uint16)")]
+    fn decrease_take(handle: &mut impl PrecompileHandle, hotkey: H256, take: u16) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::decrease_take {
+            hotkey: R::AccountId::from(hotkey.0),
+            take,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getDelegateTake(bytes32)")]
+    #[precompile::view]
+    fn get_delegate_take(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(pallet_subtensor::Pallet::<R>::get_hotkey_take(
+            &R::AccountId::from(hotkey.0),
+        ))
+    }
+
+    #[precompile::public("getDelegateTakeRange()")]
+    #[precompile::view]
+    fn get_delegate_take_range(handle: &mut impl PrecompileHandle) -> EvmResult<(u16, u16)> {
+        handle.record_db_reads::<R>(2)?;
+
+        Ok((
+            pallet_subtensor::Pallet::<R>::get_min_delegate_take(),
+            pallet_subtensor::Pallet::<R>::get_max_delegate_take(),
+        ))
+    }
+}
+
+fn linkage_to_evm<R: frame_system::Config>(linkage: Vec<(u64, R::AccountId)>) -> Vec<(u64, H256)>
+where
+    R::AccountId: Into<[u8; 32]>,
+{
+    linkage
+        .into_iter()
+        .map(|(proportion, account_id)| (proportion, H256(account_id.into())))
+        .collect()
+}
@@ -0,0 +1,259 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::marker::PhantomData;
+
+use codec::Encode;
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_commitments::{CommitmentInfo, CommitmentOf, Data, RevealedCommitments, UsedSpaceOf};
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use precompile_utils::{EvmResult, prelude::UnboundedBytes, solidity::Codec};
+use sp_core::H256;
+use sp_runtime::{
+    BoundedVec,
+    traits::{Dispatchable, UniqueSaturatedInto},
+};
+use subtensor_runtime_common::NetUid;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+pub struct CommitmentsPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for CommitmentsPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_commitments::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_commitments::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2060;
+}
+
+// Commitment fields travel as `(uint8 kind, bytes data, uint64 revealRound)` tuples, the kind
+// being the `Data` variant in declaration order. `revealRound` only applies to timelocked data.
+#[precompile_utils::precompile]
+impl<R> CommitmentsPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_commitments::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_commitments::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("setCommitment(uint16,(uint8,bytes,uint64)[])")]
+    #[precompile::payable]
+    fn set_commitment(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        fields: Vec<(u8, UnboundedBytes, u64)>,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let fields = fields
+            .into_iter()
+            .map(|(kind, data, reveal_round)| try_data_from_field(kind, data.into(), reveal_round))
+            .collect::<EvmResult<Vec<_>>>()?;
+        let fields = BoundedVec::try_from(fields).map_err(|_| PrecompileFailure::Error {
+            exit_status: ExitError::Other("too many commitment fields".into()),
+        })?;
+        let call = pallet_commitments::Call::<R>::set_commitment {
+            netuid: netuid.into(),
+            info: Box::new(CommitmentInfo { fields }),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getCommitment(uint16,bytes32)")]
+    #[precompile::view]
+    fn get_commitment(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        account: H256,
+    ) -> EvmResult<Commitment> {
+        let account_id = R::AccountId::from(account.0);
+        let registration = CommitmentOf::<R>::get(NetUid::from(netuid), &account_id);
+        handle.record_db_read_len::<R>(registration.encoded_size())?;
+        let registration = registration.ok_or(PrecompileFailure::Error {
+            exit_status: ExitError::Other("Commitment not found".into()),
+        })?;
+
+        Ok(Commitment {
+            account,
+            committed_block: registration.block.unique_saturated_into(),
+            deposit: registration.deposit.unique_saturated_into(),
+            fields: registration
+                .info
+                .fields
+                .into_iter()
+                .map(Field::from)
+                .collect(),
+        })
+    }
+
+    #[precompile::public("listCommitments(uint16)")]
+    #[precompile::view]
+    fn list_commitments(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<Vec<Commitment>> {
+        let mut commitments = Vec::new();
+        for (account_id, registration) in CommitmentOf::<R>::iter_prefix(NetUid::from(netuid)) {
+            handle.record_db_read_len::<R>(registration.encoded_size())?;
+            commitments.push(Commitment {
+                account: H256(account_id.into()),
+                committed_block: registration.block.unique_saturated_into(),
+                deposit: registration.deposit.unique_saturated_into(),
+                fields: registration
+                    .info
+                    .fields
+                    .into_iter()
+                    .map(Field::from)
+                    .collect(),
+            });
+        }
+
+        Ok(commitments)
+    }
+
+    #[precompile::public("getRevealedCommitments(uint16,bytes32)")]
+    #[precompile::view]
+    fn get_revealed_commitments(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        account: H256,
+    ) -> EvmResult<Vec<RevealedCommitment>> {
+        let account_id = R::AccountId::from(account.0);
+        let reveals = RevealedCommitments::<R>::get(NetUid::from(netuid), &account_id);
+        handle.record_db_read_len::<R>(reveals.encoded_size())?;
+
+        Ok(reveals
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(data, revealed_block)| RevealedCommitment {
+                account,
+                data: data.into(),
+                revealed_block,
+            })
+            .collect())
+    }
+
+    #[precompile::public("listRevealedCommitments(uint16)")]
+    #[precompile::view]
+    fn list_revealed_commitments(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<Vec<RevealedCommitment>> {
+        let mut commitments = Vec::new();
+        for (account_id, reveals) in RevealedCommitments::<R>::iter_prefix(NetUid::from(netuid)) {
+            handle.record_db_read_len::<R>(reveals.encoded_size())?;
+            let account = H256(account_id.into());
+            commitments.extend(reveals.into_iter().map(|(data, revealed_block)| {
+                RevealedCommitment {
+                    account,
+                    data: data.into(),
+                    revealed_block,
+                }
+            }));
+        }
+
+        Ok(commitments)
+    }
+
+    #[precompile::public("getUsedSpace(uint16,bytes32)")]
+    #[precompile::view]
+    fn get_used_space(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        account: H256,
+    ) -> EvmResult<(u64, u64)> {
+        handle.record_db_reads::<R>(1)?;
+
+        let account_id = R::AccountId::from(account.0);
+        let usage = UsedSpaceOf::<R>::get(NetUid::from(netuid), &account_id).unwrap_or_default();
+
+        Ok((usage.last_epoch, usage.used_space))
+    }
+
+    #[precompile::public("getMaxSpace()")]
+    #[precompile::view]
+    fn get_max_space(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(pallet_commitments::MaxSpace::<R>::get())
+    }
+}
+
+#[derive(Codec)]
+struct Commitment {
+    account: H256,
+    committed_block: u64,
+    deposit: u64,
+    fields: Vec<Field>,
+}
+
+#[derive(Codec)]
+struct Field {
+    kind: u8,
+    data: UnboundedBytes,
+    reveal_round: u64,
+}
+
+#[derive(Codec)]
+struct RevealedCommitment {
+    account: H256,
+    data: UnboundedBytes,
+    revealed_block: u64,
+}
+
+impl From<Data> for Field {
+    fn from(data: Data) -> Self {
+        let (kind, bytes, reveal_round) = match data {
+            Data::None => (0, Vec::new(), 0),
+            Data::Raw(bytes) => (1, bytes.into_inner(), 0),
+            Data::BlakeTwo256(hash) => (2, hash.to_vec(), 0),
+            Data::Sha256(hash) => (3, hash.to_vec(), 0),
+            Data::Keccak256(hash) => (4, hash.to_vec(), 0),
+            Data::ShaThree256(hash) => (5, hash.to_vec(), 0),
+            Data::TimelockEncrypted {
+                encrypted,
+                reveal_round,
+            } => (6, encrypted.into_inner(), reveal_round),
+            Data::ResetBondsFlag => (7, Vec::new(), 0),
+            Data::BigRaw(bytes) => (8, bytes.into_inner(), 0),
+        };
+
+        Self {
+            kind,
+            data: bytes.into(),
+            reveal_round,
+        }
+    }
+}
+
+fn try_data_from_field(kind: u8, bytes: Vec<u8>, reveal_round: u64) -> EvmResult<Data> {
+    let hash = |bytes: Vec<u8>| <[u8; 32]>::try_from(bytes).ok();
+    let data = match kind {
+        0 => Some(Data::None),
+        1 => BoundedVec::try_from(bytes).ok().map(Data::Raw),
+        2 => hash(bytes).map(Data::BlakeTwo256),
+        3 => hash(bytes).map(Data::Sha256),
+        4 => hash(bytes).map(Data::Keccak256),
+        5 => hash(bytes).map(Data::ShaThree256),
+        6 => BoundedVec::try_from(bytes)
+            .ok()
+            .map(|encrypted| Data::TimelockEncrypted {
+                encrypted,
+                reveal_round,
+            }),
+        7 => Some(Data::ResetBondsFlag),
+        8 => BoundedVec::try_from(bytes).ok().map(Data::BigRaw),
+        _ => None,
+    };
+
+    data.ok_or(PrecompileFailure::Error {
+        exit_status: ExitError::Other("invalid commitment field".into()),
+    })
+}
@@ -0,0 +1,127 @@
+use core::marker::PhantomData;
+
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_drand::{LastStoredRound, NextRandomnessRequestId, Pulses, RandomnessRequests};
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use precompile_utils::EvmResult;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+pub struct DrandPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for DrandPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_drand::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall:
+        From<pallet_drand::Call<R>> + GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2061;
+}
+
+// Pulses are verified by the drand pallet before they are stored, so anything read here is
+// verified randomness. A request commits to a round that drand publishes at least
+// `RANDOMNESS_REQUEST_MARGIN` rounds after the block timestamp, since stored pulses lag behind the
+// published ones. Its randomness is derived from that round's pulse and the request id.
+#[precompile_utils::precompile]
+impl<R> DrandPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_drand::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall:
+        From<pallet_drand::Call<R>> + GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("getRandomness(uint64)")]
+    #[precompile::view]
+    fn get_randomness(handle: &mut impl PrecompileHandle, round: u64) -> EvmResult<H256> {
+        handle.record_db_reads::<R>(1)?;
+
+        try_randomness::<R>(round)
+    }
+
+    #[precompile::public("getLatestRandomness()")]
+    #[precompile::view]
+    fn get_latest_randomness(handle: &mut impl PrecompileHandle) -> EvmResult<(u64, H256)> {
+        handle.record_db_reads::<R>(2)?;
+
+        let round = LastStoredRound::<R>::get();
+
+        Ok((round, try_randomness::<R>(round)?))
+    }
+
+    #[precompile::public("getLastStoredRound()")]
+    #[precompile::view]
+    fn get_last_stored_round(handle: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(LastStoredRound::<R>::get())
+    }
+
+    #[precompile::public("getCurrentRound()")]
+    #[precompile::view]
+    fn get_current_round(handle: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(2)?;
+
+        Ok(pallet_drand::Pallet::<R>::current_round())
+    }
+
+    #[precompile::public("requestRandomness(uint64)")]
+    fn request_randomness(handle: &mut impl PrecompileHandle, round: u64) -> EvmResult<u64> {
+        let account_id = handle.caller_account_id::<R>();
+        let request_id = NextRandomnessRequestId::<R>::get();
+        let call = pallet_drand::Call::<R>::request_randomness { round };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        Ok(request_id)
+    }
+
+    #[precompile::public("getRequest(uint64)")]
+    #[precompile::view]
+    fn get_request(
+        handle: &mut impl PrecompileHandle,
+        request_id: u64,
+    ) -> EvmResult<(H256, u64, bool)> {
+        handle.record_db_reads::<R>(2)?;
+
+        let request = RandomnessRequests::<R>::get(request_id).ok_or(PrecompileFailure::Error {
+            exit_status: ExitError::Other("Request not found".into()),
+        })?;
+        let fulfilled = Pulses::<R>::contains_key(request.round);
+
+        Ok((H256(request.requester.into()), request.round, fulfilled))
+    }
+
+    #[precompile::public("getRequestRandomness(uint64)")]
+    #[precompile::view]
+    fn get_request_randomness(
+        handle: &mut impl PrecompileHandle,
+        request_id: u64,
+    ) -> EvmResult<H256> {
+        handle.record_db_reads::<R>(2)?;
+
+        pallet_drand::Pallet::<R>::fulfilled_randomness(request_id)
+            .map(H256)
+            .ok_or(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Request not fulfilled".into()),
+            })
+    }
+}
+
+fn try_randomness<R: pallet_drand::Config>(round: u64) -> EvmResult<H256> {
+    let pulse = Pulses::<R>::get(round).ok_or(PrecompileFailure::Error {
+        exit_status: ExitError::Other("Round not stored".into()),
+    })?;
+
+    <[u8; 32]>::try_from(pulse.randomness.into_inner())
+        .map(H256)
+        .map_err(|_| PrecompileFailure::Error {
+            exit_status: ExitError::Other("invalid pulse randomness".into()),
+        })
+}
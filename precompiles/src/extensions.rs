@@ -3,6 +3,8 @@ extern crate alloc;
 use alloc::format;
 
 use frame_support::dispatch::{GetDispatchInfo, Pays, PostDispatchInfo};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use frame_system::RawOrigin;
 use pallet_admin_utils::{PrecompileEnable, PrecompileEnum};
 use pallet_evm::{
@@ -14,6 +16,8 @@ use sp_core::{H160, U256, blake2_256};
 use sp_runtime::traits::Dispatchable;
 use sp_std::vec::Vec;
 
+use crate::logs::{DispatchLog, DispatchLogs};
+
 pub(crate) trait PrecompileHandleExt: PrecompileHandle {
     fn caller_account_id<R>(&self) -> R::AccountId
     where
@@ -37,7 +41,49 @@ pub(crate) trait PrecompileHandleExt: PrecompileHandle {
         Ok(result.into())
     }
 
-    /// Dispatches a runtime call, but also checks and records the gas costs.
+    /// Records the gas cost of `reads` storage reads, for precompiles that read storage without
+    /// dispatching a call.
+    fn record_db_reads<R>(&mut self, reads: u64) -> EvmResult<()>
+    where
+        R: frame_system::Config + pallet_evm::Config,
+    {
+        self.record_weight::<R>(<R as frame_system::Config>::DbWeight::get().reads(reads))
+    }
+
+    /// Records the gas cost of reading a storage value of `len` bytes: one storage read, and the
+    /// EVM copy cost of the value so large values cost more.
+    fn record_db_read_len<R>(&mut self, len: usize) -> EvmResult<()>
+    where
+        R: frame_system::Config + pallet_evm::Config,
+    {
+        const G_COPY: u64 = 3;
+
+        self.record_db_reads::<R>(1)?;
+        self.record_cost(G_COPY.saturating_mul((len as u64).div_ceil(32)))?;
+        Ok(())
+    }
+
+    /// Records the gas cost of work done by the precompile itself, such as a swap simulation.
+    fn record_weight<R>(&mut self, weight: Weight) -> EvmResult<()>
+    where
+        R: pallet_evm::Config,
+    {
+        self.record_external_cost(Some(weight.ref_time()), Some(weight.proof_size()), None)?;
+        self.record_cost(<R as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+            weight,
+        ))?;
+        Ok(())
+    }
+
+    /// Records the gas cost of a log and emits it from the precompile address.
+    fn record_log(&mut self, log: DispatchLog) -> EvmResult<()> {
+        self.record_cost(log.cost())?;
+        self.log(self.context().address, log.topics, log.data)?;
+        Ok(())
+    }
+
+    /// Dispatches a runtime call, but also checks and records the gas costs. Emits the call's
+    /// event log once it succeeds.
     fn try_dispatch_runtime_call<R, Call>(
         &mut self,
         call: Call,
@@ -45,10 +91,12 @@ pub(crate) trait PrecompileHandleExt: PrecompileHandle {
     ) -> EvmResult<()>
     where
         R: frame_system::Config + pallet_evm::Config,
+        Call: DispatchLogs<R::AccountId>,
         R::RuntimeCall: From<Call>,
         R::RuntimeCall: GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
         R::RuntimeOrigin: From<RawOrigin<R::AccountId>>,
     {
+        let dispatch_log = call.dispatch_log(&origin);
         let call = R::RuntimeCall::from(call);
         let info = GetDispatchInfo::get_dispatch_info(&call);
 
@@ -93,6 +141,10 @@ pub(crate) trait PrecompileHandleExt: PrecompileHandle {
 
                 log::debug!("Dispatch succeeded. Post info: {:?}", post_info);
 
+                if let Some(dispatch_log) = dispatch_log {
+                    self.record_log(dispatch_log.dispatched())?;
+                }
+
                 Ok(())
             }
             Err(e) => {
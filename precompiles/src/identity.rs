@@ -0,0 +1,185 @@
+use core::marker::PhantomData;
+
+use codec::Encode;
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::ConstU32;
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use pallet_subtensor::{ChainIdentityOfV2, IdentitiesV2, SubnetIdentitiesV3, SubnetIdentityOfV3};
+use precompile_utils::{EvmResult, prelude::BoundedString, solidity::Codec};
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use subtensor_runtime_common::NetUid;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+type IdentityString = BoundedString<ConstU32<1024>>;
+
+pub struct IdentityPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for IdentityPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2065;
+}
+
+// The setters dispatch `set_identity` and `set_subnet_identity`, so identities are validated by
+// `is_valid_identity` and `is_valid_subnet_identity` as for the extrinsics.
+#[precompile_utils::precompile]
+impl<R> IdentityPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("setIdentity(string,string,string,string,string,string,string)")]
+    #[precompile::payable]
+    #[allow(clippy::too_many_arguments)]
+    fn set_identity(
+        handle: &mut impl PrecompileHandle,
+        name: BoundedString<ConstU32<256>>,
+        url: BoundedString<ConstU32<256>>,
+        github_repo: BoundedString<ConstU32<256>>,
+        image: BoundedString<ConstU32<1024>>,
+        discord: BoundedString<ConstU32<256>>,
+        description: BoundedString<ConstU32<1024>>,
+        additional: BoundedString<ConstU32<1024>>,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::set_identity {
+            name: name.into(),
+            url: url.into(),
+            github_repo: github_repo.into(),
+            image: image.into(),
+            discord: discord.into(),
+            description: description.into(),
+            additional: additional.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getIdentity(bytes32)")]
+    #[precompile::view]
+    fn get_identity(handle: &mut impl PrecompileHandle, coldkey: H256) -> EvmResult<ChainIdentity> {
+        let identity = IdentitiesV2::<R>::get(R::AccountId::from(coldkey.0));
+        handle.record_db_read_len::<R>(identity.encoded_size())?;
+
+        identity
+            .map(ChainIdentity::from)
+            .ok_or(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Identity not found".into()),
+            })
+    }
+
+    #[precompile::public(
+        "setSubnetIdentity(uint16,string,string,string,string,string,string,string,string)"
+    )]
+    #[precompile::payable]
+    #[allow(clippy::too_many_arguments)]
+    fn set_subnet_identity(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        subnet_name: BoundedString<ConstU32<256>>,
+        github_repo: BoundedString<ConstU32<1024>>,
+        subnet_contact: BoundedString<ConstU32<1024>>,
+        subnet_url: BoundedString<ConstU32<1024>>,
+        discord: BoundedString<ConstU32<256>>,
+        description: BoundedString<ConstU32<1024>>,
+        logo_url: BoundedString<ConstU32<1024>>,
+        additional: BoundedString<ConstU32<1024>>,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor::Call::<R>::set_subnet_identity {
+            netuid: netuid.into(),
+            subnet_name: subnet_name.into(),
+            github_repo: github_repo.into(),
+            subnet_contact: subnet_contact.into(),
+            subnet_url: subnet_url.into(),
+            discord: discord.into(),
+            description: description.into(),
+            logo_url: logo_url.into(),
+            additional: additional.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getSubnetIdentity(uint16)")]
+    #[precompile::view]
+    fn get_subnet_identity(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<SubnetIdentity> {
+        let identity = SubnetIdentitiesV3::<R>::get(NetUid::from(netuid));
+        handle.record_db_read_len::<R>(identity.encoded_size())?;
+
+        identity
+            .map(SubnetIdentity::from)
+            .ok_or(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Subnet identity not found".into()),
+            })
+    }
+}
+
+#[derive(Codec)]
+struct ChainIdentity {
+    name: IdentityString,
+    url: IdentityString,
+    github_repo: IdentityString,
+    image: IdentityString,
+    discord: IdentityString,
+    description: IdentityString,
+    additional: IdentityString,
+}
+
+impl From<ChainIdentityOfV2> for ChainIdentity {
+    fn from(identity: ChainIdentityOfV2) -> Self {
+        Self {
+            name: identity.name.into(),
+            url: identity.url.into(),
+            github_repo: identity.github_repo.into(),
+            image: identity.image.into(),
+            discord: identity.discord.into(),
+            description: identity.description.into(),
+            additional: identity.additional.into(),
+        }
+    }
+}
+
+#[derive(Codec)]
+struct SubnetIdentity {
+    subnet_name: IdentityString,
+    github_repo: IdentityString,
+    subnet_contact: IdentityString,
+    subnet_url: IdentityString,
+    discord: IdentityString,
+    description: IdentityString,
+    logo_url: IdentityString,
+    additional: IdentityString,
+}
+
+impl From<SubnetIdentityOfV3> for SubnetIdentity {
+    fn from(identity: SubnetIdentityOfV3) -> Self {
+        Self {
+            subnet_name: identity.subnet_name.into(),
+            github_repo: identity.github_repo.into(),
+            subnet_contact: identity.subnet_contact.into(),
+            subnet_url: identity.subnet_url.into(),
+            discord: identity.discord.into(),
+            description: identity.description.into(),
+            logo_url: identity.logo_url.into(),
+            additional: identity.additional.into(),
+        }
+    }
+}
@@ -26,13 +26,20 @@ use subtensor_runtime_common::ProxyType;
 use pallet_admin_utils::PrecompileEnum;
 
 use crate::alpha::*;
+use crate::alpha_token::*;
 use crate::balance_transfer::*;
+use crate::childkey::*;
+use crate::commitments::*;
 use crate::crowdloan::*;
+use crate::drand::*;
 use crate::ed25519::*;
 use crate::extensions::*;
+use crate::identity::*;
 use crate::leasing::*;
+use crate::liquidity::*;
 use crate::metagraph::*;
 use crate::neuron::*;
+use crate::proxy::*;
 use crate::sr25519::*;
 use crate::staking::*;
 use crate::storage_query::*;
@@ -40,13 +47,21 @@ use crate::subnet::*;
 use crate::uid_lookup::*;
 
 mod alpha;
+mod alpha_token;
 mod balance_transfer;
+mod childkey;
+mod commitments;
 mod crowdloan;
+mod drand;
 mod ed25519;
 mod extensions;
+mod identity;
 mod leasing;
+mod liquidity;
+mod logs;
 mod metagraph;
 mod neuron;
+mod proxy;
 mod sr25519;
 mod staking;
 mod storage_query;
@@ -63,15 +78,21 @@ where
         + pallet_subtensor::Config
         + pallet_subtensor_swap::Config
         + pallet_proxy::Config<ProxyType = ProxyType>
-        + pallet_crowdloan::Config,
+        + pallet_crowdloan::Config
+        + pallet_commitments::Config
+        + pallet_drand::Config,
     R::AccountId: From<[u8; 32]> + ByteArray + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + From<pallet_crowdloan::Call<R>>
+        + From<pallet_subtensor_swap::Call<R>>
+        + From<pallet_commitments::Call<R>>
+        + From<pallet_drand::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as frame_system::Config>::RuntimeEvent: TryInto<pallet_proxy::Event<R>>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
@@ -90,15 +111,21 @@ where
         + pallet_subtensor::Config
         + pallet_subtensor_swap::Config
         + pallet_proxy::Config<ProxyType = ProxyType>
-        + pallet_crowdloan::Config,
+        + pallet_crowdloan::Config
+        + pallet_commitments::Config
+        + pallet_drand::Config,
     R::AccountId: From<[u8; 32]> + ByteArray + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + From<pallet_crowdloan::Call<R>>
+        + From<pallet_subtensor_swap::Call<R>>
+        + From<pallet_commitments::Call<R>>
+        + From<pallet_drand::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as frame_system::Config>::RuntimeEvent: TryInto<pallet_proxy::Event<R>>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
@@ -107,7 +134,7 @@ where
         Self(Default::default())
     }
 
-    pub fn used_addresses() -> [H160; 24] {
+    pub fn used_addresses() -> [H160; 31] {
         [
             hash(1),
             hash(2),
@@ -133,6 +160,13 @@ where
             hash(AlphaPrecompile::<R>::INDEX),
             hash(CrowdloanPrecompile::<R>::INDEX),
             hash(LeasingPrecompile::<R>::INDEX),
+            hash(LiquidityPrecompile::<R>::INDEX),
+            hash(CommitmentsPrecompile::<R>::INDEX),
+            hash(DrandPrecompile::<R>::INDEX),
+            hash(ProxyPrecompile::<R>::INDEX),
+            hash(ChildkeyPrecompile::<R>::INDEX),
+            hash(TypedStorageQueryPrecompile::<R>::INDEX),
+            hash(IdentityPrecompile::<R>::INDEX),
         ]
     }
 }
@@ -145,18 +179,24 @@ where
         + pallet_subtensor::Config
         + pallet_subtensor_swap::Config
         + pallet_proxy::Config<ProxyType = ProxyType>
-        + pallet_crowdloan::Config,
+        + pallet_crowdloan::Config
+        + pallet_commitments::Config
+        + pallet_drand::Config,
     R::AccountId: From<[u8; 32]> + ByteArray + Into<[u8; 32]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
         + From<pallet_proxy::Call<R>>
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + From<pallet_crowdloan::Call<R>>
+        + From<pallet_subtensor_swap::Call<R>>
+        + From<pallet_commitments::Call<R>>
+        + From<pallet_drand::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>
         + Decode,
     <<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
         From<Option<pallet_evm::AccountIdOf<R>>>,
+    <R as frame_system::Config>::RuntimeEvent: TryInto<pallet_proxy::Event<R>>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
@@ -219,13 +259,41 @@ where
             a if a == hash(LeasingPrecompile::<R>::INDEX) => {
                 LeasingPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Leasing)
             }
+            a if a == hash(LiquidityPrecompile::<R>::INDEX) => {
+                LiquidityPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Liquidity)
+            }
+            a if a == hash(CommitmentsPrecompile::<R>::INDEX) => {
+                CommitmentsPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Commitments)
+            }
+            a if a == hash(DrandPrecompile::<R>::INDEX) => {
+                DrandPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Drand)
+            }
+            a if a == hash(ProxyPrecompile::<R>::INDEX) => {
+                ProxyPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Proxy)
+            }
+            a if a == hash(ChildkeyPrecompile::<R>::INDEX) => {
+                ChildkeyPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Childkey)
+            }
+            a if a == hash(TypedStorageQueryPrecompile::<R>::INDEX) => {
+                TypedStorageQueryPrecompile::<R>::try_execute::<R>(
+                    handle,
+                    PrecompileEnum::TypedStorageQuery,
+                )
+            }
+            a if a == hash(IdentityPrecompile::<R>::INDEX) => {
+                IdentityPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Identity)
+            }
+            a if AlphaTokenPrecompile::<R>::netuid(a).is_some() => {
+                AlphaTokenPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::AlphaToken)
+            }
             _ => None,
         }
     }
 
     fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
         IsPrecompileResult::Answer {
-            is_precompile: Self::used_addresses().contains(&address),
+            is_precompile: Self::used_addresses().contains(&address)
+                || AlphaTokenPrecompile::<R>::netuid(address).is_some(),
             extra_cost: 0,
         }
     }
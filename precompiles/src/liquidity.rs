@@ -0,0 +1,280 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use pallet_subtensor_swap::{
+    AlphaSqrtPrice, CurrentLiquidity, CurrentTick, EnabledUserLiquidity, LastPositionId, Positions,
+    Ticks,
+    position::{Position, PositionId},
+    tick::TickIndex,
+    weights::WeightInfo,
+};
+use precompile_utils::{EvmResult, solidity::Codec};
+use sp_core::{H256, U256};
+use sp_runtime::traits::Dispatchable;
+use subtensor_runtime_common::NetUid;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+pub struct LiquidityPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for LiquidityPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor_swap::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor_swap::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2059;
+}
+
+// Ticks and liquidity deltas are signed in Solidity. They travel as sign-extended 256-bit words,
+// which is how the ABI encodes `int32`, `int64` and `int128`.
+#[precompile_utils::precompile]
+impl<R> LiquidityPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor_swap::Config,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor_swap::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("addLiquidity(bytes32,uint16,int32,int32,uint64)")]
+    #[precompile::payable]
+    fn add_liquidity(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        tick_low: U256,
+        tick_high: U256,
+        liquidity: u64,
+    ) -> EvmResult<u128> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor_swap::Call::<R>::add_liquidity {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid: netuid.into(),
+            tick_low: try_tick_from_word(tick_low)?,
+            tick_high: try_tick_from_word(tick_high)?,
+            liquidity,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))?;
+
+        // The position just added took the latest position ID.
+        Ok(LastPositionId::<R>::get())
+    }
+
+    #[precompile::public("removeLiquidity(bytes32,uint16,uint128)")]
+    #[precompile::payable]
+    fn remove_liquidity(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        position_id: u128,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor_swap::Call::<R>::remove_liquidity {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid: netuid.into(),
+            position_id: position_id.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("modifyPosition(bytes32,uint16,uint128,int64)")]
+    #[precompile::payable]
+    fn modify_position(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        position_id: u128,
+        liquidity_delta: U256,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let liquidity_delta = int_from_word(liquidity_delta)
+            .and_then(|delta| i64::try_from(delta).ok())
+            .ok_or(PrecompileFailure::Error {
+                exit_status: ExitError::Other("invalid liquidity delta".into()),
+            })?;
+        let call = pallet_subtensor_swap::Call::<R>::modify_position {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid: netuid.into(),
+            position_id: position_id.into(),
+            liquidity_delta,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("collectFees(bytes32,uint16,uint128)")]
+    #[precompile::payable]
+    fn collect_fees(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        position_id: u128,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let call = pallet_subtensor_swap::Call::<R>::collect_fees {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid: netuid.into(),
+            position_id: position_id.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("getPositionIds(uint16,bytes32)")]
+    #[precompile::view]
+    fn get_position_ids(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        coldkey: H256,
+    ) -> EvmResult<Vec<u128>> {
+        let coldkey = R::AccountId::from(coldkey.0);
+
+        let mut position_ids = Vec::new();
+        for position in Positions::<R>::iter_prefix_values((NetUid::from(netuid), coldkey)) {
+            handle.record_db_reads::<R>(1)?;
+            position_ids.push(position.id.into());
+        }
+
+        Ok(position_ids)
+    }
+
+    #[precompile::public("getPosition(uint16,bytes32,uint128)")]
+    #[precompile::view]
+    fn get_position(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        coldkey: H256,
+        position_id: u128,
+    ) -> EvmResult<PositionInfo> {
+        handle.record_weight::<R>(
+            <R as pallet_subtensor_swap::Config>::WeightInfo::position_info(),
+        )?;
+
+        let netuid = NetUid::from(netuid);
+        let coldkey = R::AccountId::from(coldkey.0);
+        let position: Position<R> =
+            Positions::<R>::get((netuid, coldkey, PositionId::from(position_id))).ok_or(
+                PrecompileFailure::Error {
+                    exit_status: ExitError::Other("Position not found".into()),
+                },
+            )?;
+
+        let (tao, alpha) = position
+            .to_token_amounts(AlphaSqrtPrice::<R>::get(netuid))
+            .map_err(|_| PrecompileFailure::Error {
+                exit_status: ExitError::Other("invalid position tick range".into()),
+            })?;
+        // Collecting on a copy reports the pending fees without claiming them.
+        let (fees_tao, fees_alpha) = position.clone().collect_fees();
+
+        Ok(PositionInfo {
+            id: position.id.into(),
+            tick_low: int_to_word(position.tick_low.get().into()),
+            tick_high: int_to_word(position.tick_high.get().into()),
+            liquidity: position.liquidity,
+            tao,
+            alpha,
+            fees_tao,
+            fees_alpha,
+        })
+    }
+
+    #[precompile::public("getCurrentTick(uint16)")]
+    #[precompile::view]
+    fn get_current_tick(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(1)?;
+
+        let tick = CurrentTick::<R>::get(NetUid::from(netuid));
+        Ok(int_to_word(tick.get().into()))
+    }
+
+    #[precompile::public("getCurrentLiquidity(uint16)")]
+    #[precompile::view]
+    fn get_current_liquidity(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(CurrentLiquidity::<R>::get(NetUid::from(netuid)))
+    }
+
+    #[precompile::public("getTick(uint16,int32)")]
+    #[precompile::view]
+    fn get_tick(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        index: U256,
+    ) -> EvmResult<TickInfo> {
+        handle.record_db_reads::<R>(1)?;
+
+        let index = try_tick_from_word(index)?;
+        let tick = Ticks::<R>::get(NetUid::from(netuid), index).unwrap_or_default();
+
+        Ok(TickInfo {
+            liquidity_net: int_to_word(tick.liquidity_net),
+            liquidity_gross: tick.liquidity_gross,
+        })
+    }
+
+    #[precompile::public("isUserLiquidityEnabled(uint16)")]
+    #[precompile::view]
+    fn is_user_liquidity_enabled(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(EnabledUserLiquidity::<R>::get(NetUid::from(netuid)))
+    }
+}
+
+#[derive(Codec)]
+struct PositionInfo {
+    id: u128,
+    tick_low: U256,
+    tick_high: U256,
+    liquidity: u64,
+    tao: u64,
+    alpha: u64,
+    fees_tao: u64,
+    fees_alpha: u64,
+}
+
+#[derive(Codec)]
+struct TickInfo {
+    liquidity_net: U256,
+    liquidity_gross: u64,
+}
+
+/// Sign-extends `value` to a 256-bit word.
+pub(crate) fn int_to_word(value: i128) -> U256 {
+    if value < 0 {
+        !U256::from(!value as u128)
+    } else {
+        U256::from(value as u128)
+    }
+}
+
+/// Reads a sign-extended 256-bit word, `None` if it doesn't fit in an `i128`.
+fn int_from_word(word: U256) -> Option<i128> {
+    let value = word.low_u128() as i128;
+    (int_to_word(value) == word).then_some(value)
+}
+
+fn try_tick_from_word(word: U256) -> EvmResult<TickIndex> {
+    int_from_word(word)
+        .and_then(|index| i32::try_from(index).ok())
+        .and_then(|index| TickIndex::new(index).ok())
+        .ok_or(PrecompileFailure::Error {
+            exit_status: ExitError::Other("invalid tick index".into()),
+        })
+}
@@ -0,0 +1,570 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use codec::Encode;
+use frame_system::RawOrigin;
+use precompile_utils::solidity::{self, Codec};
+use sp_core::{H256, U256, keccak_256};
+use sp_runtime::traits::{StaticLookup, UniqueSaturatedInto};
+
+use crate::liquidity::int_to_word;
+
+/// A Solidity event recorded by a precompile, usually once the runtime call it dispatched
+/// succeeded.
+pub(crate) struct DispatchLog {
+    pub(crate) topics: Vec<H256>,
+    pub(crate) data: Vec<u8>,
+    /// Encodes the data once the call is dispatched, for values only known afterwards.
+    dispatched_data: Option<Box<dyn FnOnce() -> Vec<u8>>>,
+}
+
+impl DispatchLog {
+    pub(crate) fn new(
+        signature: &str,
+        indexed: impl IntoIterator<Item = H256>,
+        data: impl Codec,
+    ) -> Self {
+        Self {
+            data: solidity::encode_event_data(data),
+            ..Self::indexed(signature, indexed)
+        }
+    }
+
+    /// A log whose data is read by `data` once the call is dispatched.
+    fn after_dispatch<D: Codec>(
+        signature: &str,
+        indexed: impl IntoIterator<Item = H256>,
+        data: impl FnOnce() -> D + 'static,
+    ) -> Self {
+        Self {
+            dispatched_data: Some(Box::new(move || solidity::encode_event_data(data()))),
+            ..Self::indexed(signature, indexed)
+        }
+    }
+
+    /// A log for an event whose parameters are all indexed.
+    fn indexed(signature: &str, indexed: impl IntoIterator<Item = H256>) -> Self {
+        let mut topics = Vec::from([H256(keccak_256(signature.as_bytes()))]);
+        topics.extend(indexed);
+
+        Self {
+            topics,
+            data: Vec::new(),
+            dispatched_data: None,
+        }
+    }
+
+    /// The log of the call once it is dispatched.
+    pub(crate) fn dispatched(mut self) -> Self {
+        if let Some(data) = self.dispatched_data.take() {
+            self.data = data();
+        }
+        self
+    }
+
+    /// Gas cost of the `LOG` opcode for this log.
+    pub(crate) fn cost(&self) -> u64 {
+        const G_LOG: u64 = 375;
+        const G_LOG_TOPIC: u64 = 375;
+        const G_LOG_DATA: u64 = 8;
+
+        G_LOG
+            .saturating_add(G_LOG_TOPIC.saturating_mul(self.topics.len() as u64))
+            .saturating_add(G_LOG_DATA.saturating_mul(self.data.len() as u64))
+    }
+}
+
+/// Runtime calls the precompiles dispatch, mapped to the Solidity events they emit.
+pub(crate) trait DispatchLogs<AccountId> {
+    /// The log for dispatching the call from `origin`, `None` if the call has no event.
+    ///
+    /// Built before the call is dispatched, so it can capture values the call is about to
+    /// change, like a full unstake amount or the ID a new crowdloan will get. Values only known
+    /// after the call, like the amount a partial limit order executed, are read once it
+    /// succeeded.
+    fn dispatch_log(&self, _origin: &RawOrigin<AccountId>) -> Option<DispatchLog> {
+        None
+    }
+}
+
+impl<R> DispatchLogs<R::AccountId> for pallet_subtensor::Call<R>
+where
+    R: pallet_subtensor::Config,
+{
+    fn dispatch_log(&self, origin: &RawOrigin<R::AccountId>) -> Option<DispatchLog> {
+        let RawOrigin::Signed(who) = origin else {
+            return None;
+        };
+
+        let log = match self {
+            Self::add_stake {
+                hotkey,
+                netuid,
+                amount_staked,
+            } => DispatchLog::new(
+                "StakeAdded(bytes32,bytes32,uint16,uint64)",
+                [account_topic(who), account_topic(hotkey)],
+                (u16::from(*netuid), *amount_staked),
+            ),
+            // A limit order may execute partially, so its amount is the TAO it took.
+            Self::add_stake_limit { hotkey, netuid, .. } => {
+                let (coldkey, netuid) = (who.clone(), *netuid);
+                let balance = pallet_subtensor::Pallet::<R>::get_coldkey_balance(&coldkey);
+                DispatchLog::after_dispatch(
+                    "StakeAdded(bytes32,bytes32,uint16,uint64)",
+                    [account_topic(who), account_topic(hotkey)],
+                    move || {
+                        let spent = balance.saturating_sub(
+                            pallet_subtensor::Pallet::<R>::get_coldkey_balance(&coldkey),
+                        );
+                        (u16::from(netuid), spent)
+                    },
+                )
+            }
+            Self::remove_stake {
+                hotkey,
+                netuid,
+                amount_unstaked,
+            } => DispatchLog::new(
+                "StakeRemoved(bytes32,bytes32,uint16,uint64)",
+                [account_topic(who), account_topic(hotkey)],
+                (u16::from(*netuid), u64::from(*amount_unstaked)),
+            ),
+            // A limit order may execute partially, so its amount is the alpha it unstaked.
+            Self::remove_stake_limit { hotkey, netuid, .. } => {
+                let topics = [account_topic(who), account_topic(hotkey)];
+                let (hotkey, coldkey, netuid) = (hotkey.clone(), who.clone(), *netuid);
+                let stake = move || {
+                    pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_and_coldkey_on_subnet(
+                        &hotkey, &coldkey, netuid,
+                    )
+                };
+                let stake_before = u64::from(stake());
+                DispatchLog::after_dispatch(
+                    "StakeRemoved(bytes32,bytes32,uint16,uint64)",
+                    topics,
+                    move || {
+                        let unstaked = stake_before.saturating_sub(u64::from(stake()));
+                        (u16::from(netuid), unstaked)
+                    },
+                )
+            }
+            Self::remove_stake_full_limit { hotkey, netuid, .. } => {
+                let stake =
+                    pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_and_coldkey_on_subnet(
+                        hotkey, who, *netuid,
+                    );
+                DispatchLog::new(
+                    "StakeRemoved(bytes32,bytes32,uint16,uint64)",
+                    [account_topic(who), account_topic(hotkey)],
+                    (u16::from(*netuid), u64::from(stake)),
+                )
+            }
+            Self::move_stake {
+                origin_hotkey,
+                destination_hotkey,
+                origin_netuid,
+                destination_netuid,
+                alpha_amount,
+            } => DispatchLog::new(
+                "StakeMoved(bytes32,bytes32,bytes32,uint16,uint16,uint64)",
+                [
+                    account_topic(who),
+                    account_topic(origin_hotkey),
+                    account_topic(destination_hotkey),
+                ],
+                (
+                    u16::from(*origin_netuid),
+                    u16::from(*destination_netuid),
+                    u64::from(*alpha_amount),
+                ),
+            ),
+            Self::transfer_stake {
+                destination_coldkey,
+                hotkey,
+                origin_netuid,
+                destination_netuid,
+                alpha_amount,
+            } => DispatchLog::new(
+                "StakeTransferred(bytes32,bytes32,bytes32,uint16,uint16,uint64)",
+                [
+                    account_topic(who),
+                    account_topic(destination_coldkey),
+                    account_topic(hotkey),
+                ],
+                (
+                    u16::from(*origin_netuid),
+                    u16::from(*destination_netuid),
+                    u64::from(*alpha_amount),
+                ),
+            ),
+            Self::burned_register { netuid, hotkey } => DispatchLog::new(
+                "NeuronRegistered(bytes32,bytes32,uint16)",
+                [account_topic(who), account_topic(hotkey)],
+                u16::from(*netuid),
+            ),
+            // A weights key sets the weights of the hotkey that authorized it.
+            Self::set_weights { netuid, .. }
+            | Self::reveal_weights { netuid, .. }
+            | Self::set_weights_delta { netuid, .. }
+            | Self::reveal_weights_delta { netuid, .. } => DispatchLog::new(
+                "WeightsSet(bytes32,uint16)",
+                [account_topic(
+                    &pallet_subtensor::Pallet::<R>::get_weights_signer_hotkey(*netuid, who),
+                )],
+                u16::from(*netuid),
+            ),
+            Self::commit_weights {
+                netuid,
+                commit_hash,
+            } => DispatchLog::new(
+                "WeightsCommitted(bytes32,uint16,bytes32)",
+                [account_topic(
+                    &pallet_subtensor::Pallet::<R>::get_weights_signer_hotkey(*netuid, who),
+                )],
+                (u16::from(*netuid), *commit_hash),
+            ),
+            Self::set_weights_key {
+                netuid,
+                weights_key,
+            } => DispatchLog::new(
+                "WeightsKeySet(bytes32,bytes32,uint16)",
+                [account_topic(who), account_topic(weights_key)],
+                u16::from(*netuid),
+            ),
+            Self::remove_weights_key { netuid } => DispatchLog::new(
+                "WeightsKeyRemoved(bytes32,uint16)",
+                [account_topic(who)],
+                u16::from(*netuid),
+            ),
+            Self::serve_axon { netuid, .. }
+            | Self::serve_axon_tls { netuid, .. }
+            | Self::serve_axons { netuid, .. } => DispatchLog::new(
+                "AxonServed(bytes32,uint16)",
+                [account_topic(who)],
+                u16::from(*netuid),
+            ),
+            Self::serve_prometheus { netuid, .. } => DispatchLog::new(
+                "PrometheusServed(bytes32,uint16)",
+                [account_topic(who)],
+                u16::from(*netuid),
+            ),
+            Self::set_children {
+                hotkey,
+                netuid,
+                children,
+            } => DispatchLog::new(
+                "ChildrenScheduled(bytes32,uint16,(uint64,bytes32)[])",
+                [account_topic(hotkey), uint_topic(u16::from(*netuid))],
+                children
+                    .iter()
+                    .map(|(proportion, child)| (*proportion, account_topic(child)))
+                    .collect::<Vec<_>>(),
+            ),
+            Self::set_childkey_take {
+                hotkey,
+                netuid,
+                take,
+            } => DispatchLog::new(
+                "ChildkeyTakeSet(bytes32,uint16,uint16)",
+                [account_topic(hotkey), uint_topic(u16::from(*netuid))],
+                *take,
+            ),
+            Self::increase_take { hotkey, take } => DispatchLog::new(
+                "TakeIncreased(bytes32,bytes32,uint16)",
+                [account_topic(who), account_topic(hotkey)],
+                *take,
+            ),
+            Self::decrease_take { hotkey, take } => DispatchLog::new(
+                "TakeDecreased(bytes32,bytes32,uint16)",
+                [account_topic(who), account_topic(hotkey)],
+                *take,
+            ),
+            Self::set_identity { .. } => {
+                DispatchLog::indexed("IdentitySet(bytes32)", [account_topic(who)])
+            }
+            Self::set_subnet_identity { netuid, .. } => DispatchLog::indexed(
+                "SubnetIdentitySet(uint16)",
+                [uint_topic(u16::from(*netuid))],
+            ),
+            Self::register_network_with_identity { hotkey, .. } => DispatchLog::indexed(
+                "NetworkRegistered(bytes32,bytes32)",
+                [account_topic(who), account_topic(hotkey)],
+            ),
+            Self::terminate_lease { lease_id, hotkey } => DispatchLog::new(
+                "LeaseTerminated(uint32,bytes32,bytes32)",
+                [uint_topic(*lease_id), account_topic(who)],
+                account_topic(hotkey),
+            ),
+            _ => return None,
+        };
+
+        Some(log)
+    }
+}
+
+impl<R> DispatchLogs<R::AccountId> for pallet_crowdloan::Call<R>
+where
+    R: pallet_crowdloan::Config,
+{
+    fn dispatch_log(&self, origin: &RawOrigin<R::AccountId>) -> Option<DispatchLog> {
+        let RawOrigin::Signed(who) = origin else {
+            return None;
+        };
+
+        let log = match self {
+            Self::create {
+                deposit, cap, end, ..
+            } => DispatchLog::new(
+                "CrowdloanCreated(uint32,bytes32,uint64,uint64,uint32)",
+                [
+                    uint_topic(pallet_crowdloan::NextCrowdloanId::<R>::get()),
+                    account_topic(who),
+                ],
+                (
+                    UniqueSaturatedInto::<u64>::unique_saturated_into(*deposit),
+                    UniqueSaturatedInto::<u64>::unique_saturated_into(*cap),
+                    UniqueSaturatedInto::<u32>::unique_saturated_into(*end),
+                ),
+            ),
+            Self::contribute {
+                crowdloan_id,
+                amount,
+            } => DispatchLog::new(
+                "Contributed(uint32,bytes32,uint64)",
+                [uint_topic(*crowdloan_id), account_topic(who)],
+                UniqueSaturatedInto::<u64>::unique_saturated_into(*amount),
+            ),
+            Self::withdraw { crowdloan_id } => DispatchLog::indexed(
+                "Withdrew(uint32,bytes32)",
+                [uint_topic(*crowdloan_id), account_topic(who)],
+            ),
+            Self::finalize { crowdloan_id } => {
+                DispatchLog::indexed("CrowdloanFinalized(uint32)", [uint_topic(*crowdloan_id)])
+            }
+            Self::refund { crowdloan_id } => {
+                DispatchLog::indexed("CrowdloanRefunded(uint32)", [uint_topic(*crowdloan_id)])
+            }
+            Self::dissolve { crowdloan_id } => {
+                DispatchLog::indexed("CrowdloanDissolved(uint32)", [uint_topic(*crowdloan_id)])
+            }
+            Self::update_min_contribution {
+                crowdloan_id,
+                new_min_contribution,
+            } => DispatchLog::new(
+                "MinContributionUpdated(uint32,uint64)",
+                [uint_topic(*crowdloan_id)],
+                UniqueSaturatedInto::<u64>::unique_saturated_into(*new_min_contribution),
+            ),
+            Self::update_end {
+                crowdloan_id,
+                new_end,
+            } => DispatchLog::new(
+                "EndUpdated(uint32,uint32)",
+                [uint_topic(*crowdloan_id)],
+                UniqueSaturatedInto::<u32>::unique_saturated_into(*new_end),
+            ),
+            Self::update_cap {
+                crowdloan_id,
+                new_cap,
+            } => DispatchLog::new(
+                "CapUpdated(uint32,uint64)",
+                [uint_topic(*crowdloan_id)],
+                UniqueSaturatedInto::<u64>::unique_saturated_into(*new_cap),
+            ),
+            _ => return None,
+        };
+
+        Some(log)
+    }
+}
+
+impl<R> DispatchLogs<R::AccountId> for pallet_balances::Call<R>
+where
+    R: pallet_balances::Config,
+{
+    fn dispatch_log(&self, origin: &RawOrigin<R::AccountId>) -> Option<DispatchLog> {
+        let RawOrigin::Signed(who) = origin else {
+            return None;
+        };
+
+        match self {
+            Self::transfer_allow_death { dest, value } => {
+                let dest = R::Lookup::lookup(dest.clone()).ok()?;
+                let value: u128 = (*value).unique_saturated_into();
+                Some(DispatchLog::new(
+                    "Transfer(bytes32,bytes32,uint256)",
+                    [account_topic(who), account_topic(&dest)],
+                    U256::from(value),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<R> DispatchLogs<R::AccountId> for pallet_proxy::Call<R>
+where
+    R: pallet_proxy::Config,
+{
+    fn dispatch_log(&self, origin: &RawOrigin<R::AccountId>) -> Option<DispatchLog> {
+        let RawOrigin::Signed(who) = origin else {
+            return None;
+        };
+
+        let (signature, delegate) = match self {
+            Self::add_proxy { delegate, .. } => ("ProxyAdded(bytes32,bytes32)", delegate),
+            Self::remove_proxy { delegate, .. } => ("ProxyRemoved(bytes32,bytes32)", delegate),
+            _ => return None,
+        };
+        let delegate = R::Lookup::lookup(delegate.clone()).ok()?;
+
+        Some(DispatchLog::indexed(
+            signature,
+            [account_topic(who), account_topic(&delegate)],
+        ))
+    }
+}
+
+impl<R> DispatchLogs<R::AccountId> for pallet_subtensor_swap::Call<R>
+where
+    R: pallet_subtensor_swap::Config,
+{
+    fn dispatch_log(&self, origin: &RawOrigin<R::AccountId>) -> Option<DispatchLog> {
+        let RawOrigin::Signed(who) = origin else {
+            return None;
+        };
+
+        let log = match self {
+            Self::add_liquidity {
+                hotkey,
+                netuid,
+                liquidity,
+                ..
+            } => DispatchLog::new(
+                "LiquidityAdded(bytes32,bytes32,uint128,uint16,uint64)",
+                [
+                    account_topic(who),
+                    account_topic(hotkey),
+                    uint_topic(pallet_subtensor_swap::LastPositionId::<R>::get().saturating_add(1)),
+                ],
+                (u16::from(*netuid), *liquidity),
+            ),
+            Self::remove_liquidity {
+                hotkey,
+                netuid,
+                position_id,
+            } => DispatchLog::new(
+                "LiquidityRemoved(bytes32,bytes32,uint128,uint16)",
+                [
+                    account_topic(who),
+                    account_topic(hotkey),
+                    uint_topic(u128::from(*position_id)),
+                ],
+                u16::from(*netuid),
+            ),
+            Self::modify_position {
+                hotkey,
+                netuid,
+                position_id,
+                liquidity_delta,
+            } => DispatchLog::new(
+                "PositionModified(bytes32,bytes32,uint128,uint16,int64)",
+                [
+                    account_topic(who),
+                    account_topic(hotkey),
+                    uint_topic(u128::from(*position_id)),
+                ],
+                (u16::from(*netuid), int_to_word((*liquidity_delta).into())),
+            ),
+            Self::collect_fees {
+                hotkey,
+                netuid,
+                position_id,
+            } => DispatchLog::new(
+                "FeesCollected(bytes32,bytes32,uint128,uint16)",
+                [
+                    account_topic(who),
+                    account_topic(hotkey),
+                    uint_topic(u128::from(*position_id)),
+                ],
+                u16::from(*netuid),
+            ),
+            _ => return None,
+        };
+
+        Some(log)
+    }
+}
+
+impl<R> DispatchLogs<R::AccountId> for pallet_commitments::Call<R>
+where
+    R: pallet_commitments::Config,
+{
+    fn dispatch_log(&self, origin: &RawOrigin<R::AccountId>) -> Option<DispatchLog> {
+        let RawOrigin::Signed(who) = origin else {
+            return None;
+        };
+
+        match self {
+            Self::set_commitment { netuid, .. } => Some(DispatchLog::indexed(
+                "CommitmentSet(uint16,bytes32)",
+                [uint_topic(u16::from(*netuid)), account_topic(who)],
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl<R> DispatchLogs<R::AccountId> for pallet_drand::Call<R>
+where
+    R: pallet_drand::Config,
+{
+    fn dispatch_log(&self, origin: &RawOrigin<R::AccountId>) -> Option<DispatchLog> {
+        let RawOrigin::Signed(who) = origin else {
+            return None;
+        };
+
+        match self {
+            Self::request_randomness { round } => Some(DispatchLog::new(
+                "RandomnessRequested(uint64,bytes32,uint64)",
+                [
+                    uint_topic(pallet_drand::NextRandomnessRequestId::<R>::get()),
+                    account_topic(who),
+                ],
+                *round,
+            )),
+            _ => None,
+        }
+    }
+}
+
+// Hyperparameter updates have no events of their own.
+impl<R> DispatchLogs<R::AccountId> for pallet_admin_utils::Call<R> where
+    R: pallet_admin_utils::Config
+{
+}
+
+/// An account as a `bytes32` topic.
+fn account_topic(account: &impl Encode) -> H256 {
+    account.using_encoded(|bytes| {
+        let mut topic = H256::zero();
+        topic
+            .as_bytes_mut()
+            .iter_mut()
+            .zip(bytes)
+            .for_each(|(word, byte)| *word = *byte);
+        topic
+    })
+}
+
+/// An unsigned integer as a topic.
+fn uint_topic(value: impl Into<u128>) -> H256 {
+    let mut topic = H256::zero();
+    topic
+        .as_bytes_mut()
+        .iter_mut()
+        .rev()
+        .zip(value.into().to_be_bytes().iter().rev())
+        .for_each(|(word, byte)| *word = *byte);
+    topic
+}
@@ -1,19 +1,29 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use fp_evm::{ExitError, PrecompileFailure, PrecompileHandle};
-use pallet_subtensor::AxonInfo as SubtensorModuleAxonInfo;
+use frame_support::traits::Get;
+use pallet_subtensor::{
+    AxonInfo as SubtensorModuleAxonInfo,
+    ValidatorWeightMetrics as SubtensorModuleValidatorWeightMetrics,
+};
+use pallet_subtensor_swap::weights::WeightInfo;
 use precompile_utils::{EvmResult, solidity::Codec};
-use sp_core::{ByteArray, H256};
+use sp_core::{ByteArray, H256, U256};
 use subtensor_runtime_common::NetUid;
 
-use crate::PrecompileExt;
+use crate::liquidity::int_to_word;
+use crate::{PrecompileExt, PrecompileHandleExt};
 
 pub struct MetagraphPrecompile<R>(PhantomData<R>);
 
 impl<R> PrecompileExt<R::AccountId> for MetagraphPrecompile<R>
 where
-    R: frame_system::Config + pallet_subtensor::Config,
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_subtensor_swap::Config,
     R::AccountId: From<[u8; 32]> + ByteArray,
 {
     const INDEX: u64 = 2050;
@@ -22,12 +32,17 @@ where
 #[precompile_utils::precompile]
 impl<R> MetagraphPrecompile<R>
 where
-    R: frame_system::Config + pallet_subtensor::Config,
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_subtensor_swap::Config,
     R::AccountId: ByteArray,
 {
     #[precompile::public("getUidCount(uint16)")]
     #[precompile::view]
-    fn get_uid_count(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_uid_count(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::SubnetworkN::<R>::get(NetUid::from(
             netuid,
         )))
@@ -35,7 +50,18 @@ where
 
     #[precompile::public("getStake(uint16,uint16)")]
     #[precompile::view]
-    fn get_stake(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+    fn get_stake(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+        // The hotkey, then its alpha and the alpha price on every subnet.
+        let subnets = u64::from(pallet_subtensor::TotalNetworks::<R>::get());
+        let db_weight = <R as frame_system::Config>::DbWeight::get();
+        let per_subnet = <R as pallet_subtensor_swap::Config>::WeightInfo::current_alpha_price()
+            .saturating_add(db_weight.reads(2));
+        handle.record_weight::<R>(
+            per_subnet
+                .saturating_mul(subnets)
+                .saturating_add(db_weight.reads(2)),
+        )?;
+
         let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid.into(), uid)
             .map_err(|_| PrecompileFailure::Error {
                 exit_status: ExitError::InvalidRange,
@@ -48,7 +74,9 @@ where
 
     #[precompile::public("getRank(uint16,uint16)")]
     #[precompile::view]
-    fn get_rank(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_rank(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_rank_for_uid(
             netuid.into(),
             uid,
@@ -57,7 +85,9 @@ where
 
     #[precompile::public("getTrust(uint16,uint16)")]
     #[precompile::view]
-    fn get_trust(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_trust(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_trust_for_uid(
             netuid.into(),
             uid,
@@ -66,7 +96,9 @@ where
 
     #[precompile::public("getConsensus(uint16,uint16)")]
     #[precompile::view]
-    fn get_consensus(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_consensus(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_consensus_for_uid(
             netuid.into(),
             uid,
@@ -75,7 +107,9 @@ where
 
     #[precompile::public("getIncentive(uint16,uint16)")]
     #[precompile::view]
-    fn get_incentive(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_incentive(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_incentive_for_uid(
             netuid.into(),
             uid,
@@ -84,7 +118,9 @@ where
 
     #[precompile::public("getDividends(uint16,uint16)")]
     #[precompile::view]
-    fn get_dividends(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_dividends(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_dividends_for_uid(
             netuid.into(),
             uid,
@@ -93,13 +129,17 @@ where
 
     #[precompile::public("getEmission(uint16,uint16)")]
     #[precompile::view]
-    fn get_emission(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+    fn get_emission(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_emission_for_uid(netuid.into(), uid).into())
     }
 
     #[precompile::public("getVtrust(uint16,uint16)")]
     #[precompile::view]
-    fn get_vtrust(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+    fn get_vtrust(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_validator_trust_for_uid(
             netuid.into(),
             uid,
@@ -109,10 +149,12 @@ where
     #[precompile::public("getValidatorStatus(uint16,uint16)")]
     #[precompile::view]
     fn get_validator_status(
-        _: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
         uid: u16,
     ) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_validator_permit_for_uid(
             netuid.into(),
             uid,
@@ -121,7 +163,13 @@ where
 
     #[precompile::public("getLastUpdate(uint16,uint16)")]
     #[precompile::view]
-    fn get_last_update(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+    fn get_last_update(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        uid: u16,
+    ) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_last_update_for_uid(
             netuid.into(),
             uid,
@@ -130,7 +178,9 @@ where
 
     #[precompile::public("getIsActive(uint16,uint16)")]
     #[precompile::view]
-    fn get_is_active(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<bool> {
+    fn get_is_active(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Pallet::<R>::get_active_for_uid(
             netuid.into(),
             uid,
@@ -139,7 +189,9 @@ where
 
     #[precompile::public("getAxon(uint16,uint16)")]
     #[precompile::view]
-    fn get_axon(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<AxonInfo> {
+    fn get_axon(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<AxonInfo> {
+        handle.record_db_reads::<R>(2)?;
+
         let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid.into(), uid)
             .map_err(|_| PrecompileFailure::Error {
                 exit_status: ExitError::Other("hotkey not found".into()),
@@ -150,7 +202,9 @@ where
 
     #[precompile::public("getHotkey(uint16,uint16)")]
     #[precompile::view]
-    fn get_hotkey(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<H256> {
+    fn get_hotkey(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<H256> {
+        handle.record_db_reads::<R>(1)?;
+
         pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid.into(), uid)
             .map(|acc| H256::from_slice(acc.as_slice()))
             .map_err(|_| PrecompileFailure::Error {
@@ -160,7 +214,9 @@ where
 
     #[precompile::public("getColdkey(uint16,uint16)")]
     #[precompile::view]
-    fn get_coldkey(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<H256> {
+    fn get_coldkey(handle: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<H256> {
+        handle.record_db_reads::<R>(2)?;
+
         let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid.into(), uid)
             .map_err(|_| PrecompileFailure::Error {
                 exit_status: ExitError::InvalidRange,
@@ -169,6 +225,23 @@ where
 
         Ok(H256::from_slice(coldkey.as_slice()))
     }
+
+    #[precompile::public("getWeightMetrics(uint16,uint16)")]
+    #[precompile::view]
+    fn get_weight_metrics(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        uid: u16,
+    ) -> EvmResult<Vec<WeightMetrics>> {
+        handle.record_db_reads::<R>(1)?;
+
+        Ok(
+            pallet_subtensor::Pallet::<R>::get_validator_weight_metrics(netuid.into(), uid)
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        )
+    }
 }
 
 #[derive(Codec)]
@@ -193,3 +266,27 @@ impl From<SubtensorModuleAxonInfo> for AxonInfo {
         }
     }
 }
+
+/// Weight-setting metrics of one epoch.
+#[derive(Codec)]
+struct WeightMetrics {
+    block: u64,
+    consensus_similarity: u16,
+    clipped_share: u16,
+    bond_share: u16,
+    bonds_growth: U256,
+    blocks_since_update: u64,
+}
+
+impl From<SubtensorModuleValidatorWeightMetrics> for WeightMetrics {
+    fn from(value: SubtensorModuleValidatorWeightMetrics) -> Self {
+        Self {
+            block: value.block,
+            consensus_similarity: value.consensus_similarity,
+            clipped_share: value.clipped_share,
+            bond_share: value.bond_share,
+            bonds_growth: int_to_word(value.bonds_growth.into()),
+            blocks_since_update: value.blocks_since_update,
+        }
+    }
+}
@@ -98,6 +98,37 @@ where
         )
     }
 
+    #[precompile::public("setWeightsKey(uint16,bytes32)")]
+    #[precompile::payable]
+    pub fn set_weights_key(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        weights_key: H256,
+    ) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::set_weights_key {
+            netuid: netuid.into(),
+            weights_key: R::AccountId::from(weights_key.0),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
+    #[precompile::public("removeWeightsKey(uint16)")]
+    #[precompile::payable]
+    pub fn remove_weights_key(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<()> {
+        let call = pallet_subtensor::Call::<R>::remove_weights_key {
+            netuid: netuid.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
     #[precompile::public("burnedRegister(uint16,bytes32)")]
     #[precompile::payable]
     fn burned_register(
@@ -181,6 +212,38 @@ where
         )
     }
 
+    #[precompile::public("serveAxons(uint16,uint32,(uint8,uint128,uint8,uint16,uint8)[])")]
+    #[precompile::payable]
+    fn serve_axons(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+        version: u32,
+        endpoints: Vec<(u8, u128, u8, u16, u8)>,
+    ) -> EvmResult<()> {
+        let endpoints = endpoints
+            .into_iter()
+            .map(
+                |(protocol, ip, ip_type, port, priority)| pallet_subtensor::AxonEndpoint {
+                    protocol,
+                    ip,
+                    ip_type,
+                    port,
+                    priority,
+                },
+            )
+            .collect();
+        let call = pallet_subtensor::Call::<R>::serve_axons {
+            netuid: netuid.into(),
+            version,
+            endpoints,
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(
+            call,
+            RawOrigin::Signed(handle.caller_account_id::<R>()),
+        )
+    }
+
     #[precompile::public("servePrometheus(uint16,uint32,uint128,uint16,uint8)")]
     #[precompile::payable]
     #[allow(clippy::too_many_arguments)]
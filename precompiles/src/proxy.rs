@@ -0,0 +1,368 @@
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::marker::PhantomData;
+
+use codec::DecodeAll;
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    storage::{TransactionOutcome, with_transaction},
+};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use precompile_utils::{EvmResult, prelude::UnboundedBytes, solidity::Codec};
+use sp_core::H256;
+use sp_runtime::{
+    DispatchError,
+    traits::{Dispatchable, StaticLookup},
+};
+use subtensor_runtime_common::ProxyType;
+
+use crate::{PrecompileExt, PrecompileHandleExt, logs::DispatchLogs};
+
+/// The maximum number of calls in a batch.
+const MAX_BATCH_CALLS: usize = 16;
+
+pub struct ProxyPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for ProxyPrecompile<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_proxy::Config<ProxyType = ProxyType>,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_proxy::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as frame_system::Config>::RuntimeEvent: TryInto<pallet_proxy::Event<R>>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    const INDEX: u64 = 2062;
+}
+
+// Calls are SCALE-encoded `pallet_subtensor::Call`s, limited to the whitelist in
+// `is_batchable`. The `proxy*` variants dispatch each of them through `pallet_proxy::proxy` on
+// behalf of `real`, so the proxy of the caller is checked and filtered by the proxy pallet.
+#[precompile_utils::precompile]
+impl<R> ProxyPrecompile<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_proxy::Config<ProxyType = ProxyType>,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_proxy::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as frame_system::Config>::RuntimeEvent: TryInto<pallet_proxy::Event<R>>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    #[precompile::public("addProxy(bytes32,uint8,uint32)")]
+    fn add_proxy(
+        handle: &mut impl PrecompileHandle,
+        delegate: H256,
+        proxy_type: u8,
+        delay: u32,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let delegate = R::AccountId::from(delegate.0);
+        let call = pallet_proxy::Call::<R>::add_proxy {
+            delegate: <R as frame_system::Config>::Lookup::unlookup(delegate),
+            proxy_type: try_proxy_type(proxy_type)?,
+            delay: delay.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("removeProxy(bytes32,uint8,uint32)")]
+    fn remove_proxy(
+        handle: &mut impl PrecompileHandle,
+        delegate: H256,
+        proxy_type: u8,
+        delay: u32,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let delegate = R::AccountId::from(delegate.0);
+        let call = pallet_proxy::Call::<R>::remove_proxy {
+            delegate: <R as frame_system::Config>::Lookup::unlookup(delegate),
+            proxy_type: try_proxy_type(proxy_type)?,
+            delay: delay.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id))
+    }
+
+    #[precompile::public("batch(bytes[])")]
+    fn batch(
+        handle: &mut impl PrecompileHandle,
+        calls: Vec<UnboundedBytes>,
+    ) -> EvmResult<Vec<CallResult>> {
+        let account_id = handle.caller_account_id::<R>();
+        let calls = Self::try_decode_calls(calls)?;
+
+        Self::dispatch_batch(handle, Dispatcher::Signed(account_id), calls)
+    }
+
+    #[precompile::public("batchAll(bytes[])")]
+    fn batch_all(handle: &mut impl PrecompileHandle, calls: Vec<UnboundedBytes>) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let calls = Self::try_decode_calls(calls)?;
+
+        Self::dispatch_batch_all(handle, Dispatcher::Signed(account_id), calls)
+    }
+
+    #[precompile::public("proxyBatch(bytes32,bytes[])")]
+    fn proxy_batch(
+        handle: &mut impl PrecompileHandle,
+        real: H256,
+        calls: Vec<UnboundedBytes>,
+    ) -> EvmResult<Vec<CallResult>> {
+        let real = R::AccountId::from(real.0);
+        let calls = Self::try_decode_calls(calls)?;
+
+        Self::dispatch_batch(handle, Dispatcher::Proxy(real), calls)
+    }
+
+    #[precompile::public("proxyBatchAll(bytes32,bytes[])")]
+    fn proxy_batch_all(
+        handle: &mut impl PrecompileHandle,
+        real: H256,
+        calls: Vec<UnboundedBytes>,
+    ) -> EvmResult<()> {
+        let real = R::AccountId::from(real.0);
+        let calls = Self::try_decode_calls(calls)?;
+
+        Self::dispatch_batch_all(handle, Dispatcher::Proxy(real), calls)
+    }
+}
+
+impl<R> ProxyPrecompile<R>
+where
+    R: frame_system::Config
+        + pallet_evm::Config
+        + pallet_subtensor::Config
+        + pallet_proxy::Config<ProxyType = ProxyType>,
+    R::AccountId: From<[u8; 32]>,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + From<pallet_proxy::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as frame_system::Config>::RuntimeEvent: TryInto<pallet_proxy::Event<R>>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    fn try_decode_calls(calls: Vec<UnboundedBytes>) -> EvmResult<Vec<pallet_subtensor::Call<R>>> {
+        if calls.len() > MAX_BATCH_CALLS {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("too many calls in batch".into()),
+            });
+        }
+
+        calls
+            .into_iter()
+            .map(|call| {
+                let bytes: Vec<u8> = call.into();
+                pallet_subtensor::Call::<R>::decode_all(&mut &bytes[..])
+                    .ok()
+                    .filter(is_batchable::<R>)
+                    .ok_or(PrecompileFailure::Error {
+                        exit_status: ExitError::Other("call is not batchable".into()),
+                    })
+            })
+            .collect()
+    }
+
+    /// Dispatches a call for the dispatcher.
+    fn dispatch(
+        handle: &mut impl PrecompileHandle,
+        dispatcher: &Dispatcher<R::AccountId>,
+        call: pallet_subtensor::Call<R>,
+    ) -> EvmResult<()> {
+        let real = match dispatcher {
+            Dispatcher::Signed(account_id) => {
+                return handle.try_dispatch_runtime_call::<R, _>(
+                    call,
+                    RawOrigin::Signed(account_id.clone()),
+                );
+            }
+            Dispatcher::Proxy(real) => real.clone(),
+        };
+
+        let dispatch_log = call.dispatch_log(&RawOrigin::Signed(real.clone()));
+        let call = <R as frame_system::Config>::RuntimeCall::from(call);
+        let proxy_call = pallet_proxy::Call::<R>::proxy {
+            real: <R as frame_system::Config>::Lookup::unlookup(real),
+            force_proxy_type: None,
+            call: Box::new(call.into()),
+        };
+        let delegate = handle.caller_account_id::<R>();
+        handle.try_dispatch_runtime_call::<R, _>(proxy_call, RawOrigin::Signed(delegate))?;
+
+        // The proxy pallet reports the result of the proxied call in its last event.
+        handle.record_db_reads::<R>(1)?;
+        let result: Option<pallet_proxy::Event<R>> =
+            frame_system::Pallet::<R>::read_events_no_consensus()
+                .last()
+                .and_then(|record| record.event.try_into().ok());
+        match result {
+            Some(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }) => {}
+            Some(pallet_proxy::Event::ProxyExecuted { result: Err(e) }) => {
+                return Err(PrecompileFailure::Error {
+                    exit_status: ExitError::Other(
+                        format!("dispatch execution failed: {}", <&'static str>::from(e)).into(),
+                    ),
+                });
+            }
+            _ => {
+                return Err(PrecompileFailure::Error {
+                    exit_status: ExitError::Other("proxied call result not found".into()),
+                });
+            }
+        }
+
+        if let Some(dispatch_log) = dispatch_log {
+            handle.record_log(dispatch_log.dispatched())?;
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches the calls one after the other, each in its own storage layer, so a failed call
+    /// is rolled back and reported without affecting the others.
+    fn dispatch_batch(
+        handle: &mut impl PrecompileHandle,
+        dispatcher: Dispatcher<R::AccountId>,
+        calls: Vec<pallet_subtensor::Call<R>>,
+    ) -> EvmResult<Vec<CallResult>> {
+        calls
+            .into_iter()
+            .map(|call| {
+                let result = with_transaction(|| {
+                    let result = Self::dispatch(handle, &dispatcher, call);
+                    if result.is_ok() {
+                        TransactionOutcome::Commit(Ok::<_, DispatchError>(result))
+                    } else {
+                        TransactionOutcome::Rollback(Ok(result))
+                    }
+                })
+                .map_err(|_| PrecompileFailure::Error {
+                    exit_status: ExitError::Other("transactional layer limit reached".into()),
+                })?;
+
+                CallResult::try_from_dispatch(result)
+            })
+            .collect()
+    }
+
+    /// Dispatches the calls one after the other and reverts on the first failure, which rolls back
+    /// the whole batch.
+    fn dispatch_batch_all(
+        handle: &mut impl PrecompileHandle,
+        dispatcher: Dispatcher<R::AccountId>,
+        calls: Vec<pallet_subtensor::Call<R>>,
+    ) -> EvmResult<()> {
+        for (index, call) in calls.into_iter().enumerate() {
+            let result = Self::dispatch(handle, &dispatcher, call);
+            let CallResult { success, error } = CallResult::try_from_dispatch(result)?;
+
+            if !success {
+                let error = Vec::<u8>::from(error);
+                return Err(PrecompileFailure::Error {
+                    exit_status: ExitError::Other(
+                        format!("call {index} failed: {}", String::from_utf8_lossy(&error)).into(),
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The account a batch is dispatched for.
+enum Dispatcher<AccountId> {
+    /// The caller itself.
+    Signed(AccountId),
+    /// `real`, through `pallet_proxy::proxy` with the caller as delegate.
+    Proxy(AccountId),
+}
+
+#[derive(Codec)]
+struct CallResult {
+    success: bool,
+    error: UnboundedBytes,
+}
+
+impl CallResult {
+    /// Turns the outcome of a dispatch into a call result. Running out of gas or a fatal error
+    /// aborts the whole batch instead.
+    fn try_from_dispatch(result: EvmResult<()>) -> EvmResult<Self> {
+        let error = match result {
+            Ok(()) => Vec::new(),
+            Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other(message),
+            }) => message.as_bytes().to_vec(),
+            Err(PrecompileFailure::Revert { output, .. }) => output,
+            Err(failure) => return Err(failure),
+        };
+
+        Ok(Self {
+            success: error.is_empty(),
+            error: error.into(),
+        })
+    }
+}
+
+/// The subtensor calls a batch may contain: staking, weights, serving, registration and child key
+/// management. Calls that swap or dissolve keys and subnets, or need root, are left out.
+fn is_batchable<R: pallet_subtensor::Config>(call: &pallet_subtensor::Call<R>) -> bool {
+    matches!(
+        call,
+        pallet_subtensor::Call::add_stake { .. }
+            | pallet_subtensor::Call::add_stake_limit { .. }
+            | pallet_subtensor::Call::remove_stake { .. }
+            | pallet_subtensor::Call::remove_stake_limit { .. }
+            | pallet_subtensor::Call::remove_stake_full_limit { .. }
+            | pallet_subtensor::Call::unstake_all { .. }
+            | pallet_subtensor::Call::unstake_all_alpha { .. }
+            | pallet_subtensor::Call::move_stake { .. }
+            | pallet_subtensor::Call::transfer_stake { .. }
+            | pallet_subtensor::Call::swap_stake { .. }
+            | pallet_subtensor::Call::swap_stake_limit { .. }
+            | pallet_subtensor::Call::recycle_alpha { .. }
+            | pallet_subtensor::Call::burn_alpha { .. }
+            | pallet_subtensor::Call::set_weights { .. }
+            | pallet_subtensor::Call::batch_set_weights { .. }
+            | pallet_subtensor::Call::commit_weights { .. }
+            | pallet_subtensor::Call::batch_commit_weights { .. }
+            | pallet_subtensor::Call::reveal_weights { .. }
+            | pallet_subtensor::Call::batch_reveal_weights { .. }
+            | pallet_subtensor::Call::commit_crv3_weights { .. }
+            | pallet_subtensor::Call::set_weights_delta { .. }
+            | pallet_subtensor::Call::reveal_weights_delta { .. }
+            | pallet_subtensor::Call::serve_axon { .. }
+            | pallet_subtensor::Call::serve_axon_tls { .. }
+            | pallet_subtensor::Call::serve_axons { .. }
+            | pallet_subtensor::Call::serve_prometheus { .. }
+            | pallet_subtensor::Call::burned_register { .. }
+            | pallet_subtensor::Call::set_children { .. }
+            | pallet_subtensor::Call::set_childkey_take { .. }
+            | pallet_subtensor::Call::increase_take { .. }
+            | pallet_subtensor::Call::decrease_take { .. }
+            | pallet_subtensor::Call::set_alpha_token_hotkey { .. }
+            | pallet_subtensor::Call::approve_alpha_token { .. }
+            | pallet_subtensor::Call::transfer_alpha_token { .. }
+            | pallet_subtensor::Call::transfer_alpha_token_from { .. }
+    )
+}
+
+fn try_proxy_type(proxy_type: u8) -> EvmResult<ProxyType> {
+    codec::Decode::decode(&mut &[proxy_type][..]).map_err(|_| PrecompileFailure::Error {
+        exit_status: ExitError::Other("invalid proxy type".into()),
+    })
+}
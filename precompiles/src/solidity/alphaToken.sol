@@ -0,0 +1,73 @@
+pragma solidity ^0.8.0;
+
+// Every subnet has its own alpha token, at this address plus the subnet netuid.
+address constant IALPHA_TOKEN_BASE_ADDRESS = 0x0000000000000000000000000000000000010000;
+
+function alphaTokenAddress(uint16 netuid) pure returns (address) {
+    return address(uint160(IALPHA_TOKEN_BASE_ADDRESS) + netuid);
+}
+
+interface IAlphaToken {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+
+    /**
+     * @dev Returns the alpha staked on the subnet, by every staker and not only the holders that
+     * picked a hotkey with `setHotkey`, so it is at least the sum of all balances.
+     */
+    function totalSupply() external view returns (uint256);
+
+    /**
+     * @dev Returns the decimals of alpha, 9 like TAO.
+     */
+    function decimals() external view returns (uint8);
+
+    /**
+     * @dev Returns the stake of `owner` on the hotkey backing its tokens, zero if it has none.
+     */
+    function balanceOf(address owner) external view returns (uint256);
+
+    /**
+     * @dev Returns the alpha `spender` may transfer from `owner` with `transferFrom`, or
+     * `type(uint256).max` for an infinite allowance.
+     */
+    function allowance(
+        address owner,
+        address spender
+    ) external view returns (uint256);
+
+    /**
+     * @dev Returns the hotkey backing the tokens of `owner`: the one it chose with `setHotkey`,
+     * otherwise the first hotkey it holds stake on, such as the one of a transfer it received.
+     * Zero if it has none.
+     */
+    function hotkeyOf(address owner) external view returns (bytes32);
+
+    /**
+     * @dev Chooses the hotkey whose stake backs the caller's tokens.
+     * @param hotkey The hotkey public key (32 bytes).
+     */
+    function setHotkey(bytes32 hotkey) external;
+
+    /**
+     * @dev Moves `value` alpha staked on the caller's hotkey to `to`, on the same hotkey. A
+     * recipient backing its tokens with another hotkey doesn't see it in its balance. A zero
+     * `value` always succeeds.
+     */
+    function transfer(address to, uint256 value) external returns (bool);
+
+    /**
+     * @dev Allows `spender` to transfer up to `value` of the caller's tokens. Values of
+     * `type(uint64).max` and above are infinite allowances that `transferFrom` does not spend.
+     */
+    function approve(address spender, uint256 value) external returns (bool);
+
+    /**
+     * @dev Transfers `value` of the tokens of `from` to `to`, spending the caller's allowance.
+     */
+    function transferFrom(
+        address from,
+        address to,
+        uint256 value
+    ) external returns (bool);
+}
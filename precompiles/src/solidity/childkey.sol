@@ -0,0 +1,114 @@
+pragma solidity ^0.8.0;
+
+address constant ICHILDKEY_ADDRESS = 0x000000000000000000000000000000000000080f;
+
+/**
+ * @dev A child or parent of a hotkey, with the proportion of stake it receives, as a fraction of
+ * u64::MAX.
+ */
+struct Linkage {
+    uint64 proportion;
+    bytes32 hotkey;
+}
+
+interface IChildkey {
+    event ChildrenScheduled(
+        bytes32 indexed hotkey,
+        uint16 indexed netuid,
+        Linkage[] children
+    );
+    event ChildkeyTakeSet(bytes32 indexed hotkey, uint16 indexed netuid, uint16 take);
+    event TakeIncreased(bytes32 indexed coldkey, bytes32 indexed hotkey, uint16 take);
+    event TakeDecreased(bytes32 indexed coldkey, bytes32 indexed hotkey, uint16 take);
+
+    /**
+     * @dev Schedules the children of a hotkey owned by the caller. They apply once the pending
+     * childkey cooldown has passed. Subject to the same rate limit and validation as the
+     * `set_children` extrinsic.
+     * @param hotkey The parent hotkey.
+     * @param netuid The subnet.
+     * @param children The new children, replacing the current ones. Empty to revoke them.
+     */
+    function setChildren(
+        bytes32 hotkey,
+        uint16 netuid,
+        Linkage[] memory children
+    ) external;
+
+    /**
+     * @dev Returns the current children of a hotkey on a subnet.
+     */
+    function getChildren(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (Linkage[] memory);
+
+    /**
+     * @dev Returns the scheduled children of a hotkey on a subnet and the block they apply at.
+     */
+    function getPendingChildren(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (Linkage[] memory children, uint64 cooldownBlock);
+
+    /**
+     * @dev Returns the parents of a hotkey on a subnet.
+     */
+    function getParents(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (Linkage[] memory);
+
+    /**
+     * @dev Returns the number of blocks scheduled children wait before they apply.
+     */
+    function getPendingChildkeyCooldown() external view returns (uint64);
+
+    /**
+     * @dev Sets the childkey take of a hotkey owned by the caller on a subnet. Subject to the
+     * same rate limit and bounds as the `set_childkey_take` extrinsic.
+     * @param take The take, as a fraction of u16::MAX.
+     */
+    function setChildkeyTake(bytes32 hotkey, uint16 netuid, uint16 take) external;
+
+    /**
+     * @dev Returns the childkey take of a hotkey on a subnet.
+     */
+    function getChildkeyTake(
+        bytes32 hotkey,
+        uint16 netuid
+    ) external view returns (uint16);
+
+    /**
+     * @dev Returns the minimum and maximum childkey take.
+     */
+    function getChildkeyTakeRange()
+        external
+        view
+        returns (uint16 min, uint16 max);
+
+    /**
+     * @dev Increases the delegate take of a hotkey owned by the caller. Rate limited.
+     * @param take The take, as a fraction of u16::MAX.
+     */
+    function increaseTake(bytes32 hotkey, uint16 take) external;
+
+    /**
+     * @dev Decreases the delegate take of a hotkey owned by the caller.
+     * @param take The take, as a fraction of u16::MAX.
+     */
+    function decreaseTake(bytes32 hotkey, uint16 take) external;
+
+    /**
+     * @dev Returns the delegate take of a hotkey.
+     */
+    function getDelegateTake(bytes32 hotkey) external view returns (uint16);
+
+    /**
+     * @dev Returns the minimum and maximum delegate take.
+     */
+    function getDelegateTakeRange()
+        external
+        view
+        returns (uint16 min, uint16 max);
+}
@@ -0,0 +1,85 @@
+pragma solidity ^0.8.0;
+
+address constant ICOMMITMENTS_ADDRESS = 0x000000000000000000000000000000000000080c;
+
+/**
+ * @dev A commitment field. `kind` is one of:
+ * 0 None, 1 Raw (up to 128 bytes), 2 BlakeTwo256, 3 Sha256, 4 Keccak256, 5 ShaThree256 (32-byte
+ * hashes), 6 TimelockEncrypted (up to 1024 bytes, revealed at drand round `revealRound`),
+ * 7 ResetBondsFlag, 8 BigRaw (up to 512 bytes).
+ */
+struct Field {
+    uint8 kind;
+    bytes data;
+    uint64 revealRound;
+}
+
+struct Commitment {
+    bytes32 account;
+    uint64 committedBlock;
+    uint64 deposit;
+    Field[] fields;
+}
+
+struct RevealedCommitment {
+    bytes32 account;
+    bytes data;
+    uint64 revealedBlock;
+}
+
+interface ICommitments {
+    event CommitmentSet(uint16 indexed netuid, bytes32 indexed account);
+
+    /**
+     * @dev Sets the caller's commitment on a subnet. The fields count against the caller's space
+     * per epoch, as for the `set_commitment` extrinsic.
+     * @param netuid The subnet to commit on.
+     * @param fields The commitment fields, replacing the previous commitment.
+     */
+    function setCommitment(uint16 netuid, Field[] memory fields) external payable;
+
+    /**
+     * @dev Returns the commitment of an account on a subnet. Reverts if there is none.
+     */
+    function getCommitment(
+        uint16 netuid,
+        bytes32 account
+    ) external view returns (Commitment memory);
+
+    /**
+     * @dev Returns all commitments on a subnet. Gas grows with the number of committing
+     * accounts, one storage read each.
+     */
+    function listCommitments(
+        uint16 netuid
+    ) external view returns (Commitment[] memory);
+
+    /**
+     * @dev Returns the decrypted timelocked commitments of an account on a subnet, oldest first.
+     */
+    function getRevealedCommitments(
+        uint16 netuid,
+        bytes32 account
+    ) external view returns (RevealedCommitment[] memory);
+
+    /**
+     * @dev Returns all decrypted timelocked commitments on a subnet. Gas grows with the number
+     * of accounts with reveals, one storage read each.
+     */
+    function listRevealedCommitments(
+        uint16 netuid
+    ) external view returns (RevealedCommitment[] memory);
+
+    /**
+     * @dev Returns the epoch the account last committed in and the space it used in it.
+     */
+    function getUsedSpace(
+        uint16 netuid,
+        bytes32 account
+    ) external view returns (uint64 lastEpoch, uint64 usedSpace);
+
+    /**
+     * @dev Returns the space an account may use per epoch.
+     */
+    function getMaxSpace() external view returns (uint32);
+}
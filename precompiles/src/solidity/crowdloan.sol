@@ -3,6 +3,16 @@ pragma solidity ^0.8.0;
 address constant ICROWDLOAN_ADDRESS = 0x0000000000000000000000000000000000000809;
 
 interface ICrowdloan {
+    event CrowdloanCreated(uint32 indexed crowdloanId, bytes32 indexed creator, uint64 deposit, uint64 cap, uint32 end);
+    event Contributed(uint32 indexed crowdloanId, bytes32 indexed contributor, uint64 amount);
+    event Withdrew(uint32 indexed crowdloanId, bytes32 indexed contributor);
+    event CrowdloanFinalized(uint32 indexed crowdloanId);
+    event CrowdloanRefunded(uint32 indexed crowdloanId);
+    event CrowdloanDissolved(uint32 indexed crowdloanId);
+    event MinContributionUpdated(uint32 indexed crowdloanId, uint64 minContribution);
+    event EndUpdated(uint32 indexed crowdloanId, uint32 end);
+    event CapUpdated(uint32 indexed crowdloanId, uint64 cap);
+
     /**
      * @dev Retrieves the crowdloan info for a given crowdloan id.
      * @param crowdloanId The id of the crowdloan to get info for.
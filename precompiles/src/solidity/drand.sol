@@ -0,0 +1,67 @@
+pragma solidity ^0.8.0;
+
+address constant IDRAND_ADDRESS = 0x000000000000000000000000000000000000080d;
+
+interface IDrand {
+    event RandomnessRequested(uint64 indexed requestId, bytes32 indexed requester, uint64 round);
+
+    /**
+     * @dev Returns the verified randomness of a drand round.
+     * Reverts if the pulse of the round has not been stored yet.
+     * @param round The drand round.
+     * @return The randomness of the round.
+     */
+    function getRandomness(uint64 round) external view returns (bytes32);
+
+    /**
+     * @dev Returns the randomness of the latest stored drand round.
+     * @return round The latest stored round.
+     * @return randomness The randomness of the round.
+     */
+    function getLatestRandomness()
+        external
+        view
+        returns (uint64 round, bytes32 randomness);
+
+    /**
+     * @dev Returns the latest drand round whose pulse is stored.
+     */
+    function getLastStoredRound() external view returns (uint64);
+
+    /**
+     * @dev Returns the current drand round at the block timestamp. Its pulse is usually public
+     * before it is stored.
+     */
+    function getCurrentRound() external view returns (uint64);
+
+    /**
+     * @dev Requests the randomness of a future drand round. The request is fulfilled once the
+     * pulse of the round is stored, after which `getRequestRandomness` returns its randomness
+     * until the request is pruned, 1200 rounds later.
+     * Reverts unless the round is more than 20 rounds after `getCurrentRound` and at most 28800
+     * rounds after it, or if the caller already has 16 stored requests.
+     * @param round The drand round to commit to.
+     * @return requestId The id of the request.
+     */
+    function requestRandomness(uint64 round) external returns (uint64 requestId);
+
+    /**
+     * @dev Returns a randomness request. Reverts if the request does not exist or was pruned.
+     * @param requestId The id of the request.
+     * @return requester The account that made the request.
+     * @return round The drand round the request committed to.
+     * @return fulfilled Whether the pulse of the round is stored.
+     */
+    function getRequest(
+        uint64 requestId
+    ) external view returns (bytes32 requester, uint64 round, bool fulfilled);
+
+    /**
+     * @dev Returns the randomness of a fulfilled request, unique to the request.
+     * Reverts until the pulse of the requested round is stored.
+     * @param requestId The id of the request.
+     */
+    function getRequestRandomness(
+        uint64 requestId
+    ) external view returns (bytes32);
+}
@@ -0,0 +1,73 @@
+pragma solidity ^0.8.0;
+
+address constant IIDENTITY_ADDRESS = 0x0000000000000000000000000000000000000811;
+
+struct ChainIdentity {
+    string name;
+    string url;
+    string githubRepo;
+    string image;
+    string discord;
+    string description;
+    string additional;
+}
+
+struct SubnetIdentity {
+    string subnetName;
+    string githubRepo;
+    string subnetContact;
+    string subnetUrl;
+    string discord;
+    string description;
+    string logoUrl;
+    string additional;
+}
+
+interface IIdentity {
+    event IdentitySet(bytes32 indexed coldkey);
+    event SubnetIdentitySet(uint16 indexed netuid);
+
+    /**
+     * @dev Sets the identity of the caller's coldkey. One of its hotkeys must be registered on a
+     * subnet. Fields are validated as for the `set_identity` extrinsic.
+     */
+    function setIdentity(
+        string memory name,
+        string memory url,
+        string memory githubRepo,
+        string memory image,
+        string memory discord,
+        string memory description,
+        string memory additional
+    ) external payable;
+
+    /**
+     * @dev Returns the identity of a coldkey. Reverts if there is none.
+     */
+    function getIdentity(
+        bytes32 coldkey
+    ) external view returns (ChainIdentity memory);
+
+    /**
+     * @dev Sets the identity of a subnet owned by the caller. Fields are validated as for the
+     * `set_subnet_identity` extrinsic.
+     */
+    function setSubnetIdentity(
+        uint16 netuid,
+        string memory subnetName,
+        string memory githubRepo,
+        string memory subnetContact,
+        string memory subnetUrl,
+        string memory discord,
+        string memory description,
+        string memory logoUrl,
+        string memory additional
+    ) external payable;
+
+    /**
+     * @dev Returns the identity of a subnet. Reverts if there is none.
+     */
+    function getSubnetIdentity(
+        uint16 netuid
+    ) external view returns (SubnetIdentity memory);
+}
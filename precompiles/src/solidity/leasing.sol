@@ -3,6 +3,9 @@ pragma solidity ^0.8.0;
 address constant ILEASING_ADDRESS = 0x000000000000000000000000000000000000080a;
 
 interface ILeasing {
+    event CrowdloanCreated(uint32 indexed crowdloanId, bytes32 indexed creator, uint64 deposit, uint64 cap, uint32 end);
+    event LeaseTerminated(uint32 indexed leaseId, bytes32 indexed beneficiary, bytes32 hotkey);
+
     /**
      * @dev Retrieves the lease info for a given lease id.
      * @param leaseId The id of the lease to get info for.
@@ -0,0 +1,139 @@
+pragma solidity ^0.8.0;
+
+address constant ILIQUIDITY_ADDRESS = 0x000000000000000000000000000000000000080b;
+
+interface ILiquidity {
+    event LiquidityAdded(bytes32 indexed coldkey, bytes32 indexed hotkey, uint128 indexed positionId, uint16 netuid, uint64 liquidity);
+    event LiquidityRemoved(bytes32 indexed coldkey, bytes32 indexed hotkey, uint128 indexed positionId, uint16 netuid);
+    event PositionModified(bytes32 indexed coldkey, bytes32 indexed hotkey, uint128 indexed positionId, uint16 netuid, int64 liquidityDelta);
+    event FeesCollected(bytes32 indexed coldkey, bytes32 indexed hotkey, uint128 indexed positionId, uint16 netuid);
+
+    /**
+     * @dev Adds a concentrated liquidity position to the subnet pool, paid from the caller's
+     * TAO balance and the caller's alpha staked to `hotkey`.
+     * @param hotkey The hotkey the alpha is taken from.
+     * @param netuid The subnet of the pool.
+     * @param tickLow The lower tick of the price range.
+     * @param tickHigh The upper tick of the price range.
+     * @param liquidity The amount of liquidity to add.
+     * @return The id of the new position.
+     */
+    function addLiquidity(
+        bytes32 hotkey,
+        uint16 netuid,
+        int32 tickLow,
+        int32 tickHigh,
+        uint64 liquidity
+    ) external payable returns (uint128);
+
+    /**
+     * @dev Removes a position, returning its TAO and alpha plus accrued fees to the caller.
+     * @param hotkey The hotkey the alpha is staked to.
+     * @param netuid The subnet of the pool.
+     * @param positionId The id of the position to remove.
+     */
+    function removeLiquidity(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint128 positionId
+    ) external payable;
+
+    /**
+     * @dev Adds liquidity to or removes liquidity from a position. Accrued fees are collected.
+     * @param hotkey The hotkey the alpha is taken from or staked to.
+     * @param netuid The subnet of the pool.
+     * @param positionId The id of the position to modify.
+     * @param liquidityDelta The liquidity to add (positive) or remove (negative).
+     */
+    function modifyPosition(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint128 positionId,
+        int64 liquidityDelta
+    ) external payable;
+
+    /**
+     * @dev Collects the fees accrued by a position without changing its liquidity.
+     * @param hotkey The hotkey the alpha fees are staked to.
+     * @param netuid The subnet of the pool.
+     * @param positionId The id of the position.
+     */
+    function collectFees(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint128 positionId
+    ) external payable;
+
+    /**
+     * @dev Retrieves the ids of the positions a coldkey holds in a subnet pool. Gas grows with
+     * the number of positions, one storage read each.
+     * @param netuid The subnet of the pool.
+     * @param coldkey The owner of the positions.
+     * @return The position ids.
+     */
+    function getPositionIds(uint16 netuid, bytes32 coldkey)
+        external
+        view
+        returns (uint128[] memory);
+
+    /**
+     * @dev Retrieves a position with its current token amounts and uncollected fees.
+     * @param netuid The subnet of the pool.
+     * @param coldkey The owner of the position.
+     * @param positionId The id of the position.
+     * @return The position info.
+     */
+    function getPosition(
+        uint16 netuid,
+        bytes32 coldkey,
+        uint128 positionId
+    ) external view returns (PositionInfo memory);
+
+    /**
+     * @dev Retrieves the current tick of a subnet pool.
+     * @param netuid The subnet of the pool.
+     * @return The current tick.
+     */
+    function getCurrentTick(uint16 netuid) external view returns (int32);
+
+    /**
+     * @dev Retrieves the liquidity active at the current price of a subnet pool.
+     * @param netuid The subnet of the pool.
+     * @return The current liquidity.
+     */
+    function getCurrentLiquidity(uint16 netuid) external view returns (uint64);
+
+    /**
+     * @dev Retrieves the liquidity of a tick. Uninitialized ticks have no liquidity.
+     * @param netuid The subnet of the pool.
+     * @param index The tick index.
+     * @return The tick info.
+     */
+    function getTick(uint16 netuid, int32 index)
+        external
+        view
+        returns (TickInfo memory);
+
+    /**
+     * @dev Checks whether users can add liquidity to a subnet pool.
+     * @param netuid The subnet of the pool.
+     * @return Whether user liquidity is enabled.
+     */
+    function isUserLiquidityEnabled(uint16 netuid) external view returns (bool);
+}
+
+struct PositionInfo {
+    uint128 id;
+    int32 tick_low;
+    int32 tick_high;
+    uint64 liquidity;
+    uint64 tao;
+    uint64 alpha;
+    uint64 fees_tao;
+    uint64 fees_alpha;
+}
+
+struct TickInfo {
+    int128 liquidity_net;
+    uint64 liquidity_gross;
+}
@@ -11,6 +11,15 @@ struct AxonInfo {
   uint8 protocol;
 }
 
+struct WeightMetrics {
+  uint64 block;
+  uint16 consensus_similarity;
+  uint16 clipped_share;
+  uint16 bond_share;
+  int32 bonds_growth;
+  uint64 blocks_since_update;
+}
+
 interface IMetagraph {
   
   /**
@@ -131,4 +140,12 @@ interface IMetagraph {
    * @return The coldkey of the node with the specified netuid and uid.
    */
   function getColdkey(uint16 netuid, uint16 uid) external view returns (bytes32);
+
+  /**
+   * @dev Retrieves the weight-setting metrics of a validator over its last epochs, oldest first.
+   * @param netuid The network identifier for which to retrieve the metrics.
+   * @param uid The unique identifier for which to retrieve the metrics.
+   * @return The per-epoch similarity of its weights to consensus, share of weight clipped, share of bonds, change of that share since the previous epoch and blocks since its last weight update.
+   */
+  function getWeightMetrics(uint16 netuid, uint16 uid) external view returns (WeightMetrics[] memory);
 }
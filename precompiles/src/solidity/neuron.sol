@@ -3,6 +3,14 @@ pragma solidity ^0.8.0;
 address constant INeuron_ADDRESS = 0x0000000000000000000000000000000000000804;
 
 interface INeuron {
+    event NeuronRegistered(bytes32 indexed coldkey, bytes32 indexed hotkey, uint16 netuid);
+    event AxonServed(bytes32 indexed hotkey, uint16 netuid);
+    event PrometheusServed(bytes32 indexed hotkey, uint16 netuid);
+    event WeightsSet(bytes32 indexed hotkey, uint16 netuid);
+    event WeightsCommitted(bytes32 indexed hotkey, uint16 netuid, bytes32 commitHash);
+    event WeightsKeySet(bytes32 indexed hotkey, bytes32 indexed weightsKey, uint16 netuid);
+    event WeightsKeyRemoved(bytes32 indexed hotkey, uint16 netuid);
+
     /**
      * @dev Registers a neuron by calling `do_burned_registration` internally with the origin set to the ss58 mirror of the H160 address.
      * This allows the H160 to further call neuron-related methods and receive emissions.
@@ -62,6 +70,37 @@ interface INeuron {
         bytes memory certificate
     ) external payable;
 
+    /**
+     * @dev A single endpoint served through `serveAxons`.
+     *
+     * @param protocol The protocol used by the endpoint (uint8).
+     * @param ip The IP address of the endpoint (uint128).
+     * @param ipType The type of IP address (uint8).
+     * @param port The port number of the endpoint (uint16).
+     * @param priority The priority of the endpoint, lower values are preferred (uint8).
+     */
+    struct AxonEndpoint {
+        uint8 protocol;
+        uint128 ip;
+        uint8 ipType;
+        uint16 port;
+        uint8 priority;
+    }
+
+    /**
+     * @dev Serves several axon endpoints for a neuron.
+     * The preferred endpoint is also stored as the neuron's single axon.
+     *
+     * @param netuid The subnet to register the endpoints to (uint16).
+     * @param version The version of the axon (uint32).
+     * @param endpoints The endpoints to serve (AxonEndpoint[]).
+     */
+    function serveAxons(
+        uint16 netuid,
+        uint32 version,
+        AxonEndpoint[] memory endpoints
+    ) external payable;
+
     /**
      * @dev Serves Prometheus information for a neuron.
      * This function is used to serve Prometheus information, including the subnet to register to, version, IP address, port, and IP type.
@@ -119,4 +158,20 @@ interface INeuron {
         uint16[] memory salt,
         uint64 versionKey
     ) external payable;
+
+    /**
+     * @dev Authorizes a weights key to set, commit and reveal weights for the calling hotkey.
+     * The weights key replaces any previous one and can sign the weights methods of this interface.
+     *
+     * @param netuid The subnet the weights key acts on (uint16).
+     * @param weightsKey The public key of the weights key (32 bytes).
+     */
+    function setWeightsKey(uint16 netuid, bytes32 weightsKey) external payable;
+
+    /**
+     * @dev Revokes the weights key of the calling hotkey.
+     *
+     * @param netuid The subnet to revoke the weights key on (uint16).
+     */
+    function removeWeightsKey(uint16 netuid) external payable;
 }
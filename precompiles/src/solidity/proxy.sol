@@ -0,0 +1,64 @@
+pragma solidity ^0.8.0;
+
+address constant IPROXY_ADDRESS = 0x000000000000000000000000000000000000080e;
+
+/**
+ * @dev The outcome of a call in a batch. `error` holds the revert reason of a failed call.
+ */
+struct CallResult {
+    bool success;
+    bytes error;
+}
+
+/**
+ * @dev Batches take SCALE-encoded subtensor pallet calls, at most 16 of them. Only staking,
+ * weights, serving, burned registration, child key, delegate take and alpha token calls are
+ * allowed.
+ */
+interface IProxy {
+    event ProxyAdded(bytes32 indexed delegator, bytes32 indexed delegate);
+    event ProxyRemoved(bytes32 indexed delegator, bytes32 indexed delegate);
+
+    /**
+     * @dev Adds a proxy for the caller.
+     * @param delegate The account allowed to act for the caller.
+     * @param proxyType The proxy type, as the index of the runtime's `ProxyType` variant.
+     * @param delay The number of blocks a call must be announced in advance. Proxies with a delay
+     * can't be used through this precompile.
+     */
+    function addProxy(bytes32 delegate, uint8 proxyType, uint32 delay) external;
+
+    /**
+     * @dev Removes a proxy of the caller.
+     */
+    function removeProxy(bytes32 delegate, uint8 proxyType, uint32 delay) external;
+
+    /**
+     * @dev Dispatches the calls as the caller. A failed call is rolled back on its own and the
+     * remaining calls still run.
+     * @return The result of every call, in order.
+     */
+    function batch(bytes[] memory calls) external returns (CallResult[] memory);
+
+    /**
+     * @dev Dispatches the calls as the caller, atomically. Reverts with the index of the first
+     * failed call.
+     */
+    function batchAll(bytes[] memory calls) external;
+
+    /**
+     * @dev Same as `batch`, dispatching each call through the proxy pallet on behalf of `real`.
+     * The first proxy of the caller for `real` must have no delay and a type that allows the call.
+     */
+    function proxyBatch(
+        bytes32 real,
+        bytes[] memory calls
+    ) external returns (CallResult[] memory);
+
+    /**
+     * @dev Same as `batchAll`, dispatching each call through the proxy pallet on behalf of
+     * `real`. The first proxy of the caller for `real` must have no delay and a type that allows
+     * every call.
+     */
+    function proxyBatchAll(bytes32 real, bytes[] memory calls) external;
+}
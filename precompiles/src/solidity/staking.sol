@@ -3,6 +3,11 @@ pragma solidity ^0.8.0;
 address constant ISTAKING_ADDRESS = 0x0000000000000000000000000000000000000801;
 
 interface IStaking {
+    event StakeAdded(bytes32 indexed coldkey, bytes32 indexed hotkey, uint16 netuid, uint64 amount);
+    event StakeRemoved(bytes32 indexed coldkey, bytes32 indexed hotkey, uint16 netuid, uint64 amount);
+    event ProxyAdded(bytes32 indexed delegator, bytes32 indexed delegate);
+    event ProxyRemoved(bytes32 indexed delegator, bytes32 indexed delegate);
+
     /**
      * @dev Adds a subtensor stake corresponding to the value sent with the transaction, associated
      * with the `hotkey`.
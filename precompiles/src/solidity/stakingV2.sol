@@ -3,6 +3,13 @@ pragma solidity ^0.8.0;
 address constant ISTAKING_ADDRESS = 0x0000000000000000000000000000000000000805;
 
 interface IStaking {
+    event StakeAdded(bytes32 indexed coldkey, bytes32 indexed hotkey, uint16 netuid, uint64 amount);
+    event StakeRemoved(bytes32 indexed coldkey, bytes32 indexed hotkey, uint16 netuid, uint64 amount);
+    event StakeMoved(bytes32 indexed coldkey, bytes32 indexed originHotkey, bytes32 indexed destinationHotkey, uint16 originNetuid, uint16 destinationNetuid, uint64 amount);
+    event StakeTransferred(bytes32 indexed originColdkey, bytes32 indexed destinationColdkey, bytes32 indexed hotkey, uint16 originNetuid, uint16 destinationNetuid, uint64 amount);
+    event ProxyAdded(bytes32 indexed delegator, bytes32 indexed delegate);
+    event ProxyRemoved(bytes32 indexed delegator, bytes32 indexed delegate);
+
     /**
      * @dev Adds a subtensor stake `amount` associated with the `hotkey`.
      *
@@ -3,6 +3,8 @@ pragma solidity ^0.8.0;
 address constant ISUBNET_ADDRESS = 0x0000000000000000000000000000000000000803;
 
 interface ISubnet {
+    event NetworkRegistered(bytes32 indexed coldkey, bytes32 indexed hotkey);
+
     /// Registers a new network without specifying details.
     function registerNetwork(bytes32 hotkey) external payable;
     /// Registers a new network with specified subnet name, GitHub repository, and contact information.
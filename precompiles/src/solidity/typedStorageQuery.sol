@@ -0,0 +1,40 @@
+pragma solidity ^0.8.0;
+
+address constant ITYPED_STORAGE_QUERY_ADDRESS = 0x0000000000000000000000000000000000000810;
+
+/**
+ * @dev Stable reads of subtensor storage. `keys` and the returned value are ABI-encoded, e.g.
+ * `abi.decode(query("TotalHotkeyAlpha", 1, abi.encode(hotkey, netuid)), (uint64))`.
+ *
+ * Version 1 getters, as `name(keys) -> value`:
+ * - TotalIssuance() -> uint64
+ * - TotalStake() -> uint64
+ * - NetworksAdded(uint16 netuid) -> bool
+ * - SubnetOwner(uint16 netuid) -> (bool exists, bytes32 coldkey)
+ * - SubnetTAO(uint16 netuid) -> uint64
+ * - SubnetAlphaIn(uint16 netuid) -> uint64
+ * - SubnetAlphaOut(uint16 netuid) -> uint64
+ * - Tempo(uint16 netuid) -> uint16
+ * - SubnetworkN(uint16 netuid) -> uint16
+ * - Keys(uint16 netuid, uint16 uid) -> (bool registered, bytes32 hotkey)
+ * - Uids(uint16 netuid, bytes32 hotkey) -> (bool registered, uint16 uid)
+ * - Owner(bytes32 hotkey) -> (bool exists, bytes32 coldkey)
+ * - Delegates(bytes32 hotkey) -> uint16 take
+ * - TotalHotkeyAlpha(bytes32 hotkey, uint16 netuid) -> uint64
+ * - Stake(bytes32 hotkey, bytes32 coldkey, uint16 netuid) -> uint64
+ */
+interface ITypedStorageQuery {
+    /**
+     * @dev Reads a storage getter. Reverts if the getter or version is unknown, or the keys don't
+     * decode.
+     * @param name The getter name.
+     * @param version The getter version.
+     * @param keys The ABI-encoded keys.
+     * @return The ABI-encoded value.
+     */
+    function query(
+        string memory name,
+        uint32 version,
+        bytes memory keys
+    ) external view returns (bytes memory);
+}
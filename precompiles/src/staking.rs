@@ -41,6 +41,49 @@ use subtensor_runtime_common::{NetUid, ProxyType};
 
 use crate::{PrecompileExt, PrecompileHandleExt};
 
+/// Storage reads of a stake lookup: the shares of the coldkey and the total alpha and shares of
+/// the hotkey.
+pub(crate) const STAKE_READS: u64 = 3;
+
+/// Storage reads of pricing alpha on a subnet: the mechanism, the swap state and the reserves or
+/// the pool price.
+const PRICE_READS: u64 = 4;
+
+/// Storage reads of simulating a swap on a subnet, as estimated by the swap pallet weights.
+const SIM_SWAP_READS: u64 = 12;
+
+/// Records the reads of summing the stake of a hotkey over every subnet: its alpha and the price
+/// on each.
+fn record_total_hotkey_stake_reads<R>(handle: &mut impl PrecompileHandle) -> EvmResult<()>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+{
+    handle.record_db_reads::<R>(1)?;
+    let subnets = u64::from(pallet_subtensor::TotalNetworks::<R>::get());
+
+    handle.record_db_reads::<R>(subnets.saturating_mul(PRICE_READS.saturating_add(2)))
+}
+
+/// Records the reads of summing the stake of `coldkey`, charged per staked subnet so that gas
+/// runs out before an unbounded scan does.
+fn record_total_coldkey_stake_reads<R>(
+    handle: &mut impl PrecompileHandle,
+    coldkey: &R::AccountId,
+) -> EvmResult<()>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+{
+    handle.record_db_reads::<R>(1)?;
+    for hotkey in pallet_subtensor::StakingHotkeys::<R>::get(coldkey) {
+        handle.record_db_reads::<R>(1)?;
+        for _ in pallet_subtensor::Alpha::<R>::iter_key_prefix((&hotkey, coldkey)) {
+            handle.record_db_reads::<R>(STAKE_READS.saturating_add(SIM_SWAP_READS))?;
+        }
+    }
+
+    Ok(())
+}
+
 // Old StakingPrecompile had ETH-precision in values, which was not alligned with Substrate API. So
 // it's kinda deprecated, but exists for backward compatibility. Eventually, we should remove it
 // to stop supporting both precompiles.
@@ -214,10 +257,11 @@ where
     #[precompile::public("getTotalColdkeyStake(bytes32)")]
     #[precompile::view]
     fn get_total_coldkey_stake(
-        _handle: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         coldkey: H256,
     ) -> EvmResult<U256> {
         let coldkey = R::AccountId::from(coldkey.0);
+        record_total_coldkey_stake_reads::<R>(handle, &coldkey)?;
         let stake = pallet_subtensor::Pallet::<R>::get_total_stake_for_coldkey(&coldkey);
 
         Ok(stake.into())
@@ -225,10 +269,9 @@ where
 
     #[precompile::public("getTotalHotkeyStake(bytes32)")]
     #[precompile::view]
-    fn get_total_hotkey_stake(
-        _handle: &mut impl PrecompileHandle,
-        hotkey: H256,
-    ) -> EvmResult<U256> {
+    fn get_total_hotkey_stake(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<U256> {
+        record_total_hotkey_stake_reads::<R>(handle)?;
+
         let hotkey = R::AccountId::from(hotkey.0);
         let stake = pallet_subtensor::Pallet::<R>::get_total_stake_for_hotkey(&hotkey);
 
@@ -238,11 +281,13 @@ where
     #[precompile::public("getStake(bytes32,bytes32,uint256)")]
     #[precompile::view]
     fn get_stake(
-        _: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         hotkey: H256,
         coldkey: H256,
         netuid: U256,
     ) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(STAKE_READS)?;
+
         let hotkey = R::AccountId::from(hotkey.0);
         let coldkey = R::AccountId::from(coldkey.0);
         let netuid = try_u16_from_u256(netuid)?;
@@ -258,7 +303,7 @@ where
     #[precompile::public("getAlphaStakedValidators(bytes32,uint256)")]
     #[precompile::view]
     fn get_alpha_staked_validators(
-        _handle: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         hotkey: H256,
         netuid: U256,
     ) -> EvmResult<Vec<H256>> {
@@ -267,6 +312,8 @@ where
         let netuid = NetUid::from(try_u16_from_u256(netuid)?);
         for ((coldkey, netuid_in_alpha), _) in pallet_subtensor::Alpha::<R>::iter_prefix((hotkey,))
         {
+            // Charged per entry so that gas runs out before an unbounded scan does.
+            handle.record_db_reads::<R>(1)?;
             if netuid == netuid_in_alpha {
                 let key: [u8; 32] = coldkey.into();
                 coldkeys.push(key.into());
@@ -279,10 +326,12 @@ where
     #[precompile::public("getTotalAlphaStaked(bytes32,uint256)")]
     #[precompile::view]
     fn get_total_alpha_staked(
-        _handle: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         hotkey: H256,
         netuid: U256,
     ) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(1)?;
+
         let hotkey = R::AccountId::from(hotkey.0);
         let netuid = try_u16_from_u256(netuid)?;
         let stake =
@@ -293,7 +342,9 @@ where
 
     #[precompile::public("getNominatorMinRequiredStake()")]
     #[precompile::view]
-    fn get_nominator_min_required_stake(_handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+    fn get_nominator_min_required_stake(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(1)?;
+
         let stake = pallet_subtensor::Pallet::<R>::get_nominator_min_required_stake();
 
         Ok(stake.into())
@@ -468,10 +519,11 @@ where
     #[precompile::public("getTotalColdkeyStake(bytes32)")]
     #[precompile::view]
     fn get_total_coldkey_stake(
-        _handle: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         coldkey: H256,
     ) -> EvmResult<U256> {
         let coldkey = R::AccountId::from(coldkey.0);
+        record_total_coldkey_stake_reads::<R>(handle, &coldkey)?;
 
         // get total stake of coldkey
         let total_stake = pallet_subtensor::Pallet::<R>::get_total_stake_for_coldkey(&coldkey);
@@ -486,10 +538,9 @@ where
 
     #[precompile::public("getTotalHotkeyStake(bytes32)")]
     #[precompile::view]
-    fn get_total_hotkey_stake(
-        _handle: &mut impl PrecompileHandle,
-        hotkey: H256,
-    ) -> EvmResult<U256> {
+    fn get_total_hotkey_stake(handle: &mut impl PrecompileHandle, hotkey: H256) -> EvmResult<U256> {
+        record_total_hotkey_stake_reads::<R>(handle)?;
+
         let hotkey = R::AccountId::from(hotkey.0);
 
         // get total stake of hotkey
@@ -506,11 +557,13 @@ where
     #[precompile::public("getStake(bytes32,bytes32,uint256)")]
     #[precompile::view]
     fn get_stake(
-        _: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         hotkey: H256,
         coldkey: H256,
         netuid: U256,
     ) -> EvmResult<U256> {
+        handle.record_db_reads::<R>(STAKE_READS)?;
+
         let hotkey = R::AccountId::from(hotkey.0);
         let coldkey = R::AccountId::from(coldkey.0);
         let netuid = try_u16_from_u256(netuid)?;
@@ -1,11 +1,21 @@
 use core::marker::PhantomData;
 
+use fp_evm::{ExitError, PrecompileFailure};
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::traits::ConstU32;
 use pallet_evm::{ExitSucceed, Precompile, PrecompileHandle, PrecompileOutput};
+use precompile_utils::{
+    EvmResult,
+    prelude::{BoundedString, UnboundedBytes},
+    solidity::{Codec, decode_arguments, encode_arguments},
+};
+use sp_core::H256;
 use sp_runtime::traits::{Dispatchable, StaticLookup};
 use sp_std::vec::Vec;
+use subtensor_runtime_common::NetUid;
 
-use crate::PrecompileExt;
+use crate::staking::STAKE_READS;
+use crate::{PrecompileExt, PrecompileHandleExt};
 
 pub(crate) struct StorageQueryPrecompile<R>(PhantomData<R>);
 
@@ -54,3 +64,156 @@ where
         }
     }
 }
+
+/// Whitelisted, versioned storage getters. A getter is named after the storage item it reads and
+/// takes its keys ABI-encoded, returning the ABI-encoded value. Getters of storage values ignore
+/// the keys. A getter keeps its version's encoding across storage migrations; a new encoding gets
+/// a new version.
+pub(crate) struct TypedStorageQueryPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for TypedStorageQueryPrecompile<R>
+where
+    R: frame_system::Config + pallet_subtensor::Config + pallet_evm::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+{
+    const INDEX: u64 = 2064;
+}
+
+#[precompile_utils::precompile]
+impl<R> TypedStorageQueryPrecompile<R>
+where
+    R: frame_system::Config + pallet_subtensor::Config + pallet_evm::Config,
+    R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+{
+    #[precompile::public("query(string,uint32,bytes)")]
+    #[precompile::view]
+    fn query(
+        handle: &mut impl PrecompileHandle,
+        name: BoundedString<ConstU32<64>>,
+        version: u32,
+        keys: UnboundedBytes,
+    ) -> EvmResult<UnboundedBytes> {
+        let name: Vec<u8> = name.into();
+        let keys: Vec<u8> = keys.into();
+
+        // Every getter reads a single storage value, but for the stake lookup.
+        let reads = if name.as_slice() == b"Stake" {
+            STAKE_READS
+        } else {
+            1
+        };
+        handle.record_db_reads::<R>(reads)?;
+
+        let value = match (name.as_slice(), version) {
+            (b"TotalIssuance", 1) => encode_arguments(pallet_subtensor::TotalIssuance::<R>::get()),
+            (b"TotalStake", 1) => encode_arguments(pallet_subtensor::TotalStake::<R>::get()),
+            (b"NetworksAdded", 1) => {
+                let netuid = decode_keys::<u16>(&keys)?;
+                encode_arguments(pallet_subtensor::NetworksAdded::<R>::get(NetUid::from(
+                    netuid,
+                )))
+            }
+            (b"SubnetOwner", 1) => {
+                let netuid = decode_keys::<u16>(&keys)?;
+                let owner = pallet_subtensor::SubnetOwner::<R>::try_get(NetUid::from(netuid));
+                encode_arguments(optional_account::<R>(owner.ok()))
+            }
+            (b"SubnetTAO", 1) => {
+                let netuid = decode_keys::<u16>(&keys)?;
+                encode_arguments(pallet_subtensor::SubnetTAO::<R>::get(NetUid::from(netuid)))
+            }
+            (b"SubnetAlphaIn", 1) => {
+                let netuid = decode_keys::<u16>(&keys)?;
+                let alpha = pallet_subtensor::SubnetAlphaIn::<R>::get(NetUid::from(netuid));
+                encode_arguments(u64::from(alpha))
+            }
+            (b"SubnetAlphaOut", 1) => {
+                let netuid = decode_keys::<u16>(&keys)?;
+                let alpha = pallet_subtensor::SubnetAlphaOut::<R>::get(NetUid::from(netuid));
+                encode_arguments(u64::from(alpha))
+            }
+            (b"Tempo", 1) => {
+                let netuid = decode_keys::<u16>(&keys)?;
+                encode_arguments(pallet_subtensor::Tempo::<R>::get(NetUid::from(netuid)))
+            }
+            (b"SubnetworkN", 1) => {
+                let netuid = decode_keys::<u16>(&keys)?;
+                encode_arguments(pallet_subtensor::SubnetworkN::<R>::get(NetUid::from(
+                    netuid,
+                )))
+            }
+            (b"Keys", 1) => {
+                let (netuid, uid) = decode_keys::<(u16, u16)>(&keys)?;
+                let hotkey = pallet_subtensor::Keys::<R>::try_get(NetUid::from(netuid), uid);
+                encode_arguments(optional_account::<R>(hotkey.ok()))
+            }
+            (b"Uids", 1) => {
+                let (netuid, hotkey) = decode_keys::<(u16, H256)>(&keys)?;
+                let uid =
+                    pallet_subtensor::Uids::<R>::get(NetUid::from(netuid), account_id::<R>(hotkey));
+                encode_arguments((uid.is_some(), uid.unwrap_or_default()))
+            }
+            (b"Owner", 1) => {
+                let hotkey = decode_keys::<H256>(&keys)?;
+                let coldkey = pallet_subtensor::Owner::<R>::try_get(account_id::<R>(hotkey));
+                encode_arguments(optional_account::<R>(coldkey.ok()))
+            }
+            (b"Delegates", 1) => {
+                let hotkey = decode_keys::<H256>(&keys)?;
+                encode_arguments(pallet_subtensor::Delegates::<R>::get(account_id::<R>(
+                    hotkey,
+                )))
+            }
+            (b"TotalHotkeyAlpha", 1) => {
+                let (hotkey, netuid) = decode_keys::<(H256, u16)>(&keys)?;
+                let alpha = pallet_subtensor::TotalHotkeyAlpha::<R>::get(
+                    account_id::<R>(hotkey),
+                    NetUid::from(netuid),
+                );
+                encode_arguments(u64::from(alpha))
+            }
+            (b"Stake", 1) => {
+                let (hotkey, coldkey, netuid) = decode_keys::<(H256, H256, u16)>(&keys)?;
+                let stake =
+                    pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_and_coldkey_on_subnet(
+                        &account_id::<R>(hotkey),
+                        &account_id::<R>(coldkey),
+                        NetUid::from(netuid),
+                    );
+                encode_arguments(u64::from(stake))
+            }
+            _ => {
+                return Err(PrecompileFailure::Error {
+                    exit_status: ExitError::Other("unknown storage getter".into()),
+                });
+            }
+        };
+
+        Ok(value.into())
+    }
+}
+
+fn decode_keys<T: Codec>(keys: &[u8]) -> EvmResult<T> {
+    decode_arguments::<T>(keys).map_err(|_| PrecompileFailure::Error {
+        exit_status: ExitError::Other("invalid storage getter keys".into()),
+    })
+}
+
+fn account_id<R: frame_system::Config>(account: H256) -> R::AccountId
+where
+    R::AccountId: From<[u8; 32]>,
+{
+    R::AccountId::from(account.0)
+}
+
+fn optional_account<R: frame_system::Config>(account: Option<R::AccountId>) -> (bool, H256)
+where
+    R::AccountId: Into<[u8; 32]>,
+{
+    (
+        account.is_some(),
+        account
+            .map(|account| H256(account.into()))
+            .unwrap_or_default(),
+    )
+}
@@ -140,7 +140,9 @@ where
 
     #[precompile::public("getServingRateLimit(uint16)")]
     #[precompile::view]
-    fn get_serving_rate_limit(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_serving_rate_limit(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::ServingRateLimit::<R>::get(NetUid::from(
             netuid,
         )))
@@ -166,7 +168,9 @@ where
 
     #[precompile::public("getMinDifficulty(uint16)")]
     #[precompile::view]
-    fn get_min_difficulty(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_min_difficulty(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::MinDifficulty::<R>::get(NetUid::from(
             netuid,
         )))
@@ -192,7 +196,9 @@ where
 
     #[precompile::public("getMaxDifficulty(uint16)")]
     #[precompile::view]
-    fn get_max_difficulty(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_max_difficulty(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::MaxDifficulty::<R>::get(NetUid::from(
             netuid,
         )))
@@ -218,7 +224,9 @@ where
 
     #[precompile::public("getWeightsVersionKey(uint16)")]
     #[precompile::view]
-    fn get_weights_version_key(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_weights_version_key(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::WeightsVersionKey::<R>::get(NetUid::from(
             netuid,
         )))
@@ -244,7 +252,12 @@ where
 
     #[precompile::public("getWeightsSetRateLimit(uint16)")]
     #[precompile::view]
-    fn get_weights_set_rate_limit(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_weights_set_rate_limit(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::WeightsSetRateLimit::<R>::get(
             NetUid::from(netuid),
         ))
@@ -263,7 +276,9 @@ where
 
     #[precompile::public("getAdjustmentAlpha(uint16)")]
     #[precompile::view]
-    fn get_adjustment_alpha(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_adjustment_alpha(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::AdjustmentAlpha::<R>::get(NetUid::from(
             netuid,
         )))
@@ -289,7 +304,9 @@ where
 
     #[precompile::public("getMaxWeightLimit(uint16)")]
     #[precompile::view]
-    fn get_max_weight_limit(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_max_weight_limit(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::MaxWeightsLimit::<R>::get(NetUid::from(
             netuid,
         )))
@@ -315,7 +332,9 @@ where
 
     #[precompile::public("getImmunityPeriod(uint16)")]
     #[precompile::view]
-    fn get_immunity_period(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_immunity_period(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::ImmunityPeriod::<R>::get(NetUid::from(
             netuid,
         )))
@@ -341,7 +360,9 @@ where
 
     #[precompile::public("getMinAllowedWeights(uint16)")]
     #[precompile::view]
-    fn get_min_allowed_weights(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_min_allowed_weights(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::MinAllowedWeights::<R>::get(NetUid::from(
             netuid,
         )))
@@ -367,7 +388,9 @@ where
 
     #[precompile::public("getKappa(uint16)")]
     #[precompile::view]
-    fn get_kappa(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_kappa(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Kappa::<R>::get(NetUid::from(netuid)))
     }
 
@@ -387,13 +410,20 @@ where
 
     #[precompile::public("getRho(uint16)")]
     #[precompile::view]
-    fn get_rho(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_rho(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Rho::<R>::get(NetUid::from(netuid)))
     }
 
     #[precompile::public("getAlphaSigmoidSteepness(uint16)")]
     #[precompile::view]
-    fn get_alpha_sigmoid_steepness(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_alpha_sigmoid_steepness(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::AlphaSigmoidSteepness::<R>::get(NetUid::from(netuid)) as u16)
     }
 
@@ -431,7 +461,9 @@ where
 
     #[precompile::public("getActivityCutoff(uint16)")]
     #[precompile::view]
-    fn get_activity_cutoff(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+    fn get_activity_cutoff(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::ActivityCutoff::<R>::get(NetUid::from(
             netuid,
         )))
@@ -458,9 +490,11 @@ where
     #[precompile::public("getNetworkRegistrationAllowed(uint16)")]
     #[precompile::view]
     fn get_network_registration_allowed(
-        _: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
     ) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::NetworkRegistrationAllowed::<R>::get(
             NetUid::from(netuid),
         ))
@@ -487,9 +521,11 @@ where
     #[precompile::public("getNetworkPowRegistrationAllowed(uint16)")]
     #[precompile::view]
     fn get_network_pow_registration_allowed(
-        _: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
     ) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::NetworkPowRegistrationAllowed::<R>::get(
             NetUid::from(netuid),
         ))
@@ -515,7 +551,9 @@ where
 
     #[precompile::public("getMinBurn(uint16)")]
     #[precompile::view]
-    fn get_min_burn(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_min_burn(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::MinBurn::<R>::get(NetUid::from(netuid)))
     }
 
@@ -532,7 +570,9 @@ where
 
     #[precompile::public("getMaxBurn(uint16)")]
     #[precompile::view]
-    fn get_max_burn(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_max_burn(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::MaxBurn::<R>::get(NetUid::from(netuid)))
     }
 
@@ -549,7 +589,9 @@ where
 
     #[precompile::public("getDifficulty(uint16)")]
     #[precompile::view]
-    fn get_difficulty(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_difficulty(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Difficulty::<R>::get(NetUid::from(netuid)))
     }
 
@@ -573,7 +615,9 @@ where
 
     #[precompile::public("getBondsMovingAverage(uint16)")]
     #[precompile::view]
-    fn get_bonds_moving_average(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+    fn get_bonds_moving_average(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::BondsMovingAverage::<R>::get(
             NetUid::from(netuid),
         ))
@@ -600,9 +644,11 @@ where
     #[precompile::public("getCommitRevealWeightsEnabled(uint16)")]
     #[precompile::view]
     fn get_commit_reveal_weights_enabled(
-        _: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
     ) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::CommitRevealWeightsEnabled::<R>::get(
             NetUid::from(netuid),
         ))
@@ -628,7 +674,12 @@ where
 
     #[precompile::public("getLiquidAlphaEnabled(uint16)")]
     #[precompile::view]
-    fn get_liquid_alpha_enabled(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<bool> {
+    fn get_liquid_alpha_enabled(
+        handle: &mut impl PrecompileHandle,
+        netuid: u16,
+    ) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::LiquidAlphaOn::<R>::get(NetUid::from(
             netuid,
         )))
@@ -654,13 +705,17 @@ where
 
     #[precompile::public("getYuma3Enabled(uint16)")]
     #[precompile::view]
-    fn get_yuma3_enabled(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<bool> {
+    fn get_yuma3_enabled(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::Yuma3On::<R>::get(NetUid::from(netuid)))
     }
 
     #[precompile::public("getBondsResetEnabled(uint16)")]
     #[precompile::view]
-    fn get_bonds_reset_enabled(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<bool> {
+    fn get_bonds_reset_enabled(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<bool> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::BondsResetOn::<R>::get(NetUid::from(
             netuid,
         )))
@@ -704,7 +759,9 @@ where
 
     #[precompile::public("getAlphaValues(uint16)")]
     #[precompile::view]
-    fn get_alpha_values(_: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<(u16, u16)> {
+    fn get_alpha_values(handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<(u16, u16)> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::AlphaValues::<R>::get(NetUid::from(
             netuid,
         )))
@@ -733,9 +790,11 @@ where
     #[precompile::public("getCommitRevealWeightsInterval(uint16)")]
     #[precompile::view]
     fn get_commit_reveal_weights_interval(
-        _: &mut impl PrecompileHandle,
+        handle: &mut impl PrecompileHandle,
         netuid: u16,
     ) -> EvmResult<u64> {
+        handle.record_db_reads::<R>(1)?;
+
         Ok(pallet_subtensor::RevealPeriodEpochs::<R>::get(
             NetUid::from(netuid),
         ))
@@ -37,13 +37,16 @@ use pallet_grandpa::{
 };
 use pallet_registry::CanRegisterIdentity;
 use pallet_subtensor::rpc_info::{
-    delegate_info::DelegateInfo,
+    delegate_info::{DelegateInfo, DelegateInfoV2},
     dynamic_info::DynamicInfo,
     metagraph::{Metagraph, SelectiveMetagraph},
     neuron_info::{NeuronInfo, NeuronInfoLite},
     show_subnet::SubnetState,
     stake_info::StakeInfo,
-    subnet_info::{SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2},
+    subnet_info::{
+        SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2, SubnetRoleInfo,
+    },
+    weight_commits::WeightCommitInfo,
 };
 use smallvec::smallvec;
 use sp_api::impl_runtime_apis;
@@ -111,6 +114,7 @@ impl pallet_drand::Config for Runtime {
     type Verifier = pallet_drand::verifier::QuicknetVerifier;
     type UnsignedPriority = ConstU64<{ 1 << 20 }>;
     type HttpFetchTimeout = ConstU64<1_000>;
+    type UnixTime = Timestamp;
 }
 
 impl frame_system::offchain::SigningTypes for Runtime {
@@ -218,7 +222,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 299,
+    spec_version: 323,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -396,8 +400,10 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
                 | RuntimeCall::Timestamp(_)
                 | RuntimeCall::SubtensorModule(
                     pallet_subtensor::Call::set_weights { .. }
+                        | pallet_subtensor::Call::set_weights_delta { .. }
                         | pallet_subtensor::Call::set_tao_weights { .. }
                         | pallet_subtensor::Call::serve_axon { .. }
+                        | pallet_subtensor::Call::serve_axons { .. }
                 )
                 | RuntimeCall::Commitments(pallet_commitments::Call::set_commitment { .. })
         )
@@ -531,7 +537,9 @@ impl CanVote<AccountId> for CanVoteToTriumvirate {
     }
 }
 
-use pallet_subtensor::{CollectiveInterface, MemberManagement, ProxyInterface};
+use pallet_subtensor::subnets::hyperparameter_schedule::ScheduledHyperparameterChange;
+use pallet_subtensor::subnets::roles::HyperparameterBound;
+use pallet_subtensor::{AxonEndpoint, CollectiveInterface, MemberManagement, ProxyInterface};
 pub struct ManageSenateMembers;
 impl MemberManagement<AccountId> for ManageSenateMembers {
     fn add_member(account: &AccountId) -> DispatchResultWithPostInfo {
@@ -695,6 +703,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::Balances(..)
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::approve_alpha_token { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::transfer_alpha_token { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::transfer_alpha_token_from { .. }
+                    )
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
@@ -720,6 +737,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_stake_limit { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::approve_alpha_token { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::transfer_alpha_token { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::transfer_alpha_token_from { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
                     | RuntimeCall::SubtensorModule(
@@ -734,6 +760,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
                     | RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::transfer_alpha_token { .. }
+                    )
             ),
             ProxyType::SmallTransfer => match c {
                 RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
@@ -747,6 +776,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     alpha_amount,
                     ..
                 }) => *alpha_amount < SMALL_TRANSFER_LIMIT.into(),
+                RuntimeCall::SubtensorModule(pallet_subtensor::Call::transfer_alpha_token {
+                    amount,
+                    ..
+                }) => *amount < SMALL_TRANSFER_LIMIT.into(),
                 _ => false,
             },
             ProxyType::Owner => {
@@ -1133,18 +1166,11 @@ impl pallet_commitments::GetTempoInterface for Runtime {
     }
 }
 
-#[cfg(not(feature = "fast-blocks"))]
+// Test networks override these in their genesis config, see the localnet chain spec.
 pub const INITIAL_SUBNET_TEMPO: u16 = 360;
 
-#[cfg(feature = "fast-blocks")]
-pub const INITIAL_SUBNET_TEMPO: u16 = 10;
-
-#[cfg(not(feature = "fast-blocks"))]
 pub const INITIAL_CHILDKEY_TAKE_RATELIMIT: u64 = 216000; // 30 days at 12 seconds per block
 
-#[cfg(feature = "fast-blocks")]
-pub const INITIAL_CHILDKEY_TAKE_RATELIMIT: u64 = 5;
-
 // Configure the pallet subtensor.
 parameter_types! {
     pub const SubtensorInitialRho: u16 = 10;
@@ -1205,11 +1231,7 @@ parameter_types! {
     pub const InitialDissolveNetworkScheduleDuration: BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
     pub const SubtensorInitialTaoWeight: u64 = 971_718_665_099_567_868; // 0.05267697438728329% tao weight.
     pub const InitialEmaPriceHalvingPeriod: u64 = 201_600_u64; // 4 weeks
-    pub const DurationOfStartCall: u64 = if cfg!(feature = "fast-blocks") {
-        10 // Only 10 blocks for fast blocks
-    } else {
-        7 * 24 * 60 * 60 / 12 // 7 days
-    };
+    pub const DurationOfStartCall: u64 = 7 * 24 * 60 * 60 / 12; // 7 days
     pub const SubtensorInitialKeySwapOnSubnetCost: u64 = 1_000_000; // 0.001 TAO
     pub const HotkeySwapOnSubnetInterval : BlockNumber = 5 * 24 * 60 * 60 / 12; // 5 days
     pub const LeaseDividendsDistributionInterval: BlockNumber = 100; // 100 blocks
@@ -1776,7 +1798,7 @@ fn generate_genesis_json() -> Vec<u8> {
       },
       "subtensorModule": {
         "balancesIssuance": 0,
-        "stakes": []
+        "snapshot": null
       }
     }"#;
 
@@ -2317,6 +2339,18 @@ impl_runtime_apis! {
         fn get_delegated(delegatee_account: AccountId32) -> Vec<(DelegateInfo<AccountId32>, (Compact<NetUid>, Compact<AlphaCurrency>))> {
             SubtensorModule::get_delegated(delegatee_account)
         }
+
+        fn get_delegates_v2() -> Vec<DelegateInfoV2<AccountId32>> {
+            SubtensorModule::get_delegates_v2()
+        }
+
+        fn get_delegate_v2(delegate_account: AccountId32) -> Option<DelegateInfoV2<AccountId32>> {
+            SubtensorModule::get_delegate_v2(delegate_account)
+        }
+
+        fn get_delegated_v2(delegatee_account: AccountId32) -> Vec<(DelegateInfoV2<AccountId32>, (Compact<NetUid>, Compact<AlphaCurrency>))> {
+            SubtensorModule::get_delegated_v2(delegatee_account)
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block> for Runtime {
@@ -2335,6 +2369,14 @@ impl_runtime_apis! {
         fn get_neuron(netuid: NetUid, uid: u16) -> Option<NeuronInfo<AccountId32>> {
             SubtensorModule::get_neuron(netuid, uid)
         }
+
+        fn get_neuron_axon_endpoints(netuid: NetUid, uid: u16) -> Vec<AxonEndpoint> {
+            SubtensorModule::get_neuron_axon_endpoints(netuid, uid)
+        }
+
+        fn get_neuron_weights_key(netuid: NetUid, uid: u16) -> Option<AccountId32> {
+            SubtensorModule::get_neuron_weights_key(netuid, uid)
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block> for Runtime {
@@ -2386,6 +2428,22 @@ impl_runtime_apis! {
             SubtensorModule::get_selective_metagraph(netuid, metagraph_indexes)
         }
 
+        fn get_subnet_roles(netuid: NetUid) -> Vec<SubnetRoleInfo<AccountId32>> {
+            SubtensorModule::get_subnet_roles(netuid)
+        }
+
+        fn get_scheduled_hyperparameter_changes(netuid: NetUid) -> Vec<ScheduledHyperparameterChange> {
+            SubtensorModule::get_scheduled_hyperparameter_changes(netuid)
+        }
+
+        fn get_hyperparameter_bounds() -> Vec<HyperparameterBound> {
+            SubtensorModule::get_hyperparameter_bounds()
+        }
+
+        fn get_weight_commits(netuid: NetUid, hotkey: Option<AccountId32>) -> Vec<WeightCommitInfo<AccountId32>> {
+            SubtensorModule::get_weight_commits(netuid, hotkey)
+        }
+
     }
 
     impl subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {